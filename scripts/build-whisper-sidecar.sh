@@ -0,0 +1,36 @@
+#!/usr/bin/env bash
+# Builds whisper-cli from whisper.cpp and places it where Tauri expects sidecar
+# binaries: src-tauri/binaries/whisper-cli-<target-triple>[.exe]
+set -euo pipefail
+
+WHISPER_CPP_REF="${WHISPER_CPP_REF:-v1.7.5}"
+ROOT="$(cd "$(dirname "$0")/.." && pwd)"
+WORK_DIR="${ROOT}/target/whisper.cpp"
+OUT_DIR="${ROOT}/src-tauri/binaries"
+TRIPLE="${TARGET_TRIPLE:-$(rustc -vV | sed -n 's/^host: //p')}"
+
+EXT=""
+if [[ "${TRIPLE}" == *windows* ]]; then
+  EXT=".exe"
+fi
+
+if [[ ! -d "${WORK_DIR}" ]]; then
+  git clone --depth 1 --branch "${WHISPER_CPP_REF}" https://github.com/ggml-org/whisper.cpp.git "${WORK_DIR}"
+fi
+
+cmake -S "${WORK_DIR}" -B "${WORK_DIR}/build" \
+  -DCMAKE_BUILD_TYPE=Release \
+  -DBUILD_SHARED_LIBS=OFF \
+  -DWHISPER_BUILD_TESTS=OFF \
+  -DWHISPER_BUILD_EXAMPLES=ON
+cmake --build "${WORK_DIR}/build" --config Release --target whisper-cli
+
+BUILT="$(find "${WORK_DIR}/build" -type f -name "whisper-cli${EXT}" | head -n 1)"
+if [[ -z "${BUILT}" ]]; then
+  echo "whisper-cli${EXT} was not produced by the build" >&2
+  exit 1
+fi
+
+mkdir -p "${OUT_DIR}"
+cp "${BUILT}" "${OUT_DIR}/whisper-cli-${TRIPLE}${EXT}"
+echo "Sidecar ready: ${OUT_DIR}/whisper-cli-${TRIPLE}${EXT}"
@@ -0,0 +1,168 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::{format_seconds, llm, TranscriptionSegment, WhisperConfig};
+
+const COMMITMENT_CUES: &[&str] = &[
+    "やります",
+    "やっておきます",
+    "対応します",
+    "確認します",
+    "確認しておきます",
+    "調べます",
+    "調べておきます",
+    "送ります",
+    "送っておきます",
+    "共有します",
+    "共有しておきます",
+    "作成します",
+    "準備します",
+    "まとめます",
+    "連絡します",
+    "i'll ",
+    "i will ",
+    "i can take",
+];
+
+const REQUEST_CUES: &[&str] = &[
+    "お願いします",
+    "お願いできますか",
+    "お願いできますでしょうか",
+    "してください",
+    "しておいてください",
+    "can you ",
+    "could you ",
+    "please ",
+];
+
+const EXPLICIT_CUES: &[&str] = &["todo", "宿題", "アクションアイテム", "action item"];
+
+const LLM_INSTRUCTIONS: &str = "Extract action items from the meeting transcript. \
+Each line is \"[time] speaker: text\". Respond with a JSON object \
+{\"items\": [{\"owner\": string|null, \"task\": string, \"due\": string|null, \"time\": string|null}]}. \
+Guess the owner from the speaker labels and write tasks in the transcript's language.";
+
+static ADDRESSEE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"([\p{Han}\p{Katakana}\p{Hiragana}A-Za-z]{1,10})さん").unwrap());
+static DUE_JA: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"([^、。,\s]{1,12})までに").unwrap());
+static DUE_EN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\bby (monday|tuesday|wednesday|thursday|friday|saturday|sunday|tomorrow|next week|end of (?:the )?(?:day|week|month))\b",
+    )
+    .unwrap()
+});
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ActionItem {
+    owner: Option<String>,
+    task: String,
+    due: Option<String>,
+    time: Option<String>,
+}
+
+fn contains_any(text: &str, cues: &[&str]) -> bool {
+    cues.iter().any(|cue| text.contains(cue))
+}
+
+fn find_due(text: &str) -> Option<String> {
+    DUE_JA
+        .captures(text)
+        .or_else(|| DUE_EN.captures(text))
+        .map(|captures| captures[1].to_string())
+}
+
+fn rule_based(segments: &[TranscriptionSegment]) -> Vec<ActionItem> {
+    let mut items = Vec::new();
+    for (index, segment) in segments.iter().enumerate() {
+        let text = segment.text.trim();
+        let lower = format!("{} ", text.to_lowercase());
+        let owner = if contains_any(&lower, REQUEST_CUES) {
+            ADDRESSEE
+                .captures(text)
+                .map(|captures| captures[1].to_string())
+                .or_else(|| {
+                    segments[index + 1..]
+                        .iter()
+                        .map(|next| &next.speaker)
+                        .find(|speaker| **speaker != segment.speaker)
+                        .cloned()
+                })
+        } else if contains_any(&lower, COMMITMENT_CUES) || contains_any(&lower, EXPLICIT_CUES) {
+            Some(segment.speaker.clone())
+        } else {
+            continue;
+        };
+        items.push(ActionItem {
+            owner: owner.filter(|owner| !owner.trim().is_empty()),
+            task: text.to_string(),
+            due: find_due(text),
+            time: Some(format_seconds(segment.start)),
+        });
+    }
+    items
+}
+
+async fn llm_based(
+    whisper: &WhisperConfig,
+    proxy_url: &str,
+    model: &str,
+    segments: &[TranscriptionSegment],
+) -> anyhow::Result<Vec<ActionItem>> {
+    let transcript: String = segments
+        .iter()
+        .map(|segment| {
+            format!(
+                "[{}] {}: {}\n",
+                format_seconds(segment.start),
+                segment.speaker,
+                segment.text.trim()
+            )
+        })
+        .collect();
+    let value = llm::chat_json(whisper, proxy_url, model, LLM_INSTRUCTIONS, &transcript).await?;
+    let items = value.get("items").cloned().unwrap_or_default();
+    Ok(serde_json::from_value(items)?)
+}
+
+pub(crate) async fn extract(
+    whisper: &WhisperConfig,
+    proxy_url: &str,
+    segments: &[TranscriptionSegment],
+    log: impl Fn(&str),
+) -> Vec<ActionItem> {
+    let model = whisper.action_items_model.trim();
+    if !model.is_empty() {
+        match llm_based(whisper, proxy_url, model, segments).await {
+            Ok(items) => return items,
+            Err(err) => log(&format!(
+                "Action item extraction via {model} failed, using rules instead: {err}"
+            )),
+        }
+    }
+    rule_based(segments)
+}
+
+pub(crate) fn render(items: &[ActionItem]) -> String {
+    let mut output = String::from("\n## アクションアイテム\n");
+    if items.is_empty() {
+        output.push_str("- なし\n");
+    }
+    for item in items {
+        output.push_str("- ");
+        if let Some(time) = &item.time {
+            output.push_str(&format!("{time} "));
+        }
+        if let Some(owner) = &item.owner {
+            output.push_str(&format!("[{owner}] "));
+        }
+        output.push_str(item.task.trim());
+        if let Some(due) = &item.due {
+            output.push_str(&format!("（期限: {due}）"));
+        }
+        output.push('\n');
+    }
+    output
+}
@@ -0,0 +1,152 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use chrono::NaiveDate;
+use serde::Serialize;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::{effective_config, output_root, parse_date_any, transcripts};
+
+const MANIFEST_SUFFIX: &str = ".manifest.json";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ExportSummary {
+    path: String,
+    transcripts: usize,
+    files: usize,
+}
+
+struct ArchiveEntry {
+    date: NaiveDate,
+    stem: String,
+}
+
+fn collect_entries(root: &Path, from: NaiveDate, to: NaiveDate) -> Result<Vec<ArchiveEntry>> {
+    let mut entries = Vec::new();
+    let dir = match std::fs::read_dir(root) {
+        Ok(dir) => dir,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(entries),
+        Err(err) => return Err(err.into()),
+    };
+    for entry in dir {
+        let name = entry?.file_name().to_string_lossy().to_string();
+        let Some(stem) = name.strip_suffix(MANIFEST_SUFFIX) else {
+            continue;
+        };
+        let (date, _, _) = transcripts::split_stem(stem);
+        let Some(date) = parse_date_any(&date) else {
+            continue;
+        };
+        if date >= from && date <= to {
+            entries.push(ArchiveEntry {
+                date,
+                stem: stem.to_string(),
+            });
+        }
+    }
+    entries.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.stem.cmp(&b.stem)));
+    Ok(entries)
+}
+
+fn files_for(root: &Path, stem: &str) -> Result<Vec<PathBuf>> {
+    let prefix = format!("{stem}.");
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(root)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with(&prefix)
+            && !name.ends_with(MANIFEST_SUFFIX)
+            && entry.file_type()?.is_file()
+        {
+            files.push(entry.path());
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn write_zip(root: &Path, entries: &[ArchiveEntry], file: std::fs::File) -> Result<usize> {
+    let mut writer = ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let mut files = 0;
+    for entry in entries {
+        for path in files_for(root, &entry.stem)? {
+            let name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            writer.start_file(format!("{}/{name}", entry.date.format("%Y-%m-%d")), options)?;
+            writer.write_all(&std::fs::read(&path)?)?;
+            files += 1;
+        }
+    }
+    writer.finish()?;
+    Ok(files)
+}
+
+fn write_archive(
+    root: &Path,
+    entries: &[ArchiveEntry],
+    destination: &Path,
+) -> Result<ExportSummary> {
+    let partial = destination.with_extension("zip.part");
+    let files = match write_zip(root, entries, std::fs::File::create(&partial)?) {
+        Ok(files) => files,
+        Err(err) => {
+            let _ = std::fs::remove_file(&partial);
+            return Err(err);
+        }
+    };
+    std::fs::rename(&partial, destination)?;
+    Ok(ExportSummary {
+        path: destination.to_string_lossy().to_string(),
+        transcripts: entries.len(),
+        files,
+    })
+}
+
+pub(crate) async fn export(from: &str, to: &str, destination: &str) -> Result<ExportSummary> {
+    let from_date =
+        parse_date_any(from.trim()).ok_or_else(|| anyhow!("Invalid start date: {from}"))?;
+    let to_date = parse_date_any(to.trim()).ok_or_else(|| anyhow!("Invalid end date: {to}"))?;
+    if from_date > to_date {
+        return Err(anyhow!("Start date {from} is after end date {to}"));
+    }
+    let config = effective_config().await?;
+    let root = output_root(&config)?;
+    let mut destination = PathBuf::from(destination.trim());
+    if destination.as_os_str().is_empty() {
+        return Err(anyhow!("No destination selected"));
+    }
+    if destination.is_dir() {
+        destination = destination.join(format!(
+            "transcripts_{}_{}.zip",
+            from_date.format("%Y%m%d"),
+            to_date.format("%Y%m%d")
+        ));
+    }
+    tokio::task::spawn_blocking(move || {
+        let entries = collect_entries(&root, from_date, to_date)?;
+        if entries.is_empty() {
+            return Err(anyhow!(
+                "No transcripts found between {from_date} and {to_date}"
+            ));
+        }
+        write_archive(&root, &entries, &destination)
+    })
+    .await?
+}
+
+#[tauri::command]
+pub async fn export_transcripts_zip(
+    from: String,
+    to: String,
+    destination: String,
+) -> Result<ExportSummary, String> {
+    export(&from, &to, &destination)
+        .await
+        .map_err(|err| err.to_string())
+}
@@ -0,0 +1,326 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use percent_encoding::utf8_percent_encode;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use sha2::Sha256;
+use tokio::fs;
+
+use crate::models::download_client;
+use crate::storage::{write_response, ProgressCallback, StorageSource, PATH_SEGMENT};
+use crate::AppConfig;
+
+const AZURE_API_VERSION: &str = "2021-08-06";
+
+enum AzureAuth {
+    SharedKey { account: String, key: Vec<u8> },
+    Sas(Vec<(String, String)>),
+    Anonymous,
+}
+
+#[derive(Default)]
+struct ListPage {
+    blobs: Vec<String>,
+    prefixes: Vec<String>,
+    next_marker: Option<String>,
+}
+
+pub(crate) struct AzureBlobSource {
+    client: reqwest::Client,
+    endpoint: String,
+    container: String,
+    auth: AzureAuth,
+    key_root: String,
+}
+
+fn parse_connection_string(value: &str) -> Vec<(String, String)> {
+    value
+        .split(';')
+        .filter_map(|part| part.split_once('='))
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .collect()
+}
+
+fn parse_sas(value: &str) -> Result<Vec<(String, String)>> {
+    let value = value.trim().trim_start_matches('?');
+    let url = reqwest::Url::parse(&format!("https://sas.invalid/?{value}"))
+        .with_context(|| "Invalid SAS token")?;
+    Ok(url.query_pairs().into_owned().collect())
+}
+
+impl AzureBlobSource {
+    pub(crate) fn from_config(config: &AppConfig) -> Result<Self> {
+        let minio = &config.minio;
+        let connection_string = minio.azure_connection_string.trim();
+        let sas_url = minio.azure_sas_url.trim();
+        let mut container = minio.azure_container.trim().to_string();
+        let (endpoint, auth) = if !connection_string.is_empty() {
+            let parts = parse_connection_string(connection_string);
+            let get = |name: &str| {
+                parts
+                    .iter()
+                    .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                    .map(|(_, value)| value.as_str())
+            };
+            let account = get("AccountName").unwrap_or_default();
+            let endpoint = match get("BlobEndpoint") {
+                Some(endpoint) => endpoint.trim_end_matches('/').to_string(),
+                None if !account.is_empty() => format!(
+                    "{}://{account}.blob.{}",
+                    get("DefaultEndpointsProtocol").unwrap_or("https"),
+                    get("EndpointSuffix").unwrap_or("core.windows.net")
+                ),
+                None => return Err(anyhow!("Azure connection string has no account name")),
+            };
+            let auth = if let Some(sas) = get("SharedAccessSignature") {
+                AzureAuth::Sas(parse_sas(sas)?)
+            } else if let Some(key) = get("AccountKey") {
+                AzureAuth::SharedKey {
+                    account: account.to_string(),
+                    key: BASE64
+                        .decode(key)
+                        .with_context(|| "Invalid Azure account key")?,
+                }
+            } else {
+                AzureAuth::Anonymous
+            };
+            (endpoint, auth)
+        } else if !sas_url.is_empty() {
+            let url = reqwest::Url::parse(sas_url)
+                .with_context(|| format!("Invalid Azure SAS URL: {sas_url}"))?;
+            let path = url.path().trim_matches('/');
+            if container.is_empty() {
+                container = path.split('/').next().unwrap_or_default().to_string();
+            }
+            let endpoint = url.origin().ascii_serialization();
+            (
+                endpoint,
+                AzureAuth::Sas(url.query_pairs().into_owned().collect()),
+            )
+        } else {
+            return Err(anyhow!(
+                "Azure connection string or SAS URL is not configured"
+            ));
+        };
+        if container.is_empty() {
+            return Err(anyhow!("Azure container is not configured"));
+        }
+        Ok(Self {
+            client: download_client(&config.proxy_url)?,
+            endpoint,
+            container,
+            auth,
+            key_root: minio.key_root(),
+        })
+    }
+
+    fn blob_path(&self, blob: &str) -> String {
+        let mut path = format!("/{}", self.container);
+        for segment in blob.split('/') {
+            path.push('/');
+            path.push_str(&utf8_percent_encode(segment, PATH_SEGMENT).to_string());
+        }
+        path
+    }
+
+    fn request(
+        &self,
+        path: &str,
+        mut query: Vec<(String, String)>,
+    ) -> Result<reqwest::RequestBuilder> {
+        let date = chrono::Utc::now()
+            .format("%a, %d %b %Y %H:%M:%S GMT")
+            .to_string();
+        let mut request = self
+            .client
+            .get(format!("{}{path}", self.endpoint))
+            .header("x-ms-date", &date)
+            .header("x-ms-version", AZURE_API_VERSION);
+        match &self.auth {
+            AzureAuth::SharedKey { account, key } => {
+                query.sort();
+                let endpoint_path = reqwest::Url::parse(&self.endpoint)
+                    .map(|url| url.path().trim_end_matches('/').to_string())
+                    .unwrap_or_default();
+                let mut resource = format!("/{account}{endpoint_path}{path}");
+                for (name, value) in &query {
+                    resource.push_str(&format!("\n{}:{value}", name.to_ascii_lowercase()));
+                }
+                let string_to_sign = format!(
+                    "GET\n\n\n\n\n\n\n\n\n\n\n\nx-ms-date:{date}\nx-ms-version:{AZURE_API_VERSION}\n{resource}"
+                );
+                let mut mac = Hmac::<Sha256>::new_from_slice(key)
+                    .map_err(|err| anyhow!("Invalid Azure account key: {err}"))?;
+                mac.update(string_to_sign.as_bytes());
+                let signature = BASE64.encode(mac.finalize().into_bytes());
+                request = request.header(
+                    reqwest::header::AUTHORIZATION,
+                    format!("SharedKey {account}:{signature}"),
+                );
+            }
+            AzureAuth::Sas(params) => query.extend(params.iter().cloned()),
+            AzureAuth::Anonymous => {}
+        }
+        Ok(request.query(&query))
+    }
+
+    async fn list_page(
+        &self,
+        prefix: &str,
+        delimiter: bool,
+        marker: Option<&str>,
+    ) -> Result<ListPage> {
+        let mut query = vec![
+            ("restype".to_string(), "container".to_string()),
+            ("comp".to_string(), "list".to_string()),
+            ("prefix".to_string(), prefix.to_string()),
+        ];
+        if delimiter {
+            query.push(("delimiter".to_string(), "/".to_string()));
+        }
+        if let Some(marker) = marker {
+            query.push(("marker".to_string(), marker.to_string()));
+        }
+        let path = format!("/{}", self.container);
+        let response = self
+            .request(&path, query)?
+            .send()
+            .await
+            .with_context(|| format!("Failed to list container {}", self.container))?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(anyhow!("Azure listing failed with {status}: {body}"));
+        }
+        parse_list_page(&response.text().await?)
+    }
+
+    async fn list_all(&self, prefix: &str, delimiter: bool) -> Result<ListPage> {
+        let mut result = ListPage::default();
+        let mut marker: Option<String> = None;
+        loop {
+            let page = self.list_page(prefix, delimiter, marker.as_deref()).await?;
+            result.blobs.extend(page.blobs);
+            result.prefixes.extend(page.prefixes);
+            marker = page.next_marker;
+            if marker.is_none() {
+                break;
+            }
+        }
+        Ok(result)
+    }
+}
+
+fn parse_list_page(body: &str) -> Result<ListPage> {
+    let mut reader = Reader::from_str(body);
+    reader.config_mut().trim_text(true);
+    let mut page = ListPage::default();
+    let mut stack: Vec<Vec<u8>> = Vec::new();
+    loop {
+        match reader.read_event()? {
+            Event::Start(tag) => stack.push(tag.local_name().as_ref().to_vec()),
+            Event::End(_) => {
+                stack.pop();
+            }
+            Event::Text(text) => {
+                let value = text.unescape()?.to_string();
+                let parent = stack
+                    .len()
+                    .checked_sub(2)
+                    .map(|index| stack[index].as_slice());
+                match (parent, stack.last().map(Vec::as_slice)) {
+                    (Some(b"Blob"), Some(b"Name")) => page.blobs.push(value),
+                    (Some(b"BlobPrefix"), Some(b"Name")) => page.prefixes.push(value),
+                    (_, Some(b"NextMarker")) if !value.is_empty() => page.next_marker = Some(value),
+                    _ => {}
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(page)
+}
+
+#[async_trait]
+impl StorageSource for AzureBlobSource {
+    fn name(&self) -> &'static str {
+        "azure"
+    }
+
+    async fn check(&self) -> Result<()> {
+        let mut query = vec![
+            ("restype".to_string(), "container".to_string()),
+            ("comp".to_string(), "list".to_string()),
+            ("maxresults".to_string(), "1".to_string()),
+        ];
+        if !self.key_root.is_empty() {
+            query.push(("prefix".to_string(), self.key_root.clone()));
+        }
+        let path = format!("/{}", self.container);
+        let response = self
+            .request(&path, query)?
+            .send()
+            .await
+            .with_context(|| format!("Failed to connect to {}", self.endpoint))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(anyhow!("Azure check failed with {status}"));
+        }
+        Ok(())
+    }
+
+    async fn list_prefixes(&self, prefix: &str) -> Result<Vec<String>> {
+        let full_prefix = format!("{}{prefix}", self.key_root);
+        let page = self.list_all(&full_prefix, true).await?;
+        Ok(page
+            .prefixes
+            .iter()
+            .map(|value| {
+                value
+                    .strip_prefix(full_prefix.as_str())
+                    .unwrap_or(value)
+                    .trim_end_matches('/')
+                    .to_string()
+            })
+            .filter(|value| !value.is_empty())
+            .collect())
+    }
+
+    async fn list_objects(&self, prefix: &str) -> Result<Vec<String>> {
+        let full_prefix = format!("{}{prefix}", self.key_root);
+        let page = self.list_all(&full_prefix, false).await?;
+        Ok(page
+            .blobs
+            .iter()
+            .map(|name| {
+                name.strip_prefix(self.key_root.as_str())
+                    .unwrap_or(name)
+                    .to_string()
+            })
+            .collect())
+    }
+
+    async fn get_object(
+        &self,
+        key: &str,
+        dest: &Path,
+        on_progress: ProgressCallback<'_>,
+    ) -> Result<()> {
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        let path = self.blob_path(&format!("{}{key}", self.key_root));
+        let response = self
+            .request(&path, Vec::new())?
+            .send()
+            .await
+            .with_context(|| format!("Failed to download {key}"))?;
+        write_response(response, key, dest, on_progress).await
+    }
+}
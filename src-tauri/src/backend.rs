@@ -0,0 +1,1175 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use async_trait::async_trait;
+use tokio::fs;
+use tokio::io::AsyncBufReadExt;
+use tokio::process::Command;
+
+use crate::models::download_client;
+use crate::{
+    append_log, ensure_whisper_resources, extract_segments_from_value, find_in_path,
+    group_word_segments, normalize_json_contents, parse_json_lines, run_whisper_segments,
+    word_from_value, AppConfig, JobState, SegmentCallback, WhisperConfig, WhisperSegment,
+    WordTiming,
+};
+
+#[async_trait]
+pub(crate) trait TranscriptionBackend: Send {
+    fn name(&self) -> &'static str;
+
+    async fn transcribe(
+        &mut self,
+        input: &Path,
+        output_base: &Path,
+        jobs_state: &JobState,
+        job_id: &str,
+        on_segment: Option<SegmentCallback>,
+    ) -> Result<Vec<WhisperSegment>>;
+
+    fn accepts_original_audio(&self) -> bool {
+        false
+    }
+
+    async fn shutdown(&mut self) {}
+}
+
+pub(crate) async fn create_backend(
+    config: &AppConfig,
+    jobs_state: &JobState,
+    job_id: &str,
+    single_track: bool,
+) -> Result<Box<dyn TranscriptionBackend>> {
+    match config.whisper.backend.as_str() {
+        "openai" => Ok(Box::new(OpenAiTranscriber::from_config(
+            &config.whisper,
+            &config.proxy_url,
+        )?)),
+        "external" => Ok(Box::new(ExternalTranscriber::from_config(&config.whisper)?)),
+        "azure" => Ok(Box::new(AzureSpeechTranscriber::from_config(
+            &config.whisper,
+            &config.proxy_url,
+        )?)),
+        // Per-speaker tracks already identify the speaker; only diarize a mixed recording.
+        "deepgram" => Ok(Box::new(DeepgramTranscriber::from_config(
+            &config.whisper,
+            &config.proxy_url,
+            single_track,
+        )?)),
+        #[cfg(feature = "vosk")]
+        "vosk" => Ok(Box::new(
+            VoskTranscriber::from_config(&config.whisper).await?,
+        )),
+        #[cfg(not(feature = "vosk"))]
+        "vosk" => Err(anyhow!(
+            "This build does not include the Vosk backend. Rebuild with --features vosk."
+        )),
+        "server" => {
+            let (binary_path, model_path) = ensure_whisper_resources(config).await?;
+            let server_binary = resolve_whisper_server_binary(config, &binary_path)?;
+            append_log(
+                jobs_state,
+                job_id,
+                &format!("Starting whisper-server: {}", server_binary.display()),
+            );
+            let server = WhisperServer::start(
+                &server_binary,
+                &model_path,
+                &config.whisper,
+                jobs_state,
+                job_id,
+            )
+            .await?;
+            Ok(Box::new(server))
+        }
+        _ => {
+            let (binary_path, model_path) = ensure_whisper_resources(config).await?;
+            let needs_cli = config.whisper.stream_partial_results
+                || config.whisper.diarization.trim() == "tinydiarize";
+            if config.whisper.reuse_model && needs_cli {
+                append_log(
+                    jobs_state,
+                    job_id,
+                    "Loading model per track: live partial results and tinydiarize need whisper-cli",
+                );
+            } else if config.whisper.reuse_model {
+                match start_persistent_server(config, &binary_path, &model_path, jobs_state, job_id)
+                    .await
+                {
+                    Ok(server) => return Ok(Box::new(server)),
+                    Err(err) => append_log(
+                        jobs_state,
+                        job_id,
+                        &format!(
+                            "Persistent whisper-server unavailable, loading model per track: {err}"
+                        ),
+                    ),
+                }
+            }
+            Ok(Box::new(WhisperCliBackend {
+                binary_path,
+                model_path,
+                args: whisper_cli_args(&config.whisper),
+                word_timestamps: config.whisper.word_timestamps,
+            }))
+        }
+    }
+}
+
+async fn start_persistent_server(
+    config: &AppConfig,
+    cli_binary: &Path,
+    model_path: &Path,
+    jobs_state: &JobState,
+    job_id: &str,
+) -> Result<WhisperServer> {
+    let server_binary = resolve_whisper_server_binary(config, cli_binary)?;
+    append_log(
+        jobs_state,
+        job_id,
+        &format!(
+            "Keeping model loaded with whisper-server: {}",
+            server_binary.display()
+        ),
+    );
+    WhisperServer::start(
+        &server_binary,
+        model_path,
+        &config.whisper,
+        jobs_state,
+        job_id,
+    )
+    .await
+}
+
+struct WhisperCliBackend {
+    binary_path: PathBuf,
+    model_path: PathBuf,
+    args: Vec<String>,
+    word_timestamps: bool,
+}
+
+#[async_trait]
+impl TranscriptionBackend for WhisperCliBackend {
+    fn name(&self) -> &'static str {
+        "whisper-cli"
+    }
+
+    async fn transcribe(
+        &mut self,
+        input: &Path,
+        output_base: &Path,
+        jobs_state: &JobState,
+        job_id: &str,
+        on_segment: Option<SegmentCallback>,
+    ) -> Result<Vec<WhisperSegment>> {
+        let segments = run_whisper_segments(
+            &self.binary_path,
+            &self.model_path,
+            input,
+            output_base,
+            &self.args,
+            jobs_state,
+            job_id,
+            on_segment,
+        )
+        .await?;
+        if self.word_timestamps {
+            Ok(group_word_segments(segments))
+        } else {
+            Ok(segments)
+        }
+    }
+}
+
+pub(crate) fn gpu_backend_log(line: &str) -> Option<String> {
+    let (_, rest) = line.split_once("whisper_backend_init_gpu:")?;
+    let rest = rest.trim();
+    if rest.starts_with("no GPU found") {
+        return Some(
+            "Warning: GPU acceleration enabled but whisper found no GPU backend; running on CPU"
+                .to_string(),
+        );
+    }
+    let device = rest.strip_prefix("using ")?.split_whitespace().next()?;
+    let lower = device.to_lowercase();
+    let backend = GPU_BACKENDS
+        .iter()
+        .find(|(needle, _)| lower.contains(needle))
+        .map(|(_, label)| label.to_string())
+        .unwrap_or_else(|| device.to_string());
+    Some(format!("GPU acceleration: {backend}"))
+}
+
+pub(crate) fn whisper_cli_args(whisper: &WhisperConfig) -> Vec<String> {
+    let mut args = vec!["-l".to_string(), whisper.language_code().to_string()];
+    if let Some(threads) = whisper.threads.filter(|value| *value > 0) {
+        args.push("-t".to_string());
+        args.push(threads.to_string());
+    }
+    if let Some(beam_size) = whisper.beam_size.filter(|value| *value > 0) {
+        args.push("-bs".to_string());
+        args.push(beam_size.to_string());
+    }
+    if let Some(best_of) = whisper.best_of.filter(|value| *value > 0) {
+        args.push("-bo".to_string());
+        args.push(best_of.to_string());
+    }
+    if let Some(temperature) = whisper.temperature.filter(|value| *value >= 0.0) {
+        args.push("-tp".to_string());
+        args.push(temperature.to_string());
+    }
+    if whisper.word_timestamps {
+        args.push("-ml".to_string());
+        args.push("1".to_string());
+        args.push("-sow".to_string());
+    }
+    if whisper.diarization.trim() == "tinydiarize" {
+        args.push("-tdrz".to_string());
+    }
+    if whisper.gpu {
+        if let Some(device) = whisper.gpu_device {
+            args.push("-dev".to_string());
+            args.push(device.to_string());
+        }
+    } else {
+        args.push("-ng".to_string());
+    }
+    args
+}
+
+const GPU_BACKENDS: [(&str, &str); 3] =
+    [("cuda", "CUDA"), ("metal", "Metal"), ("vulkan", "Vulkan")];
+
+pub(crate) fn detect_gpu_backend(binary_path: &Path) -> Option<&'static str> {
+    let binary_dir = binary_path.parent()?;
+    let mut dirs = vec![binary_dir.to_path_buf()];
+    if let Some(prefix) = binary_dir.parent() {
+        dirs.push(prefix.join("lib"));
+    }
+    for dir in dirs {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_lowercase();
+            if !name.contains("ggml") {
+                continue;
+            }
+            for (needle, label) in GPU_BACKENDS {
+                if name.contains(needle) {
+                    return Some(label);
+                }
+            }
+        }
+    }
+    None
+}
+
+struct WhisperServer {
+    child: tokio::process::Child,
+    base_url: String,
+    http: reqwest::Client,
+    word_timestamps: bool,
+}
+
+impl WhisperServer {
+    async fn start(
+        binary_path: &Path,
+        model_path: &Path,
+        whisper: &WhisperConfig,
+        jobs_state: &JobState,
+        job_id: &str,
+    ) -> Result<Self> {
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .and_then(|listener| listener.local_addr())
+            .with_context(|| "Failed to reserve a port for whisper-server")?
+            .port();
+        let mut child = Command::new(binary_path)
+            .arg("-m")
+            .arg(model_path)
+            .args(whisper_cli_args(whisper))
+            .arg("--host")
+            .arg("127.0.0.1")
+            .arg("--port")
+            .arg(port.to_string())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .with_context(|| {
+                format!(
+                    "Failed to execute whisper-server: {}",
+                    binary_path.display()
+                )
+            })?;
+
+        let stderr = child
+            .stderr
+            .take()
+            .ok_or_else(|| anyhow!("Failed to capture whisper-server stderr"))?;
+        let stderr_state = jobs_state.clone();
+        let stderr_job = job_id.to_string();
+        tokio::spawn(async move {
+            let mut lines = tokio::io::BufReader::new(stderr).lines();
+            while let Some(line) = lines.next_line().await? {
+                if !line.trim().is_empty() {
+                    append_log(&stderr_state, &stderr_job, &line);
+                }
+                if let Some(message) = gpu_backend_log(&line) {
+                    append_log(&stderr_state, &stderr_job, &message);
+                }
+            }
+            Ok::<(), anyhow::Error>(())
+        });
+
+        let server = Self {
+            child,
+            base_url: format!("http://127.0.0.1:{port}"),
+            http: reqwest::Client::new(),
+            word_timestamps: whisper.word_timestamps,
+        };
+        server.wait_ready().await?;
+        Ok(server)
+    }
+
+    async fn wait_ready(&self) -> Result<()> {
+        const READY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(300);
+        let started = std::time::Instant::now();
+        while started.elapsed() < READY_TIMEOUT {
+            if self.http.get(&self.base_url).send().await.is_ok() {
+                return Ok(());
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        }
+        Err(anyhow!("whisper-server did not become ready in time"))
+    }
+}
+
+#[async_trait]
+impl TranscriptionBackend for WhisperServer {
+    fn name(&self) -> &'static str {
+        "whisper-server"
+    }
+
+    async fn transcribe(
+        &mut self,
+        input: &Path,
+        _output_base: &Path,
+        _jobs_state: &JobState,
+        _job_id: &str,
+        on_segment: Option<SegmentCallback>,
+    ) -> Result<Vec<WhisperSegment>> {
+        if let Some(status) = self.child.try_wait()? {
+            return Err(anyhow!("whisper-server exited unexpectedly: {status}"));
+        }
+        let data = fs::read(input)
+            .await
+            .with_context(|| format!("Failed to read audio: {}", input.display()))?;
+        let file_name = input
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "audio.wav".to_string());
+        let part = reqwest::multipart::Part::bytes(data)
+            .file_name(file_name)
+            .mime_str("audio/wav")?;
+        let form = reqwest::multipart::Form::new()
+            .part("file", part)
+            .text("response_format", "verbose_json");
+        let response = self
+            .http
+            .post(format!("{}/inference", self.base_url))
+            .multipart(form)
+            .send()
+            .await
+            .with_context(|| "Failed to send audio to whisper-server")?;
+        let status = response.status();
+        let body = response.text().await?;
+        if !status.is_success() {
+            return Err(anyhow!("whisper-server returned {status}: {body}"));
+        }
+        let mut segments = parse_server_response(&body)?;
+        if self.word_timestamps {
+            segments = group_word_segments(segments);
+        }
+        if let Some(callback) = on_segment.as_ref() {
+            for segment in &segments {
+                callback(segment.clone());
+            }
+        }
+        Ok(segments)
+    }
+
+    async fn shutdown(&mut self) {
+        let _ = self.child.kill().await;
+    }
+}
+
+fn parse_server_response(body: &str) -> Result<Vec<WhisperSegment>> {
+    let json = normalize_json_contents(body);
+    let value: serde_json::Value =
+        serde_json::from_str(&json).with_context(|| "Failed to parse whisper-server response")?;
+    if let Some(error) = value.get("error").and_then(|v| v.as_str()) {
+        return Err(anyhow!("whisper-server error: {error}"));
+    }
+    if let Some(segments) = extract_segments_from_value(value.clone()) {
+        return Ok(segments);
+    }
+    let text = value
+        .get("text")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .trim()
+        .to_string();
+    if text.is_empty() {
+        return Ok(Vec::new());
+    }
+    Ok(vec![WhisperSegment {
+        start: 0.0,
+        end: None,
+        text,
+        words: Vec::new(),
+        speaker: None,
+        speaker_turn_next: false,
+    }])
+}
+
+struct OpenAiTranscriber {
+    http: reqwest::Client,
+    api_key: String,
+    base_url: String,
+    model: String,
+    language: String,
+    temperature: Option<f32>,
+    word_timestamps: bool,
+}
+
+impl OpenAiTranscriber {
+    fn from_config(whisper: &WhisperConfig, proxy_url: &str) -> Result<Self> {
+        let api_key = whisper.openai_api_key.trim();
+        if api_key.is_empty() {
+            return Err(anyhow!("OpenAI API key is not configured"));
+        }
+        let base_url = whisper.openai_base_url.trim().trim_end_matches('/');
+        let model = whisper.openai_model.trim();
+        Ok(Self {
+            http: download_client(proxy_url)?,
+            api_key: api_key.to_string(),
+            base_url: if base_url.is_empty() {
+                "https://api.openai.com/v1".to_string()
+            } else {
+                base_url.to_string()
+            },
+            model: if model.is_empty() {
+                "whisper-1".to_string()
+            } else {
+                model.to_string()
+            },
+            language: whisper.language_code().to_string(),
+            temperature: whisper.temperature,
+            word_timestamps: whisper.word_timestamps,
+        })
+    }
+}
+
+#[async_trait]
+impl TranscriptionBackend for OpenAiTranscriber {
+    fn name(&self) -> &'static str {
+        "openai"
+    }
+
+    async fn transcribe(
+        &mut self,
+        input: &Path,
+        _output_base: &Path,
+        _jobs_state: &JobState,
+        _job_id: &str,
+        _on_segment: Option<SegmentCallback>,
+    ) -> Result<Vec<WhisperSegment>> {
+        let data = fs::read(input)
+            .await
+            .with_context(|| format!("Failed to read audio: {}", input.display()))?;
+        let file_name = input
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "audio.wav".to_string());
+        let part = reqwest::multipart::Part::bytes(data)
+            .file_name(file_name)
+            .mime_str("audio/wav")?;
+        let mut form = reqwest::multipart::Form::new()
+            .part("file", part)
+            .text("model", self.model.clone())
+            .text("language", self.language.clone())
+            .text("response_format", "verbose_json")
+            .text("timestamp_granularities[]", "segment");
+        if self.word_timestamps {
+            form = form.text("timestamp_granularities[]", "word");
+        }
+        if let Some(temperature) = self.temperature {
+            form = form.text("temperature", temperature.to_string());
+        }
+        let response = self
+            .http
+            .post(format!("{}/audio/transcriptions", self.base_url))
+            .bearer_auth(&self.api_key)
+            .multipart(form)
+            .send()
+            .await
+            .with_context(|| "Failed to send audio to OpenAI")?;
+        let status = response.status();
+        let body = response.text().await?;
+        if status == reqwest::StatusCode::PAYLOAD_TOO_LARGE {
+            return Err(anyhow!(
+                "Track is too large for the OpenAI transcription API (25MB limit)"
+            ));
+        }
+        if !status.is_success() {
+            return Err(anyhow!("OpenAI API returned {status}: {body}"));
+        }
+        let value: serde_json::Value =
+            serde_json::from_str(&body).with_context(|| "Failed to parse OpenAI response")?;
+        let words: Vec<WordTiming> = value
+            .get("words")
+            .and_then(|v| v.as_array())
+            .map(|items| items.iter().filter_map(word_from_value).collect())
+            .unwrap_or_default();
+        let mut segments = parse_server_response(&body)?;
+        if !words.is_empty() {
+            for segment in segments.iter_mut() {
+                let end = segment.end.unwrap_or(f64::MAX);
+                segment.words = words
+                    .iter()
+                    .filter(|word| word.start >= segment.start && word.start < end)
+                    .cloned()
+                    .collect();
+            }
+        }
+        Ok(segments)
+    }
+}
+
+struct ExternalTranscriber {
+    template: Vec<String>,
+    model: String,
+    language: String,
+}
+
+impl ExternalTranscriber {
+    fn from_config(whisper: &WhisperConfig) -> Result<Self> {
+        let template = split_command_line(whisper.external_command.trim());
+        if template.is_empty() {
+            return Err(anyhow!("External transcription command is not configured"));
+        }
+        Ok(Self {
+            template,
+            model: whisper.model_path.trim().to_string(),
+            language: whisper.language_code().to_string(),
+        })
+    }
+}
+
+#[async_trait]
+impl TranscriptionBackend for ExternalTranscriber {
+    fn name(&self) -> &'static str {
+        "external"
+    }
+
+    async fn transcribe(
+        &mut self,
+        input: &Path,
+        output_base: &Path,
+        jobs_state: &JobState,
+        job_id: &str,
+        _on_segment: Option<SegmentCallback>,
+    ) -> Result<Vec<WhisperSegment>> {
+        let output_dir = output_base
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(std::env::temp_dir);
+        let output_json = output_base.with_extension("json");
+        let args: Vec<String> = self
+            .template
+            .iter()
+            .map(|arg| {
+                arg.replace("{input}", &input.to_string_lossy())
+                    .replace("{output_dir}", &output_dir.to_string_lossy())
+                    .replace("{output_base}", &output_base.to_string_lossy())
+                    .replace("{output_json}", &output_json.to_string_lossy())
+                    .replace("{model}", &self.model)
+                    .replace("{language}", &self.language)
+            })
+            .collect();
+        let stdout_contents = run_logged_command(&args, jobs_state, job_id)
+            .await
+            .with_context(|| "External transcription command failed")?;
+
+        let mut candidates = vec![output_json];
+        if let Some(stem) = input.file_stem() {
+            candidates.push(output_dir.join(stem).with_extension("json"));
+        }
+        for candidate in candidates {
+            if let Ok(contents) = fs::read_to_string(&candidate).await {
+                if let Some(segments) = parse_segments_json(&contents) {
+                    return Ok(segments);
+                }
+            }
+        }
+        if let Some(segments) = parse_segments_json(&stdout_contents) {
+            return Ok(segments);
+        }
+        Err(anyhow!(
+            "External transcription command produced no segment JSON"
+        ))
+    }
+}
+
+struct AzureSpeechTranscriber {
+    http: reqwest::Client,
+    key: String,
+    region: String,
+    locale: String,
+}
+
+impl AzureSpeechTranscriber {
+    fn from_config(whisper: &WhisperConfig, proxy_url: &str) -> Result<Self> {
+        let key = whisper.azure_speech_key.trim();
+        let region = whisper.azure_speech_region.trim();
+        if key.is_empty() || region.is_empty() {
+            return Err(anyhow!(
+                "Azure Speech key and region must both be configured"
+            ));
+        }
+        Ok(Self {
+            http: download_client(proxy_url)?,
+            key: key.to_string(),
+            region: region.to_string(),
+            locale: azure_locale(whisper.language_code()),
+        })
+    }
+}
+
+#[async_trait]
+impl TranscriptionBackend for AzureSpeechTranscriber {
+    fn name(&self) -> &'static str {
+        "azure"
+    }
+
+    async fn transcribe(
+        &mut self,
+        input: &Path,
+        _output_base: &Path,
+        _jobs_state: &JobState,
+        _job_id: &str,
+        _on_segment: Option<SegmentCallback>,
+    ) -> Result<Vec<WhisperSegment>> {
+        let data = fs::read(input)
+            .await
+            .with_context(|| format!("Failed to read audio: {}", input.display()))?;
+        let file_name = input
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "audio.wav".to_string());
+        let part = reqwest::multipart::Part::bytes(data)
+            .file_name(file_name)
+            .mime_str("audio/wav")?;
+        let definition = serde_json::json!({ "locales": [self.locale] });
+        let form = reqwest::multipart::Form::new()
+            .part("audio", part)
+            .text("definition", definition.to_string());
+        let url = format!(
+            "https://{}.api.cognitive.microsoft.com/speechtotext/transcriptions:transcribe?api-version=2024-11-15",
+            self.region
+        );
+        let response = self
+            .http
+            .post(url)
+            .header("Ocp-Apim-Subscription-Key", &self.key)
+            .multipart(form)
+            .send()
+            .await
+            .with_context(|| "Failed to send audio to Azure Speech")?;
+        let status = response.status();
+        let body = response.text().await?;
+        if !status.is_success() {
+            return Err(anyhow!("Azure Speech returned {status}: {body}"));
+        }
+        let value: serde_json::Value =
+            serde_json::from_str(&body).with_context(|| "Failed to parse Azure Speech response")?;
+        Ok(azure_phrases_to_segments(&value))
+    }
+}
+
+fn azure_locale(language: &str) -> String {
+    match language {
+        "ja" => "ja-JP".to_string(),
+        "en" => "en-US".to_string(),
+        "zh" => "zh-CN".to_string(),
+        "ko" => "ko-KR".to_string(),
+        value if value.contains('-') => value.to_string(),
+        value => format!("{value}-{}", value.to_uppercase()),
+    }
+}
+
+fn azure_phrases_to_segments(value: &serde_json::Value) -> Vec<WhisperSegment> {
+    let millis = |item: &serde_json::Value, key: &str| {
+        item.get(key).and_then(|v| v.as_f64()).unwrap_or(0.0) / 1000.0
+    };
+    let Some(phrases) = value.get("phrases").and_then(|v| v.as_array()) else {
+        return Vec::new();
+    };
+    phrases
+        .iter()
+        .filter_map(|phrase| {
+            let text = phrase.get("text").and_then(|v| v.as_str())?.to_string();
+            let start = millis(phrase, "offsetMilliseconds");
+            let end = start + millis(phrase, "durationMilliseconds");
+            let words = phrase
+                .get("words")
+                .and_then(|v| v.as_array())
+                .map(|items| {
+                    items
+                        .iter()
+                        .filter_map(|word| {
+                            let text = word.get("text").and_then(|v| v.as_str())?.to_string();
+                            let start = millis(word, "offsetMilliseconds");
+                            let end = start + millis(word, "durationMilliseconds");
+                            Some(WordTiming { start, end, text })
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            Some(WhisperSegment {
+                start,
+                end: Some(end),
+                text,
+                words,
+                speaker: None,
+                speaker_turn_next: false,
+            })
+        })
+        .collect()
+}
+
+struct DeepgramTranscriber {
+    http: reqwest::Client,
+    api_key: String,
+    model: String,
+    language: String,
+    diarize: bool,
+}
+
+impl DeepgramTranscriber {
+    fn from_config(whisper: &WhisperConfig, proxy_url: &str, diarize: bool) -> Result<Self> {
+        let api_key = whisper.deepgram_api_key.trim();
+        if api_key.is_empty() {
+            return Err(anyhow!("Deepgram API key is not configured"));
+        }
+        let model = whisper.deepgram_model.trim();
+        Ok(Self {
+            http: download_client(proxy_url)?,
+            api_key: api_key.to_string(),
+            model: if model.is_empty() {
+                "nova-2".to_string()
+            } else {
+                model.to_string()
+            },
+            language: whisper.language_code().to_string(),
+            diarize,
+        })
+    }
+}
+
+#[async_trait]
+impl TranscriptionBackend for DeepgramTranscriber {
+    fn name(&self) -> &'static str {
+        "deepgram"
+    }
+
+    fn accepts_original_audio(&self) -> bool {
+        true
+    }
+
+    async fn transcribe(
+        &mut self,
+        input: &Path,
+        _output_base: &Path,
+        _jobs_state: &JobState,
+        _job_id: &str,
+        _on_segment: Option<SegmentCallback>,
+    ) -> Result<Vec<WhisperSegment>> {
+        let data = fs::read(input)
+            .await
+            .with_context(|| format!("Failed to read audio: {}", input.display()))?;
+        let content_type = match input
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase())
+            .as_deref()
+        {
+            Some("ogg" | "opus") => "audio/ogg",
+            Some("wav") => "audio/wav",
+            Some("mp3") => "audio/mpeg",
+            Some("m4a") => "audio/mp4",
+            Some("aac") => "audio/aac",
+            Some("flac") => "audio/flac",
+            Some("webm") => "audio/webm",
+            _ => "application/octet-stream",
+        };
+        let mut query = vec![
+            ("model", self.model.as_str()),
+            ("language", self.language.as_str()),
+            ("punctuate", "true"),
+            ("utterances", "true"),
+        ];
+        if self.diarize {
+            query.push(("diarize", "true"));
+        }
+        let response = self
+            .http
+            .post("https://api.deepgram.com/v1/listen")
+            .query(&query)
+            .header("Authorization", format!("Token {}", self.api_key))
+            .header("Content-Type", content_type)
+            .body(data)
+            .send()
+            .await
+            .with_context(|| "Failed to send audio to Deepgram")?;
+        let status = response.status();
+        let body = response.text().await?;
+        if !status.is_success() {
+            return Err(anyhow!("Deepgram returned {status}: {body}"));
+        }
+        let value: serde_json::Value =
+            serde_json::from_str(&body).with_context(|| "Failed to parse Deepgram response")?;
+        Ok(deepgram_utterances_to_segments(&value))
+    }
+}
+
+fn deepgram_utterances_to_segments(value: &serde_json::Value) -> Vec<WhisperSegment> {
+    let Some(utterances) = value
+        .get("results")
+        .and_then(|results| results.get("utterances"))
+        .and_then(|v| v.as_array())
+    else {
+        return Vec::new();
+    };
+    utterances
+        .iter()
+        .filter_map(|utterance| {
+            let text = utterance
+                .get("transcript")
+                .and_then(|v| v.as_str())?
+                .to_string();
+            let start = utterance
+                .get("start")
+                .and_then(|v| v.as_f64())
+                .unwrap_or(0.0);
+            let end = utterance.get("end").and_then(|v| v.as_f64());
+            let words = utterance
+                .get("words")
+                .and_then(|v| v.as_array())
+                .map(|items| {
+                    items
+                        .iter()
+                        .filter_map(|word| {
+                            let text = word
+                                .get("punctuated_word")
+                                .or_else(|| word.get("word"))
+                                .and_then(|v| v.as_str())?
+                                .to_string();
+                            let start = word.get("start").and_then(|v| v.as_f64())?;
+                            let end = word.get("end").and_then(|v| v.as_f64()).unwrap_or(start);
+                            Some(WordTiming { start, end, text })
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            let speaker = utterance
+                .get("speaker")
+                .and_then(|v| v.as_u64())
+                .map(|index| format!("話者{}", index + 1));
+            Some(WhisperSegment {
+                start,
+                end,
+                text,
+                words,
+                speaker,
+                speaker_turn_next: false,
+            })
+        })
+        .collect()
+}
+
+#[cfg(feature = "vosk")]
+struct VoskTranscriber {
+    model: std::sync::Arc<vosk::Model>,
+    strip_spaces: bool,
+}
+
+#[cfg(feature = "vosk")]
+impl VoskTranscriber {
+    async fn from_config(whisper: &WhisperConfig) -> Result<Self> {
+        let model_path = whisper.vosk_model_path.trim().to_string();
+        if model_path.is_empty() {
+            return Err(anyhow!("Vosk model directory is not configured"));
+        }
+        if !Path::new(&model_path).is_dir() {
+            return Err(anyhow!("Vosk model directory not found at {model_path}"));
+        }
+        let model = tokio::task::spawn_blocking(move || vosk::Model::new(model_path.as_str()))
+            .await?
+            .ok_or_else(|| anyhow!("Failed to load Vosk model"))?;
+        Ok(Self {
+            model: std::sync::Arc::new(model),
+            strip_spaces: matches!(whisper.language_code(), "ja" | "zh"),
+        })
+    }
+}
+
+#[cfg(feature = "vosk")]
+#[async_trait]
+impl TranscriptionBackend for VoskTranscriber {
+    fn name(&self) -> &'static str {
+        "vosk"
+    }
+
+    async fn transcribe(
+        &mut self,
+        input: &Path,
+        _output_base: &Path,
+        _jobs_state: &JobState,
+        _job_id: &str,
+        _on_segment: Option<SegmentCallback>,
+    ) -> Result<Vec<WhisperSegment>> {
+        let model = self.model.clone();
+        let input = input.to_path_buf();
+        let strip_spaces = self.strip_spaces;
+        tokio::task::spawn_blocking(move || run_vosk(&model, &input, strip_spaces)).await?
+    }
+}
+
+#[cfg(feature = "vosk")]
+fn run_vosk(model: &vosk::Model, input: &Path, strip_spaces: bool) -> Result<Vec<WhisperSegment>> {
+    let mut reader = hound::WavReader::open(input)
+        .with_context(|| format!("Failed to open wav: {}", input.display()))?;
+    let sample_rate = reader.spec().sample_rate as f32;
+    let mut recognizer = vosk::Recognizer::new(model, sample_rate)
+        .ok_or_else(|| anyhow!("Failed to create Vosk recognizer"))?;
+    recognizer.set_words(true);
+
+    let samples = reader
+        .samples::<i16>()
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .with_context(|| format!("Failed to decode wav: {}", input.display()))?;
+    let mut segments = Vec::new();
+    for chunk in samples.chunks(4000) {
+        let state = recognizer
+            .accept_waveform(chunk)
+            .map_err(|err| anyhow!("Vosk failed to decode audio: {err:?}"))?;
+        if let vosk::DecodingState::Finalized = state {
+            segments.extend(vosk_result_to_segment(recognizer.result(), strip_spaces));
+        }
+    }
+    segments.extend(vosk_result_to_segment(
+        recognizer.final_result(),
+        strip_spaces,
+    ));
+    Ok(segments)
+}
+
+#[cfg(feature = "vosk")]
+fn vosk_result_to_segment(
+    result: vosk::CompleteResult<'_>,
+    strip_spaces: bool,
+) -> Option<WhisperSegment> {
+    let single = result.single()?;
+    let words: Vec<WordTiming> = single
+        .result
+        .iter()
+        .map(|word| WordTiming {
+            start: word.start as f64,
+            end: word.end as f64,
+            text: word.word.to_string(),
+        })
+        .collect();
+    let first = words.first()?;
+    let text = if strip_spaces {
+        single.text.replace(' ', "")
+    } else {
+        single.text.to_string()
+    };
+    if text.trim().is_empty() {
+        return None;
+    }
+    Some(WhisperSegment {
+        start: first.start,
+        end: words.last().map(|word| word.end),
+        text,
+        words,
+        speaker: None,
+        speaker_turn_next: false,
+    })
+}
+
+pub(crate) async fn run_logged_command(
+    args: &[String],
+    jobs_state: &JobState,
+    job_id: &str,
+) -> Result<String> {
+    let (program, rest) = args
+        .split_first()
+        .ok_or_else(|| anyhow!("Command is empty"))?;
+    append_log(jobs_state, job_id, &format!("Running: {}", args.join(" ")));
+    let mut child = Command::new(program)
+        .args(rest)
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to execute command: {program}"))?;
+
+    let mut stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| anyhow!("Failed to capture command stdout"))?;
+    let stderr = child
+        .stderr
+        .take()
+        .ok_or_else(|| anyhow!("Failed to capture command stderr"))?;
+    let stderr_state = jobs_state.clone();
+    let stderr_job = job_id.to_string();
+    let stdout_task = tokio::spawn(async move {
+        let mut buffer = String::new();
+        tokio::io::AsyncReadExt::read_to_string(&mut stdout, &mut buffer).await?;
+        Ok::<String, anyhow::Error>(buffer)
+    });
+    let stderr_task = tokio::spawn(async move {
+        let mut lines = tokio::io::BufReader::new(stderr).lines();
+        while let Some(line) = lines.next_line().await? {
+            if !line.trim().is_empty() {
+                append_log(&stderr_state, &stderr_job, &line);
+            }
+        }
+        Ok::<(), anyhow::Error>(())
+    });
+
+    let status = child.wait().await?;
+    let stdout_contents = stdout_task
+        .await
+        .ok()
+        .and_then(|result| result.ok())
+        .unwrap_or_default();
+    let _ = stderr_task.await;
+    if !status.success() {
+        return Err(anyhow!("{program} exited with {status}"));
+    }
+    Ok(stdout_contents)
+}
+
+pub(crate) async fn align_segments(
+    command: &str,
+    input: &Path,
+    output_base: &Path,
+    segments: &[WhisperSegment],
+    language: &str,
+    jobs_state: &JobState,
+    job_id: &str,
+) -> Result<Vec<WhisperSegment>> {
+    let template = split_command_line(command.trim());
+    if template.is_empty() {
+        return Err(anyhow!("Alignment command is not configured"));
+    }
+    let segments_json = output_base.with_extension("segments.json");
+    let aligned_json = output_base.with_extension("aligned.json");
+    let payload = serde_json::json!({ "segments": segments });
+    fs::write(&segments_json, serde_json::to_string(&payload)?)
+        .await
+        .with_context(|| format!("Failed to write segments: {}", segments_json.display()))?;
+    let args: Vec<String> = template
+        .iter()
+        .map(|arg| {
+            arg.replace("{input}", &input.to_string_lossy())
+                .replace("{segments_json}", &segments_json.to_string_lossy())
+                .replace("{output_json}", &aligned_json.to_string_lossy())
+                .replace("{language}", language)
+        })
+        .collect();
+    let stdout_contents = run_logged_command(&args, jobs_state, job_id).await?;
+    if let Ok(contents) = fs::read_to_string(&aligned_json).await {
+        if let Some(aligned) = parse_segments_json(&contents) {
+            return Ok(aligned);
+        }
+    }
+    parse_segments_json(&stdout_contents)
+        .ok_or_else(|| anyhow!("Alignment command produced no segment JSON"))
+}
+
+pub(crate) fn split_command_line(value: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    let mut in_arg = false;
+    for c in value.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => current.push(c),
+            None if c == '"' || c == '\'' => {
+                quote = Some(c);
+                in_arg = true;
+            }
+            None if c.is_whitespace() => {
+                if in_arg {
+                    args.push(std::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            None => {
+                current.push(c);
+                in_arg = true;
+            }
+        }
+    }
+    if in_arg {
+        args.push(current);
+    }
+    args
+}
+
+fn parse_segments_json(contents: &str) -> Option<Vec<WhisperSegment>> {
+    let json = normalize_json_contents(contents);
+    if json.is_empty() {
+        return None;
+    }
+    if let Ok(value) = serde_json::from_str::<serde_json::Value>(&json) {
+        if let Some(segments) = extract_segments_from_value(value) {
+            return Some(segments);
+        }
+    }
+    parse_json_lines(&json)
+}
+
+fn resolve_whisper_server_binary(config: &AppConfig, cli_binary: &Path) -> Result<PathBuf> {
+    let requested = config.whisper.server_binary_path.trim();
+    if !requested.is_empty() {
+        let requested_path = PathBuf::from(requested);
+        if requested_path.is_file() {
+            return Ok(requested_path);
+        }
+        if let Some(found) = find_in_path(requested) {
+            return Ok(found);
+        }
+        return Err(anyhow!("whisper-server binary not found at {requested}"));
+    }
+    let server_name = if cfg!(target_os = "windows") {
+        "whisper-server.exe"
+    } else {
+        "whisper-server"
+    };
+    if let Some(sibling) = cli_binary.parent().map(|dir| dir.join(server_name)) {
+        if sibling.is_file() {
+            return Ok(sibling);
+        }
+    }
+    find_in_path(server_name).ok_or_else(|| {
+        anyhow!("whisper-server binary not found. Install whisper.cpp with the server example or set serverBinaryPath.")
+    })
+}
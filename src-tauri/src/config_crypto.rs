@@ -0,0 +1,81 @@
+use anyhow::{anyhow, Result};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+
+const KEYRING_SERVICE: &str = "WhisperDesktop";
+const KEYRING_USER: &str = "config-encryption-key";
+const ENVELOPE_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EncryptedConfig {
+    encrypted_config: u32,
+    nonce: String,
+    data: String,
+}
+
+fn config_key(create: bool) -> Result<LessSafeKey> {
+    let entry = keyring::Entry::new(KEYRING_SERVICE, KEYRING_USER)?;
+    let key = match entry.get_password() {
+        Ok(encoded) => STANDARD.decode(encoded.trim())?,
+        Err(keyring::Error::NoEntry) if create => {
+            let mut key = vec![0u8; AES_256_GCM.key_len()];
+            SystemRandom::new()
+                .fill(&mut key)
+                .map_err(|_| anyhow!("Failed to generate config encryption key"))?;
+            entry.set_password(&STANDARD.encode(&key))?;
+            key
+        }
+        Err(keyring::Error::NoEntry) => {
+            return Err(anyhow!(
+                "Config is encrypted but the key is missing from the OS keychain"
+            ))
+        }
+        Err(err) => return Err(err.into()),
+    };
+    let key = UnboundKey::new(&AES_256_GCM, &key)
+        .map_err(|_| anyhow!("Invalid config encryption key"))?;
+    Ok(LessSafeKey::new(key))
+}
+
+pub(crate) fn encrypt(plain: &str) -> Result<String> {
+    let key = config_key(true)?;
+    let mut nonce = [0u8; NONCE_LEN];
+    SystemRandom::new()
+        .fill(&mut nonce)
+        .map_err(|_| anyhow!("Failed to generate nonce"))?;
+    let mut data = plain.as_bytes().to_vec();
+    key.seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::empty(), &mut data)
+        .map_err(|_| anyhow!("Failed to encrypt config"))?;
+    let envelope = EncryptedConfig {
+        encrypted_config: ENVELOPE_VERSION,
+        nonce: STANDARD.encode(nonce),
+        data: STANDARD.encode(data),
+    };
+    Ok(serde_json::to_string_pretty(&envelope)?)
+}
+
+pub(crate) fn decrypt(contents: &str) -> Result<String> {
+    let Ok(envelope) = serde_json::from_str::<EncryptedConfig>(contents) else {
+        return Ok(contents.to_string());
+    };
+    if envelope.encrypted_config != ENVELOPE_VERSION {
+        return Err(anyhow!(
+            "Unsupported encrypted config version: {}",
+            envelope.encrypted_config
+        ));
+    }
+    let nonce: [u8; NONCE_LEN] = STANDARD
+        .decode(envelope.nonce)?
+        .try_into()
+        .map_err(|_| anyhow!("Invalid config nonce"))?;
+    let mut data = STANDARD.decode(envelope.data)?;
+    let key = config_key(false)?;
+    let plain = key
+        .open_in_place(Nonce::assume_unique_for_key(nonce), Aad::empty(), &mut data)
+        .map_err(|_| anyhow!("Failed to decrypt config; the keychain key may have changed"))?;
+    Ok(String::from_utf8(plain.to_vec())?)
+}
@@ -0,0 +1,71 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use notify::{RecursiveMode, Watcher};
+use tauri::{AppHandle, Emitter};
+use tokio::sync::mpsc;
+
+use crate::config_path;
+
+const RELOAD_DEBOUNCE_MS: u64 = 500;
+
+static LAST_SAVED: Mutex<Option<String>> = Mutex::new(None);
+
+pub(crate) fn note_saved(payload: &str) {
+    *LAST_SAVED.lock().unwrap() = Some(payload.to_string());
+}
+
+pub(crate) fn spawn_config_watcher(app: AppHandle) {
+    let path = match config_path() {
+        Ok(path) => path,
+        Err(err) => {
+            eprintln!("Config watcher: {err}");
+            return;
+        }
+    };
+    let Some(dir) = path.parent().map(|dir| dir.to_path_buf()) else {
+        return;
+    };
+    if let Err(err) = std::fs::create_dir_all(&dir) {
+        eprintln!("Config watcher: {err}");
+        return;
+    }
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let watched = path.clone();
+    let mut watcher =
+        match notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            if let Ok(event) = event {
+                if event.paths.iter().any(|changed| changed == &watched) {
+                    let _ = tx.send(());
+                }
+            }
+        }) {
+            Ok(watcher) => watcher,
+            Err(err) => {
+                eprintln!("Config watcher: {err}");
+                return;
+            }
+        };
+    if let Err(err) = watcher.watch(&dir, RecursiveMode::NonRecursive) {
+        eprintln!("Config watcher: {err}");
+        return;
+    }
+
+    tauri::async_runtime::spawn(async move {
+        let _watcher = watcher;
+        let mut last = std::fs::read_to_string(&path).ok();
+        while rx.recv().await.is_some() {
+            tokio::time::sleep(Duration::from_millis(RELOAD_DEBOUNCE_MS)).await;
+            while rx.try_recv().is_ok() {}
+            let current = tokio::fs::read_to_string(&path).await.ok();
+            if current == last {
+                continue;
+            }
+            let saved_by_app = current.is_some() && *LAST_SAVED.lock().unwrap() == current;
+            last = current;
+            if !saved_by_app {
+                let _ = app.emit("config://changed", ());
+            }
+        }
+    });
+}
@@ -0,0 +1,103 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+use tokio::fs;
+
+use crate::backend::{run_logged_command, split_command_line};
+use crate::{JobState, WhisperSegment};
+
+#[derive(Debug, Deserialize)]
+struct SpeakerTurn {
+    start: f64,
+    end: f64,
+    speaker: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum TurnsFile {
+    Wrapped { segments: Vec<SpeakerTurn> },
+    List(Vec<SpeakerTurn>),
+}
+
+fn speaker_label(index: usize) -> String {
+    format!("話者{}", index + 1)
+}
+
+pub(crate) fn label_turns(segments: &mut [WhisperSegment]) {
+    let mut current = 0;
+    for segment in segments.iter_mut() {
+        segment.speaker = Some(speaker_label(current));
+        if segment.speaker_turn_next {
+            current = (current + 1) % 2;
+        }
+    }
+}
+
+fn overlap(segment: &WhisperSegment, turn: &SpeakerTurn) -> f64 {
+    let end = segment.end.unwrap_or(segment.start);
+    (end.min(turn.end) - segment.start.max(turn.start)).max(0.0)
+}
+
+fn assign_turns(segments: &mut [WhisperSegment], turns: &[SpeakerTurn]) {
+    let mut labels: Vec<&str> = Vec::new();
+    for segment in segments.iter_mut() {
+        let best = turns
+            .iter()
+            .map(|turn| (turn, overlap(segment, turn)))
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .filter(|(_, overlap)| *overlap > 0.0)
+            .map(|(turn, _)| turn)
+            .or_else(|| {
+                turns
+                    .iter()
+                    .find(|turn| turn.start <= segment.start && segment.start < turn.end)
+            });
+        if let Some(turn) = best {
+            let index = match labels.iter().position(|label| *label == turn.speaker) {
+                Some(index) => index,
+                None => {
+                    labels.push(&turn.speaker);
+                    labels.len() - 1
+                }
+            };
+            segment.speaker = Some(speaker_label(index));
+        }
+    }
+}
+
+pub(crate) async fn diarize_external(
+    command: &str,
+    input: &Path,
+    output_base: &Path,
+    segments: &mut [WhisperSegment],
+    jobs_state: &JobState,
+    job_id: &str,
+) -> Result<()> {
+    let template = split_command_line(command.trim());
+    if template.is_empty() {
+        return Err(anyhow!("Diarization command is not configured"));
+    }
+    let output_json = output_base.with_extension("speakers.json");
+    let args: Vec<String> = template
+        .iter()
+        .map(|arg| {
+            arg.replace("{input}", &input.to_string_lossy())
+                .replace("{output_json}", &output_json.to_string_lossy())
+        })
+        .collect();
+    let stdout = run_logged_command(&args, jobs_state, job_id).await?;
+    let contents = match fs::read_to_string(&output_json).await {
+        Ok(contents) => contents,
+        Err(_) => stdout,
+    };
+    let turns = match serde_json::from_str::<TurnsFile>(contents.trim())
+        .with_context(|| "Failed to parse diarization output")?
+    {
+        TurnsFile::Wrapped { segments } => segments,
+        TurnsFile::List(turns) => turns,
+    };
+    assign_turns(segments, &turns);
+    Ok(())
+}
@@ -0,0 +1,168 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+use tauri::State;
+use tokio::fs;
+use tokio::sync::Mutex;
+
+use crate::{
+    effective_config, manifest_path, store, write_outputs, AppConfig, JobState, TranscriptManifest,
+    TranscriptionSegment,
+};
+
+static EDIT_LOCK: Mutex<()> = Mutex::const_new(());
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SegmentEdit {
+    text: Option<String>,
+    speaker: Option<String>,
+}
+
+async fn job_output_path(jobs: &JobState, job_id: &str) -> Result<PathBuf> {
+    let in_memory = jobs
+        .lock()
+        .unwrap()
+        .get(job_id)
+        .and_then(|status| status.output_path.clone());
+    let output_path = match in_memory {
+        Some(path) => Some(path),
+        None => {
+            let job_id = job_id.to_string();
+            tokio::task::spawn_blocking(move || store::job(&job_id))
+                .await??
+                .and_then(|record| record.output_path)
+        }
+    };
+    output_path
+        .map(PathBuf::from)
+        .ok_or_else(|| anyhow!("No transcript found for job {job_id}"))
+}
+
+async fn read_manifest(output_path: &Path) -> Result<TranscriptManifest> {
+    let path = manifest_path(output_path);
+    let data = fs::read_to_string(&path)
+        .await
+        .with_context(|| format!("Failed to read transcript manifest: {}", path.display()))?;
+    Ok(serde_json::from_str(&data)?)
+}
+
+fn config_for_output(mut config: AppConfig, output_path: &Path, regenerate: bool) -> AppConfig {
+    if let Some(extension) = output_path.extension().and_then(|value| value.to_str()) {
+        if matches!(extension, "txt" | "srt" | "vtt" | "json") {
+            config.whisper.output_format = extension.to_string();
+        }
+    }
+    // Translation and action items call paid APIs; a text fix should not re-bill them.
+    if !regenerate {
+        config.whisper.translation_provider = "off".to_string();
+        config.whisper.action_items = "off".to_string();
+    }
+    config
+}
+
+async fn rewrite(
+    jobs: &JobState,
+    job_id: &str,
+    output_path: &Path,
+    manifest: &TranscriptManifest,
+    regenerate: bool,
+) -> Result<()> {
+    let config = config_for_output(effective_config().await?, output_path, regenerate);
+    write_outputs(&config, &manifest.segments, output_path, jobs, job_id).await?;
+    let manifest_path = manifest_path(output_path);
+    fs::write(&manifest_path, serde_json::to_string(manifest)?)
+        .await
+        .with_context(|| format!("Failed to write output: {}", manifest_path.display()))?;
+    Ok(())
+}
+
+pub(crate) async fn segments(jobs: &JobState, job_id: &str) -> Result<Vec<TranscriptionSegment>> {
+    let output_path = job_output_path(jobs, job_id).await?;
+    Ok(read_manifest(&output_path).await?.segments)
+}
+
+pub(crate) async fn save(
+    jobs: &JobState,
+    job_id: &str,
+    content: Vec<TranscriptionSegment>,
+    regenerate: bool,
+) -> Result<()> {
+    let _guard = EDIT_LOCK.lock().await;
+    let output_path = job_output_path(jobs, job_id).await?;
+    let mut manifest = read_manifest(&output_path).await?;
+    manifest.segments = content;
+    manifest.segments.sort_by(|a, b| {
+        a.start
+            .partial_cmp(&b.start)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    rewrite(jobs, job_id, &output_path, &manifest, regenerate).await
+}
+
+pub(crate) async fn update(
+    jobs: &JobState,
+    job_id: &str,
+    index: usize,
+    edit: SegmentEdit,
+    regenerate: bool,
+) -> Result<TranscriptionSegment> {
+    let _guard = EDIT_LOCK.lock().await;
+    let output_path = job_output_path(jobs, job_id).await?;
+    let mut manifest = read_manifest(&output_path).await?;
+    let total = manifest.segments.len();
+    let segment = manifest
+        .segments
+        .get_mut(index)
+        .ok_or_else(|| anyhow!("Segment {index} out of range ({total} segments)"))?;
+    if let Some(text) = edit.text {
+        if text != segment.text {
+            segment.text = text;
+            segment.words.clear();
+        }
+    }
+    if let Some(speaker) = edit.speaker {
+        segment.speaker = speaker.trim().to_string();
+    }
+    let updated = segment.clone();
+    rewrite(jobs, job_id, &output_path, &manifest, regenerate).await?;
+    Ok(updated)
+}
+
+/// Returns the segments as recognised, before post-processing. Edits apply to this text
+/// and the post-processing rules run again when the outputs are rewritten.
+#[tauri::command]
+pub async fn get_transcript_segments(
+    jobs: State<'_, JobState>,
+    job_id: String,
+) -> Result<Vec<TranscriptionSegment>, String> {
+    segments(&jobs, &job_id)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn save_transcript(
+    jobs: State<'_, JobState>,
+    job_id: String,
+    content: Vec<TranscriptionSegment>,
+    regenerate: Option<bool>,
+) -> Result<(), String> {
+    save(&jobs, &job_id, content, regenerate.unwrap_or(false))
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn update_segment(
+    jobs: State<'_, JobState>,
+    job_id: String,
+    index: usize,
+    edit: SegmentEdit,
+    regenerate: Option<bool>,
+) -> Result<TranscriptionSegment, String> {
+    update(&jobs, &job_id, index, edit, regenerate.unwrap_or(false))
+        .await
+        .map_err(|err| err.to_string())
+}
@@ -0,0 +1,63 @@
+use anyhow::{anyhow, Result};
+use serde_json::{Map, Value};
+
+use crate::AppConfig;
+
+const ENV_PREFIX: &str = "WHISPERDESKTOP_";
+
+fn env_name(key: &str) -> String {
+    let mut name = String::new();
+    for ch in key.chars() {
+        if ch.is_ascii_uppercase() {
+            name.push('_');
+        }
+        name.push(ch.to_ascii_uppercase());
+    }
+    name
+}
+
+fn parse_override(var: &str, raw: String, current: &Value) -> Result<Value> {
+    match current {
+        Value::String(_) => Ok(Value::String(raw)),
+        Value::Null => Ok(serde_json::from_str(&raw).unwrap_or(Value::String(raw))),
+        _ => serde_json::from_str(&raw).map_err(|err| anyhow!("Invalid value for {var}: {err}")),
+    }
+}
+
+fn override_fields(fields: &mut Map<String, Value>, prefixes: &[&str]) -> Result<()> {
+    for (key, value) in fields.iter_mut() {
+        let suffix = env_name(key);
+        for prefix in prefixes {
+            let var = format!("{ENV_PREFIX}{prefix}{suffix}");
+            if let Ok(raw) = std::env::var(&var) {
+                *value = parse_override(&var, raw, value)?;
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn apply(config: AppConfig) -> Result<AppConfig> {
+    let mut root = serde_json::to_value(&config)?;
+    let Some(fields) = root.as_object_mut() else {
+        return Ok(config);
+    };
+    if let Some(Value::Object(minio)) = fields.get_mut("minio") {
+        override_fields(minio, &["MINIO_"])?;
+    }
+    if let Some(Value::Object(whisper)) = fields.get_mut("whisper") {
+        override_fields(whisper, &["WHISPER_", ""])?;
+    }
+    if let Some(Value::Object(integrations)) = fields.get_mut("integrations") {
+        override_fields(integrations, &["INTEGRATIONS_"])?;
+    }
+    let mut top_level: Map<String, Value> = fields
+        .iter()
+        .filter(|(key, _)| !matches!(key.as_str(), "minio" | "whisper" | "integrations"))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+    override_fields(&mut top_level, &[""])?;
+    fields.extend(top_level);
+    Ok(serde_json::from_value(root)?)
+}
@@ -0,0 +1,266 @@
+use std::path::Path;
+use std::sync::Mutex;
+
+use anyhow::{anyhow, Context, Result};
+use async_trait::async_trait;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use percent_encoding::utf8_percent_encode;
+use ring::rand::SystemRandom;
+use ring::signature::{RsaKeyPair, RSA_PKCS1_SHA256};
+use rustls_pki_types::pem::PemObject;
+use rustls_pki_types::PrivatePkcs8KeyDer;
+use serde::Deserialize;
+use tokio::fs;
+
+use crate::models::download_client;
+use crate::storage::{write_response, ProgressCallback, StorageSource, PATH_SEGMENT};
+use crate::AppConfig;
+
+const GCS_API_URL: &str = "https://storage.googleapis.com/storage/v1";
+const GCS_SCOPE: &str = "https://www.googleapis.com/auth/devstorage.read_only";
+const DEFAULT_TOKEN_URI: &str = "https://oauth2.googleapis.com/token";
+const JWT_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:jwt-bearer";
+const TOKEN_LIFETIME_SECS: i64 = 3600;
+const TOKEN_EXPIRY_MARGIN_SECS: i64 = 60;
+
+#[derive(Debug, Deserialize)]
+struct ServiceAccount {
+    client_email: String,
+    private_key: String,
+    token_uri: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: Option<i64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ObjectList {
+    #[serde(default)]
+    items: Vec<ObjectItem>,
+    #[serde(default)]
+    prefixes: Vec<String>,
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ObjectItem {
+    name: String,
+}
+
+pub(crate) struct GcsSource {
+    client: reqwest::Client,
+    account: ServiceAccount,
+    key_pair: RsaKeyPair,
+    bucket: String,
+    key_root: String,
+    token: Mutex<Option<(String, i64)>>,
+}
+
+impl GcsSource {
+    pub(crate) async fn from_config(config: &AppConfig) -> Result<Self> {
+        let minio = &config.minio;
+        let path = minio.gcs_service_account_path.trim();
+        if path.is_empty() {
+            return Err(anyhow!("GCS service account file is not configured"));
+        }
+        if minio.bucket.trim().is_empty() {
+            return Err(anyhow!("GCS bucket is not configured"));
+        }
+        let data = fs::read_to_string(path)
+            .await
+            .with_context(|| format!("Failed to read service account file: {path}"))?;
+        let account: ServiceAccount = serde_json::from_str(&data)
+            .with_context(|| format!("Invalid service account file: {path}"))?;
+        let key = PrivatePkcs8KeyDer::from_pem_slice(account.private_key.as_bytes())
+            .map_err(|err| anyhow!("Invalid service account private key: {err}"))?;
+        let key_pair = RsaKeyPair::from_pkcs8(key.secret_pkcs8_der())
+            .map_err(|err| anyhow!("Invalid service account private key: {err}"))?;
+        Ok(Self {
+            client: download_client(&config.proxy_url)?,
+            account,
+            key_pair,
+            bucket: minio.bucket.trim().to_string(),
+            key_root: minio.key_root(),
+            token: Mutex::new(None),
+        })
+    }
+
+    fn token_uri(&self) -> &str {
+        self.account
+            .token_uri
+            .as_deref()
+            .unwrap_or(DEFAULT_TOKEN_URI)
+    }
+
+    fn signed_assertion(&self, now: i64) -> Result<String> {
+        let header = URL_SAFE_NO_PAD.encode(r#"{"alg":"RS256","typ":"JWT"}"#);
+        let claims = serde_json::json!({
+            "iss": self.account.client_email,
+            "scope": GCS_SCOPE,
+            "aud": self.token_uri(),
+            "iat": now,
+            "exp": now + TOKEN_LIFETIME_SECS,
+        });
+        let claims = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&claims)?);
+        let message = format!("{header}.{claims}");
+        let mut signature = vec![0u8; self.key_pair.public().modulus_len()];
+        self.key_pair
+            .sign(
+                &RSA_PKCS1_SHA256,
+                &SystemRandom::new(),
+                message.as_bytes(),
+                &mut signature,
+            )
+            .map_err(|_| anyhow!("Failed to sign GCS token request"))?;
+        Ok(format!("{message}.{}", URL_SAFE_NO_PAD.encode(signature)))
+    }
+
+    async fn access_token(&self) -> Result<String> {
+        let now = chrono::Utc::now().timestamp();
+        if let Some((token, expires_at)) = self.token.lock().unwrap().as_ref() {
+            if expires_at - TOKEN_EXPIRY_MARGIN_SECS > now {
+                return Ok(token.clone());
+            }
+        }
+        let assertion = self.signed_assertion(now)?;
+        let response = self
+            .client
+            .post(self.token_uri())
+            .form(&[("grant_type", JWT_GRANT_TYPE), ("assertion", &assertion)])
+            .send()
+            .await
+            .with_context(|| "Failed to request GCS access token")?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(anyhow!("GCS token request failed with {status}: {body}"));
+        }
+        let token: TokenResponse = response.json().await?;
+        let expires_at = now + token.expires_in.unwrap_or(TOKEN_LIFETIME_SECS);
+        *self.token.lock().unwrap() = Some((token.access_token.clone(), expires_at));
+        Ok(token.access_token)
+    }
+
+    async fn list(&self, prefix: &str, delimiter: bool) -> Result<ObjectList> {
+        let token = self.access_token().await?;
+        let url = format!("{GCS_API_URL}/b/{}/o", self.bucket);
+        let mut result = ObjectList::default();
+        let mut page_token: Option<String> = None;
+        loop {
+            let mut request = self.client.get(&url).bearer_auth(&token).query(&[
+                ("prefix", prefix),
+                ("fields", "items(name),prefixes,nextPageToken"),
+            ]);
+            if delimiter {
+                request = request.query(&[("delimiter", "/")]);
+            }
+            if let Some(page_token) = &page_token {
+                request = request.query(&[("pageToken", page_token)]);
+            }
+            let response = request
+                .send()
+                .await
+                .with_context(|| format!("Failed to list bucket {}", self.bucket))?;
+            let status = response.status();
+            if !status.is_success() {
+                let body = response.text().await.unwrap_or_default();
+                return Err(anyhow!("GCS listing failed with {status}: {body}"));
+            }
+            let page: ObjectList = response.json().await?;
+            result.items.extend(page.items);
+            result.prefixes.extend(page.prefixes);
+            page_token = page.next_page_token;
+            if page_token.is_none() {
+                break;
+            }
+        }
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl StorageSource for GcsSource {
+    fn name(&self) -> &'static str {
+        "gcs"
+    }
+
+    async fn check(&self) -> Result<()> {
+        let token = self.access_token().await?;
+        let response = self
+            .client
+            .get(format!("{GCS_API_URL}/b/{}/o", self.bucket))
+            .bearer_auth(&token)
+            .query(&[("prefix", self.key_root.as_str()), ("maxResults", "1")])
+            .send()
+            .await
+            .with_context(|| format!("Failed to connect to bucket {}", self.bucket))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(anyhow!("GCS check failed with {status}"));
+        }
+        Ok(())
+    }
+
+    async fn list_prefixes(&self, prefix: &str) -> Result<Vec<String>> {
+        let full_prefix = format!("{}{prefix}", self.key_root);
+        let list = self.list(&full_prefix, true).await?;
+        Ok(list
+            .prefixes
+            .iter()
+            .map(|value| {
+                value
+                    .strip_prefix(full_prefix.as_str())
+                    .unwrap_or(value)
+                    .trim_end_matches('/')
+                    .to_string()
+            })
+            .filter(|value| !value.is_empty())
+            .collect())
+    }
+
+    async fn list_objects(&self, prefix: &str) -> Result<Vec<String>> {
+        let full_prefix = format!("{}{prefix}", self.key_root);
+        let list = self.list(&full_prefix, false).await?;
+        Ok(list
+            .items
+            .into_iter()
+            .map(|item| {
+                item.name
+                    .strip_prefix(self.key_root.as_str())
+                    .map(str::to_string)
+                    .unwrap_or(item.name)
+            })
+            .collect())
+    }
+
+    async fn get_object(
+        &self,
+        key: &str,
+        dest: &Path,
+        on_progress: ProgressCallback<'_>,
+    ) -> Result<()> {
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        let token = self.access_token().await?;
+        let object = format!("{}{key}", self.key_root);
+        let response = self
+            .client
+            .get(format!(
+                "{GCS_API_URL}/b/{}/o/{}",
+                self.bucket,
+                utf8_percent_encode(&object, PATH_SEGMENT)
+            ))
+            .bearer_auth(&token)
+            .query(&[("alt", "media")])
+            .send()
+            .await
+            .with_context(|| format!("Failed to download {key}"))?;
+        write_response(response, key, dest, on_progress).await
+    }
+}
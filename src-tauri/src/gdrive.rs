@@ -0,0 +1,398 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::{anyhow, Context, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+use crate::config_crypto;
+use crate::models::download_client;
+use crate::storage::{write_response, ProgressCallback, StorageSource};
+use crate::{project_dirs, AppConfig, MinioConfig};
+
+const DEVICE_CODE_URL: &str = "https://oauth2.googleapis.com/device/code";
+const TOKEN_URL: &str = "https://oauth2.googleapis.com/token";
+const DRIVE_FILES_URL: &str = "https://www.googleapis.com/drive/v3/files";
+const DRIVE_SCOPE: &str = "https://www.googleapis.com/auth/drive.readonly";
+const FOLDER_MIME_TYPE: &str = "application/vnd.google-apps.folder";
+const DEVICE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:device_code";
+const DEFAULT_POLL_INTERVAL_SECS: u64 = 5;
+const TOKEN_EXPIRY_MARGIN_SECS: i64 = 60;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DeviceCode {
+    #[serde(alias = "device_code")]
+    device_code: String,
+    #[serde(alias = "user_code")]
+    user_code: String,
+    #[serde(alias = "verification_url")]
+    verification_url: String,
+    #[serde(alias = "expires_in")]
+    expires_in: u64,
+    interval: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: Option<String>,
+    refresh_token: Option<String>,
+    expires_in: Option<i64>,
+    error: Option<String>,
+    error_description: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredToken {
+    access_token: String,
+    refresh_token: String,
+    expires_at: i64,
+}
+
+fn token_path() -> Result<PathBuf> {
+    let dirs = project_dirs()?;
+    Ok(dirs.config_dir().join("gdrive_token.json"))
+}
+
+async fn load_token() -> Result<StoredToken> {
+    let path = token_path()?;
+    let data = fs::read_to_string(&path)
+        .await
+        .map_err(|_| anyhow!("Google Drive is not authorized yet"))?;
+    serde_json::from_str(&config_crypto::decrypt(&data)?)
+        .with_context(|| format!("Invalid token file: {}", path.display()))
+}
+
+async fn save_token(token: &StoredToken) -> Result<()> {
+    let path = token_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+    // The refresh token grants lasting Drive access, so it never touches disk in plaintext.
+    let payload = config_crypto::encrypt(&serde_json::to_string(token)?)?;
+    fs::write(&path, payload).await?;
+    Ok(())
+}
+
+fn client_credentials(minio: &MinioConfig) -> Result<(&str, &str)> {
+    let client_id = minio.gdrive_client_id.trim();
+    if client_id.is_empty() {
+        return Err(anyhow!("Google Drive client ID is not configured"));
+    }
+    Ok((client_id, minio.gdrive_client_secret.trim()))
+}
+
+pub(crate) async fn start_device_auth(config: &AppConfig) -> Result<DeviceCode> {
+    let (client_id, _) = client_credentials(&config.minio)?;
+    let client = download_client(&config.proxy_url)?;
+    let response = client
+        .post(DEVICE_CODE_URL)
+        .form(&[("client_id", client_id), ("scope", DRIVE_SCOPE)])
+        .send()
+        .await
+        .with_context(|| "Failed to start Google authorization")?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(anyhow!("Google authorization failed with {status}: {body}"));
+    }
+    Ok(response.json().await?)
+}
+
+pub(crate) async fn complete_device_auth(
+    config: &AppConfig,
+    device_code: &str,
+    interval: Option<u64>,
+) -> Result<()> {
+    let (client_id, client_secret) = client_credentials(&config.minio)?;
+    let client = download_client(&config.proxy_url)?;
+    let mut interval = interval
+        .filter(|secs| *secs > 0)
+        .unwrap_or(DEFAULT_POLL_INTERVAL_SECS);
+    loop {
+        tokio::time::sleep(std::time::Duration::from_secs(interval)).await;
+        let response: TokenResponse = client
+            .post(TOKEN_URL)
+            .form(&[
+                ("client_id", client_id),
+                ("client_secret", client_secret),
+                ("device_code", device_code),
+                ("grant_type", DEVICE_GRANT_TYPE),
+            ])
+            .send()
+            .await
+            .with_context(|| "Failed to poll Google authorization")?
+            .json()
+            .await?;
+        match response.error.as_deref() {
+            None => {
+                let access_token = response
+                    .access_token
+                    .ok_or_else(|| anyhow!("Google did not return an access token"))?;
+                let refresh_token = response
+                    .refresh_token
+                    .ok_or_else(|| anyhow!("Google did not return a refresh token"))?;
+                return save_token(&StoredToken {
+                    access_token,
+                    refresh_token,
+                    expires_at: chrono::Utc::now().timestamp() + response.expires_in.unwrap_or(0),
+                })
+                .await;
+            }
+            Some("authorization_pending") => {}
+            Some("slow_down") => interval += DEFAULT_POLL_INTERVAL_SECS,
+            Some(error) => {
+                return Err(anyhow!(
+                    "Google authorization failed: {}",
+                    response.error_description.as_deref().unwrap_or(error)
+                ))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DriveFile {
+    id: String,
+    name: String,
+    mime_type: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DriveFileList {
+    #[serde(default)]
+    files: Vec<DriveFile>,
+    next_page_token: Option<String>,
+}
+
+pub(crate) struct GoogleDriveSource {
+    client: reqwest::Client,
+    client_id: String,
+    client_secret: String,
+    folder_id: String,
+    ids: Mutex<HashMap<String, String>>,
+}
+
+impl GoogleDriveSource {
+    pub(crate) fn from_config(config: &AppConfig) -> Result<Self> {
+        let (client_id, client_secret) = client_credentials(&config.minio)?;
+        let folder_id = config.minio.gdrive_folder_id.trim();
+        if folder_id.is_empty() {
+            return Err(anyhow!("Google Drive folder ID is not configured"));
+        }
+        Ok(Self {
+            client: download_client(&config.proxy_url)?,
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            folder_id: folder_id.to_string(),
+            ids: Mutex::new(HashMap::new()),
+        })
+    }
+
+    async fn access_token(&self) -> Result<String> {
+        let token = load_token().await?;
+        let now = chrono::Utc::now().timestamp();
+        if token.expires_at - TOKEN_EXPIRY_MARGIN_SECS > now {
+            return Ok(token.access_token);
+        }
+        let response: TokenResponse = self
+            .client
+            .post(TOKEN_URL)
+            .form(&[
+                ("client_id", self.client_id.as_str()),
+                ("client_secret", self.client_secret.as_str()),
+                ("refresh_token", token.refresh_token.as_str()),
+                ("grant_type", "refresh_token"),
+            ])
+            .send()
+            .await
+            .with_context(|| "Failed to refresh Google Drive token")?
+            .json()
+            .await?;
+        if let Some(error) = response.error {
+            return Err(anyhow!(
+                "Failed to refresh Google Drive token: {}",
+                response.error_description.unwrap_or(error)
+            ));
+        }
+        let access_token = response
+            .access_token
+            .ok_or_else(|| anyhow!("Google did not return an access token"))?;
+        save_token(&StoredToken {
+            access_token: access_token.clone(),
+            refresh_token: response.refresh_token.unwrap_or(token.refresh_token),
+            expires_at: now + response.expires_in.unwrap_or(0),
+        })
+        .await?;
+        Ok(access_token)
+    }
+
+    async fn children(&self, folder_id: &str) -> Result<Vec<DriveFile>> {
+        let token = self.access_token().await?;
+        let query = format!("'{folder_id}' in parents and trashed = false");
+        let mut files = Vec::new();
+        let mut page_token: Option<String> = None;
+        loop {
+            let mut request = self
+                .client
+                .get(DRIVE_FILES_URL)
+                .bearer_auth(&token)
+                .query(&[
+                    ("q", query.as_str()),
+                    ("fields", "nextPageToken,files(id,name,mimeType)"),
+                    ("pageSize", "1000"),
+                    ("supportsAllDrives", "true"),
+                    ("includeItemsFromAllDrives", "true"),
+                ]);
+            if let Some(page_token) = &page_token {
+                request = request.query(&[("pageToken", page_token)]);
+            }
+            let response = request
+                .send()
+                .await
+                .with_context(|| "Failed to list Google Drive folder")?;
+            let status = response.status();
+            if !status.is_success() {
+                return Err(anyhow!("Google Drive listing failed with {status}"));
+            }
+            let page: DriveFileList = response.json().await?;
+            files.extend(page.files);
+            page_token = page.next_page_token;
+            if page_token.is_none() {
+                break;
+            }
+        }
+        Ok(files)
+    }
+
+    fn cached_id(&self, key: &str) -> Option<String> {
+        self.ids.lock().unwrap().get(key).cloned()
+    }
+
+    fn cache_id(&self, key: String, id: &str) {
+        self.ids.lock().unwrap().insert(key, id.to_string());
+    }
+
+    async fn resolve(&self, key: &str) -> Result<Option<String>> {
+        let key = key.trim_matches('/');
+        if key.is_empty() {
+            return Ok(Some(self.folder_id.clone()));
+        }
+        if let Some(id) = self.cached_id(key) {
+            return Ok(Some(id));
+        }
+        let mut current = self.folder_id.clone();
+        let mut path = String::new();
+        for segment in key.split('/').filter(|segment| !segment.is_empty()) {
+            path = if path.is_empty() {
+                segment.to_string()
+            } else {
+                format!("{path}/{segment}")
+            };
+            if let Some(id) = self.cached_id(&path) {
+                current = id;
+                continue;
+            }
+            let found = self
+                .children(&current)
+                .await?
+                .into_iter()
+                .find(|file| file.name == segment);
+            match found {
+                Some(file) => {
+                    self.cache_id(path.clone(), &file.id);
+                    current = file.id;
+                }
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(current))
+    }
+}
+
+#[async_trait]
+impl StorageSource for GoogleDriveSource {
+    fn name(&self) -> &'static str {
+        "gdrive"
+    }
+
+    async fn check(&self) -> Result<()> {
+        self.children(&self.folder_id).await?;
+        Ok(())
+    }
+
+    async fn list_prefixes(&self, prefix: &str) -> Result<Vec<String>> {
+        let Some(folder_id) = self.resolve(prefix).await? else {
+            return Ok(Vec::new());
+        };
+        let base = prefix.trim_matches('/');
+        let mut prefixes = Vec::new();
+        for file in self.children(&folder_id).await? {
+            if file.mime_type == FOLDER_MIME_TYPE {
+                let key = if base.is_empty() {
+                    file.name.clone()
+                } else {
+                    format!("{base}/{}", file.name)
+                };
+                self.cache_id(key, &file.id);
+                prefixes.push(file.name);
+            }
+        }
+        prefixes.sort();
+        Ok(prefixes)
+    }
+
+    async fn list_objects(&self, prefix: &str) -> Result<Vec<String>> {
+        let base = prefix.trim_matches('/').to_string();
+        let Some(folder_id) = self.resolve(&base).await? else {
+            return Ok(Vec::new());
+        };
+        let mut keys = Vec::new();
+        let mut pending = vec![(base, folder_id)];
+        while let Some((dir, folder_id)) = pending.pop() {
+            for file in self.children(&folder_id).await? {
+                let key = if dir.is_empty() {
+                    file.name.clone()
+                } else {
+                    format!("{dir}/{}", file.name)
+                };
+                self.cache_id(key.clone(), &file.id);
+                if file.mime_type == FOLDER_MIME_TYPE {
+                    pending.push((key, file.id));
+                } else {
+                    keys.push(key);
+                }
+            }
+        }
+        Ok(keys)
+    }
+
+    async fn get_object(
+        &self,
+        key: &str,
+        dest: &Path,
+        on_progress: ProgressCallback<'_>,
+    ) -> Result<()> {
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        let file_id = self
+            .resolve(key)
+            .await?
+            .ok_or_else(|| anyhow!("File not found in Google Drive: {key}"))?;
+        let token = self.access_token().await?;
+        let response = self
+            .client
+            .get(format!("{DRIVE_FILES_URL}/{file_id}"))
+            .bearer_auth(&token)
+            .query(&[("alt", "media"), ("supportsAllDrives", "true")])
+            .send()
+            .await
+            .with_context(|| format!("Failed to download {key}"))?;
+        write_response(response, key, dest, on_progress).await
+    }
+}
@@ -0,0 +1,88 @@
+use std::path::Path;
+
+use crate::{transcripts, AppConfig, RoomLabeler, TranscriptionSegment, WhisperConfig};
+
+const DEFAULT_TEMPLATE: &str = "日付: {date}
+会議室: {room}
+開始時刻: {time}
+参加者: {participants}
+モデル: {model}
+生成日時: {generated}";
+
+fn model_name(whisper: &WhisperConfig) -> String {
+    let model = match whisper.backend.as_str() {
+        "openai" => whisper.openai_model.trim(),
+        "deepgram" => whisper.deepgram_model.trim(),
+        "vosk" => whisper.vosk_model_path.trim(),
+        _ => whisper.model_path.trim(),
+    };
+    if model.is_empty() {
+        return whisper.backend.clone();
+    }
+    Path::new(model)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| model.to_string())
+}
+
+fn participants(segments: &[TranscriptionSegment]) -> String {
+    let mut speakers: Vec<&str> = Vec::new();
+    for segment in segments {
+        let speaker = segment.speaker.trim();
+        if !speaker.is_empty() && !speakers.contains(&speaker) {
+            speakers.push(speaker);
+        }
+    }
+    speakers.join("、")
+}
+
+fn unversioned_stem(output_path: &Path) -> String {
+    let stem = output_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    match stem.rsplit_once("-v") {
+        Some((base, version)) if version.parse::<u32>().is_ok() => base.to_string(),
+        _ => stem,
+    }
+}
+
+pub(crate) fn render(
+    config: &AppConfig,
+    output_path: &Path,
+    segments: &[TranscriptionSegment],
+) -> String {
+    let (date, room_id, time) = transcripts::split_stem(&unversioned_stem(output_path));
+    let room = if room_id.is_empty() {
+        String::new()
+    } else {
+        RoomLabeler::new(config).label(&room_id)
+    };
+    let template = config.whisper.metadata_header_template.trim();
+    let template = if template.is_empty() {
+        DEFAULT_TEMPLATE
+    } else {
+        template
+    };
+    template
+        .replace("{date}", &date)
+        .replace("{room}", &room)
+        .replace("{time}", &time)
+        .replace("{participants}", &participants(segments))
+        .replace("{model}", &model_name(&config.whisper))
+        .replace(
+            "{generated}",
+            &chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        )
+}
+
+pub(crate) fn prepend(header: &str, output: String, extension: &str) -> String {
+    match extension {
+        "txt" => format!("{header}\n\n{output}"),
+        "vtt" => match output.strip_prefix("WEBVTT\n\n") {
+            Some(cues) => format!("WEBVTT\n\nNOTE\n{header}\n\n{cues}"),
+            None => output,
+        },
+        _ => output,
+    }
+}
@@ -0,0 +1,186 @@
+use anyhow::Result;
+use axum::extract::{Path, Query, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tauri::{AppHandle, Manager};
+
+use crate::listing_cache::Listing;
+use crate::transcripts::{self, TranscriptEntry};
+use crate::{
+    effective_config, job_status, list_dates, list_meetings, start_meeting_job, JobOptions,
+    JobState, JobStatus, MeetingSummary,
+};
+
+type ApiResult<T> = std::result::Result<Json<T>, (StatusCode, String)>;
+
+#[derive(Clone)]
+struct ApiState {
+    app: AppHandle,
+    jobs: JobState,
+    token: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProfileQuery {
+    profile: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MeetingsQuery {
+    date: String,
+    room: Option<String>,
+    profile: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StartRequest {
+    meeting_id: String,
+    profile: Option<String>,
+    tracks: Option<Vec<String>>,
+    #[serde(default)]
+    incremental: bool,
+    options: Option<JobOptions>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StartResponse {
+    job_id: String,
+}
+
+fn tokens_match(provided: &str, expected: &str) -> bool {
+    let provided = Sha256::digest(provided.as_bytes());
+    let expected = Sha256::digest(expected.as_bytes());
+    provided
+        .iter()
+        .zip(expected.iter())
+        .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+        == 0
+}
+
+fn authorize(
+    state: &ApiState,
+    headers: &HeaderMap,
+) -> std::result::Result<(), (StatusCode, String)> {
+    let provided = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .unwrap_or_default();
+    if provided.is_empty() || !tokens_match(provided, &state.token) {
+        return Err((StatusCode::UNAUTHORIZED, "Invalid API token".to_string()));
+    }
+    Ok(())
+}
+
+fn internal(err: impl ToString) -> (StatusCode, String) {
+    (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+}
+
+async fn dates(
+    State(state): State<ApiState>,
+    headers: HeaderMap,
+    Query(query): Query<ProfileQuery>,
+) -> ApiResult<Listing<Vec<String>>> {
+    authorize(&state, &headers)?;
+    list_dates(query.profile).await.map(Json).map_err(internal)
+}
+
+async fn meetings(
+    State(state): State<ApiState>,
+    headers: HeaderMap,
+    Query(query): Query<MeetingsQuery>,
+) -> ApiResult<Listing<Vec<MeetingSummary>>> {
+    authorize(&state, &headers)?;
+    list_meetings(query.date, query.room, query.profile)
+        .await
+        .map(Json)
+        .map_err(internal)
+}
+
+async fn start(
+    State(state): State<ApiState>,
+    headers: HeaderMap,
+    Json(request): Json<StartRequest>,
+) -> ApiResult<StartResponse> {
+    authorize(&state, &headers)?;
+    let job_id = start_meeting_job(
+        state.app.clone(),
+        &state.jobs,
+        request.meeting_id,
+        request.profile.as_deref(),
+        request.tracks,
+        request.incremental,
+        request.options,
+    )
+    .await
+    .map_err(internal)?;
+    Ok(Json(StartResponse { job_id }))
+}
+
+async fn status(
+    State(state): State<ApiState>,
+    headers: HeaderMap,
+    Path(job_id): Path<String>,
+) -> ApiResult<JobStatus> {
+    authorize(&state, &headers)?;
+    job_status(&state.jobs, job_id)
+        .await
+        .map(Json)
+        .map_err(|err| (StatusCode::NOT_FOUND, err.to_string()))
+}
+
+async fn transcript_list(
+    State(state): State<ApiState>,
+    headers: HeaderMap,
+) -> ApiResult<Vec<TranscriptEntry>> {
+    authorize(&state, &headers)?;
+    let config = effective_config().await.map_err(internal)?;
+    transcripts::list(&config, &state.jobs)
+        .await
+        .map(Json)
+        .map_err(internal)
+}
+
+async fn serve(app: AppHandle) -> Result<()> {
+    let config = effective_config().await?;
+    let integrations = &config.integrations;
+    let Some(port) = integrations.http_api_port.filter(|port| *port > 0) else {
+        return Ok(());
+    };
+    let token = integrations.http_api_token.trim().to_string();
+    if token.is_empty() {
+        eprintln!("HTTP API disabled: httpApiToken is not configured");
+        return Ok(());
+    }
+    let state = ApiState {
+        jobs: app.state::<JobState>().inner().clone(),
+        app,
+        token,
+    };
+    let router = Router::new()
+        .route("/dates", get(dates))
+        .route("/meetings", get(meetings))
+        .route("/transcribe", post(start))
+        .route("/jobs/{job_id}", get(status))
+        .route("/transcripts", get(transcript_list))
+        .with_state(state);
+    let listener = tokio::net::TcpListener::bind(("127.0.0.1", port)).await?;
+    eprintln!("HTTP API listening on 127.0.0.1:{port}");
+    axum::serve(listener, router).await?;
+    Ok(())
+}
+
+pub(crate) fn spawn_server(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        if let Err(err) = serve(app).await {
+            eprintln!("HTTP API: {err}");
+        }
+    });
+}
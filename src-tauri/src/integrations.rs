@@ -0,0 +1,232 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use lettre::message::header::ContentType;
+use lettre::message::{Attachment, Mailbox, MultiPart, SinglePart};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+use crate::models::download_client;
+use crate::notifications::JobFinishedEvent;
+use crate::{load_manifest, postprocess, AppConfig};
+
+const SNIPPET_MAX_CHARS: usize = 300;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub(crate) struct IntegrationConfig {
+    #[serde(alias = "webhook_url")]
+    pub(crate) webhook_url: String,
+    #[serde(alias = "webhook_kind")]
+    pub(crate) webhook_kind: String,
+    #[serde(alias = "webhook_channel")]
+    pub(crate) webhook_channel: String,
+    #[serde(alias = "email_on_complete")]
+    pub(crate) email_on_complete: bool,
+    #[serde(alias = "email_recipients")]
+    pub(crate) email_recipients: Vec<String>,
+    #[serde(alias = "email_from")]
+    pub(crate) email_from: String,
+    #[serde(alias = "smtp_host")]
+    pub(crate) smtp_host: String,
+    #[serde(alias = "smtp_port")]
+    pub(crate) smtp_port: Option<u16>,
+    #[serde(alias = "smtp_security")]
+    pub(crate) smtp_security: String,
+    #[serde(alias = "smtp_username")]
+    pub(crate) smtp_username: String,
+    #[serde(alias = "smtp_password")]
+    pub(crate) smtp_password: String,
+    #[serde(alias = "http_api_port")]
+    pub(crate) http_api_port: Option<u16>,
+    #[serde(alias = "http_api_token")]
+    pub(crate) http_api_token: String,
+}
+
+impl IntegrationConfig {
+    pub(crate) fn secrets_mut(&mut self) -> [&mut String; 3] {
+        [
+            &mut self.webhook_url,
+            &mut self.smtp_password,
+            &mut self.http_api_token,
+        ]
+    }
+
+    fn webhook_kind(&self) -> &str {
+        match self.webhook_kind.trim() {
+            "slack" => "slack",
+            "teams" => "teams",
+            _ if self.webhook_url.contains("webhook.office.com")
+                || self.webhook_url.contains("logic.azure.com") =>
+            {
+                "teams"
+            }
+            _ => "slack",
+        }
+    }
+}
+
+async fn transcript_snippet(config: &AppConfig, output_path: &Path) -> String {
+    let mut segments = load_manifest(output_path).await.segments;
+    postprocess::apply(config, &mut segments, |_| {});
+    let mut snippet = String::new();
+    for segment in segments {
+        let text = segment.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let line = if segment.speaker.is_empty() {
+            text.to_string()
+        } else {
+            format!("{}: {text}", segment.speaker)
+        };
+        if snippet.chars().count() + line.chars().count() > SNIPPET_MAX_CHARS {
+            let remaining = SNIPPET_MAX_CHARS.saturating_sub(snippet.chars().count());
+            snippet.extend(line.chars().take(remaining));
+            snippet.push('…');
+            break;
+        }
+        snippet.push_str(&line);
+        snippet.push('\n');
+    }
+    snippet.trim_end().to_string()
+}
+
+fn webhook_payload(config: &IntegrationConfig, title: &str, snippet: &str) -> serde_json::Value {
+    if config.webhook_kind() == "teams" {
+        return serde_json::json!({
+            "@type": "MessageCard",
+            "@context": "https://schema.org/extensions",
+            "summary": title,
+            "title": title,
+            "text": snippet.replace('\n', "<br>"),
+        });
+    }
+    let text = if snippet.is_empty() {
+        title.to_string()
+    } else {
+        format!("{title}\n```{snippet}```")
+    };
+    let mut payload = serde_json::json!({ "text": text });
+    let channel = config.webhook_channel.trim();
+    if !channel.is_empty() {
+        payload["channel"] = serde_json::Value::String(channel.to_string());
+    }
+    payload
+}
+
+pub(crate) async fn post_webhook(config: &AppConfig, event: &JobFinishedEvent) -> Result<()> {
+    let integrations = &config.integrations;
+    let url = integrations.webhook_url.trim();
+    if url.is_empty() || event.state != "done" {
+        return Ok(());
+    }
+    let title = format!("議事録が完成しました: {}", event.label);
+    let snippet = match event.output_path.as_deref() {
+        Some(output_path) => transcript_snippet(config, Path::new(output_path)).await,
+        None => String::new(),
+    };
+    let response = download_client(&config.proxy_url)?
+        .post(url)
+        .json(&webhook_payload(integrations, &title, &snippet))
+        .send()
+        .await
+        .with_context(|| "Failed to send webhook notification")?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(anyhow!("Webhook returned {status}: {body}"));
+    }
+    Ok(())
+}
+
+fn attachment_content_type(path: &Path) -> Result<ContentType> {
+    let mime = match path.extension().and_then(|value| value.to_str()) {
+        Some("vtt") => "text/vtt; charset=utf-8",
+        Some("json") => "application/json",
+        _ => "text/plain; charset=utf-8",
+    };
+    Ok(ContentType::parse(mime)?)
+}
+
+fn smtp_transport(config: &IntegrationConfig) -> Result<AsyncSmtpTransport<Tokio1Executor>> {
+    let host = config.smtp_host.trim();
+    if host.is_empty() {
+        return Err(anyhow!("SMTP host is not configured"));
+    }
+    let mut builder = match config.smtp_security.trim() {
+        "tls" => AsyncSmtpTransport::<Tokio1Executor>::relay(host)?,
+        "none" => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host),
+        _ => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)?,
+    };
+    if let Some(port) = config.smtp_port.filter(|port| *port > 0) {
+        builder = builder.port(port);
+    }
+    let username = config.smtp_username.trim();
+    if !username.is_empty() {
+        builder = builder.credentials(Credentials::new(
+            username.to_string(),
+            config.smtp_password.clone(),
+        ));
+    }
+    Ok(builder.build())
+}
+
+pub(crate) async fn send_email(config: &AppConfig, event: &JobFinishedEvent) -> Result<()> {
+    let integrations = &config.integrations;
+    if !integrations.email_on_complete || event.state != "done" {
+        return Ok(());
+    }
+    let Some(output_path) = event.output_path.as_deref().map(Path::new) else {
+        return Ok(());
+    };
+    let recipients: Vec<&str> = integrations
+        .email_recipients
+        .iter()
+        .map(|recipient| recipient.trim())
+        .filter(|recipient| !recipient.is_empty())
+        .collect();
+    if recipients.is_empty() {
+        return Err(anyhow!("No email recipients configured"));
+    }
+    let from = match integrations.email_from.trim() {
+        "" => integrations.smtp_username.trim(),
+        from => from,
+    };
+    let from: Mailbox = from
+        .parse()
+        .with_context(|| format!("Invalid sender address: {from}"))?;
+    let mut builder = Message::builder()
+        .from(from)
+        .subject(format!("議事録: {}", event.label));
+    for recipient in recipients {
+        let mailbox: Mailbox = recipient
+            .parse()
+            .with_context(|| format!("Invalid recipient address: {recipient}"))?;
+        builder = builder.to(mailbox);
+    }
+
+    let title = format!("議事録が完成しました: {}", event.label);
+    let snippet = transcript_snippet(config, output_path).await;
+    let file_name = output_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "transcript.txt".to_string());
+    let attachment = fs::read(output_path)
+        .await
+        .with_context(|| format!("Failed to read transcript: {}", output_path.display()))?;
+    let message = builder.multipart(
+        MultiPart::mixed()
+            .singlepart(SinglePart::plain(format!("{title}\n\n{snippet}\n")))
+            .singlepart(
+                Attachment::new(file_name).body(attachment, attachment_content_type(output_path)?),
+            ),
+    )?;
+    smtp_transport(integrations)?
+        .send(message)
+        .await
+        .with_context(|| "Failed to send transcript email")?;
+    Ok(())
+}
@@ -0,0 +1,197 @@
+use std::sync::LazyLock;
+
+use regex::{Captures, Regex};
+
+use crate::WhisperConfig;
+
+const HALF_WIDTH_KANA: &[(char, char)] = &[
+    ('｡', '。'),
+    ('｢', '「'),
+    ('｣', '」'),
+    ('､', '、'),
+    ('･', '・'),
+    ('ｦ', 'ヲ'),
+    ('ｧ', 'ァ'),
+    ('ｨ', 'ィ'),
+    ('ｩ', 'ゥ'),
+    ('ｪ', 'ェ'),
+    ('ｫ', 'ォ'),
+    ('ｬ', 'ャ'),
+    ('ｭ', 'ュ'),
+    ('ｮ', 'ョ'),
+    ('ｯ', 'ッ'),
+    ('ｰ', 'ー'),
+    ('ｱ', 'ア'),
+    ('ｲ', 'イ'),
+    ('ｳ', 'ウ'),
+    ('ｴ', 'エ'),
+    ('ｵ', 'オ'),
+    ('ｶ', 'カ'),
+    ('ｷ', 'キ'),
+    ('ｸ', 'ク'),
+    ('ｹ', 'ケ'),
+    ('ｺ', 'コ'),
+    ('ｻ', 'サ'),
+    ('ｼ', 'シ'),
+    ('ｽ', 'ス'),
+    ('ｾ', 'セ'),
+    ('ｿ', 'ソ'),
+    ('ﾀ', 'タ'),
+    ('ﾁ', 'チ'),
+    ('ﾂ', 'ツ'),
+    ('ﾃ', 'テ'),
+    ('ﾄ', 'ト'),
+    ('ﾅ', 'ナ'),
+    ('ﾆ', 'ニ'),
+    ('ﾇ', 'ヌ'),
+    ('ﾈ', 'ネ'),
+    ('ﾉ', 'ノ'),
+    ('ﾊ', 'ハ'),
+    ('ﾋ', 'ヒ'),
+    ('ﾌ', 'フ'),
+    ('ﾍ', 'ヘ'),
+    ('ﾎ', 'ホ'),
+    ('ﾏ', 'マ'),
+    ('ﾐ', 'ミ'),
+    ('ﾑ', 'ム'),
+    ('ﾒ', 'メ'),
+    ('ﾓ', 'モ'),
+    ('ﾔ', 'ヤ'),
+    ('ﾕ', 'ユ'),
+    ('ﾖ', 'ヨ'),
+    ('ﾗ', 'ラ'),
+    ('ﾘ', 'リ'),
+    ('ﾙ', 'ル'),
+    ('ﾚ', 'レ'),
+    ('ﾛ', 'ロ'),
+    ('ﾜ', 'ワ'),
+    ('ﾝ', 'ン'),
+];
+
+static JAPANESE_GAP: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"([\p{Han}\p{Hiragana}\p{Katakana}ー。、「」])\s+([\p{Han}\p{Hiragana}\p{Katakana}ー。、「」])")
+        .unwrap()
+});
+static SPACE_RUN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\s{2,}").unwrap());
+static ASCII_COMMA: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"([\p{Han}\p{Hiragana}\p{Katakana}ー])\s*[,，]\s*").unwrap());
+static ASCII_PERIOD: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"([\p{Han}\p{Hiragana}\p{Katakana}ー])\s*[.．](\s+|$)").unwrap());
+static PUNCTUATION_SPACE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\s*([。、])\s*").unwrap());
+static PLAIN_ENDING: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(ではない|じゃない|だった|だろう|である|ました|です|ます|まだ|だ|する|した|いる|ある)([。！？!?]|$)")
+        .unwrap()
+});
+
+fn polite_ending(ending: &str) -> &str {
+    match ending {
+        "ではない" | "じゃない" => "ではありません",
+        "だった" => "でした",
+        "だろう" => "でしょう",
+        "である" | "だ" => "です",
+        "する" => "します",
+        "した" => "しました",
+        "いる" => "います",
+        "ある" => "あります",
+        other => other,
+    }
+}
+
+fn combine_voiced(base: char, mark: char) -> Option<char> {
+    let offset = match mark {
+        'ﾞ' if "カキクケコサシスセソタチツテトハヒフヘホ".contains(base) => 1,
+        'ﾞ' if base == 'ウ' => return Some('ヴ'),
+        'ﾟ' if "ハヒフヘホ".contains(base) => 2,
+        _ => return None,
+    };
+    char::from_u32(base as u32 + offset)
+}
+
+fn unify_width(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    for ch in text.chars() {
+        let converted = match ch {
+            '\u{3000}' => ' ',
+            'Ａ'..='Ｚ' | 'ａ'..='ｚ' | '０'..='９' => {
+                char::from_u32(ch as u32 - 0xFEE0).unwrap_or(ch)
+            }
+            'ﾞ' | 'ﾟ' => {
+                if let Some(combined) = output
+                    .chars()
+                    .last()
+                    .and_then(|last| combine_voiced(last, ch))
+                {
+                    output.pop();
+                    output.push(combined);
+                    continue;
+                }
+                if ch == 'ﾞ' {
+                    '゛'
+                } else {
+                    '゜'
+                }
+            }
+            _ => HALF_WIDTH_KANA
+                .iter()
+                .find(|(half, _)| *half == ch)
+                .map(|(_, full)| *full)
+                .unwrap_or(ch),
+        };
+        output.push(converted);
+    }
+    output
+}
+
+fn trim_spaces(text: &str) -> String {
+    let text = SPACE_RUN.replace_all(text.trim(), " ");
+    let mut text = text.to_string();
+    loop {
+        let next = JAPANESE_GAP.replace_all(&text, "$1$2").to_string();
+        if next == text {
+            return text;
+        }
+        text = next;
+    }
+}
+
+fn normalize_punctuation(text: &str) -> String {
+    let text = ASCII_COMMA.replace_all(text, "$1、");
+    let text = ASCII_PERIOD.replace_all(&text, "$1。");
+    PUNCTUATION_SPACE
+        .replace_all(&text, "$1")
+        .trim()
+        .to_string()
+}
+
+fn polite_form(text: &str) -> String {
+    PLAIN_ENDING
+        .replace_all(text, |captures: &Captures| {
+            format!("{}{}", polite_ending(&captures[1]), &captures[2])
+        })
+        .to_string()
+}
+
+pub(crate) fn is_enabled(whisper: &WhisperConfig) -> bool {
+    whisper.normalize_width
+        || whisper.trim_spaces
+        || whisper.normalize_punctuation
+        || whisper.polite_form
+}
+
+pub(crate) fn normalize(whisper: &WhisperConfig, text: &str) -> String {
+    let mut text = text.to_string();
+    if whisper.normalize_width {
+        text = unify_width(&text);
+    }
+    if whisper.trim_spaces {
+        text = trim_spaces(&text);
+    }
+    if whisper.normalize_punctuation {
+        text = normalize_punctuation(&text);
+    }
+    if whisper.polite_form {
+        text = polite_form(&text);
+    }
+    text
+}
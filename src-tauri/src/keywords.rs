@@ -0,0 +1,269 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::Serialize;
+
+use crate::{format_seconds, TranscriptionSegment};
+
+const KEYWORD_LIMIT: usize = 20;
+const TOPIC_KEYWORDS: usize = 3;
+const TOPIC_WINDOW_SECS: f64 = 600.0;
+
+const STOPWORDS: &[&str] = &[
+    "the",
+    "and",
+    "for",
+    "that",
+    "this",
+    "with",
+    "you",
+    "are",
+    "was",
+    "have",
+    "not",
+    "but",
+    "can",
+    "will",
+    "just",
+    "what",
+    "there",
+    "about",
+    "from",
+    "they",
+    "one",
+    "all",
+    "なので",
+    "ところ",
+    "こと",
+    "もの",
+    "ため",
+    "よう",
+    "それ",
+    "これ",
+    "あれ",
+    "感じ",
+    "ちょっと",
+];
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Keyword {
+    term: String,
+    count: usize,
+    score: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Topic {
+    start: f64,
+    end: f64,
+    keywords: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct KeywordReport {
+    keywords: Vec<Keyword>,
+    topics: Vec<Topic>,
+}
+
+#[cfg(feature = "keywords")]
+fn tokenize(text: &str) -> Vec<String> {
+    use std::borrow::Cow;
+    use std::sync::LazyLock;
+
+    use lindera::dictionary::load_dictionary;
+    use lindera::mode::Mode;
+    use lindera::segmenter::Segmenter;
+
+    static SEGMENTER: LazyLock<Option<Segmenter>> =
+        LazyLock::new(|| match load_dictionary("embedded://ipadic") {
+            Ok(dictionary) => Some(Segmenter::new(Mode::Normal, dictionary, None)),
+            Err(err) => {
+                eprintln!("Failed to load the ipadic dictionary: {err}");
+                None
+            }
+        });
+
+    let Some(segmenter) = SEGMENTER.as_ref() else {
+        return split_by_script(text);
+    };
+    let Ok(mut tokens) = segmenter.segment(Cow::Borrowed(text)) else {
+        return split_by_script(text);
+    };
+    let mut terms = Vec::new();
+    let mut compound = String::new();
+    for token in tokens.iter_mut() {
+        let surface = token.surface.to_string();
+        let details = token.details();
+        let is_noun = details.first() == Some(&"名詞")
+            && !matches!(
+                details.get(1).copied(),
+                Some("非自立" | "代名詞" | "数" | "接尾")
+            );
+        if is_noun {
+            compound.push_str(&surface);
+        } else if !compound.is_empty() {
+            terms.push(std::mem::take(&mut compound));
+        }
+    }
+    if !compound.is_empty() {
+        terms.push(compound);
+    }
+    terms
+}
+
+#[cfg(not(feature = "keywords"))]
+fn tokenize(text: &str) -> Vec<String> {
+    split_by_script(text)
+}
+
+#[derive(PartialEq, Clone, Copy)]
+enum Script {
+    Kanji,
+    Katakana,
+    Latin,
+    Other,
+}
+
+fn script_of(ch: char) -> Script {
+    match ch {
+        '\u{4E00}'..='\u{9FFF}' | '々' => Script::Kanji,
+        '\u{30A0}'..='\u{30FF}' => Script::Katakana,
+        ch if ch.is_ascii_alphanumeric() => Script::Latin,
+        _ => Script::Other,
+    }
+}
+
+fn split_by_script(text: &str) -> Vec<String> {
+    let mut terms = Vec::new();
+    let mut current = String::new();
+    let mut current_script = Script::Other;
+    for ch in text.chars() {
+        let script = script_of(ch);
+        if script != current_script && !current.is_empty() {
+            terms.push(std::mem::take(&mut current));
+        }
+        if script != Script::Other {
+            current.push(ch);
+        }
+        current_script = script;
+    }
+    if !current.is_empty() {
+        terms.push(current);
+    }
+    terms
+}
+
+fn is_candidate(term: &str) -> bool {
+    let length = term.chars().count();
+    let min_length = if term.is_ascii() { 3 } else { 2 };
+    length >= min_length
+        && !term.chars().all(|ch| ch.is_ascii_digit())
+        && !STOPWORDS.contains(&term.to_lowercase().as_str())
+}
+
+fn segment_terms(segments: &[TranscriptionSegment]) -> Vec<Vec<String>> {
+    segments
+        .iter()
+        .map(|segment| {
+            tokenize(&segment.text)
+                .into_iter()
+                .filter(|term| is_candidate(term))
+                .collect()
+        })
+        .collect()
+}
+
+fn rank(terms: &[&Vec<String>], total_docs: usize, df: &HashMap<&str, usize>) -> Vec<Keyword> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for term in terms.iter().flat_map(|terms| terms.iter()) {
+        *counts.entry(term.as_str()).or_default() += 1;
+    }
+    let mut keywords: Vec<Keyword> = counts
+        .into_iter()
+        .map(|(term, count)| {
+            let docs = df.get(term).copied().unwrap_or(1).max(1) as f64;
+            let idf = 1.0 + (total_docs.max(1) as f64 / docs).ln();
+            let length_boost = (term.chars().count() as f64).sqrt();
+            Keyword {
+                term: term.to_string(),
+                count,
+                score: count as f64 * idf * length_boost,
+            }
+        })
+        .collect();
+    keywords.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.term.cmp(&b.term))
+    });
+    keywords
+}
+
+pub(crate) fn extract(segments: &[TranscriptionSegment]) -> KeywordReport {
+    let terms = segment_terms(segments);
+    let mut df: HashMap<&str, usize> = HashMap::new();
+    for doc in &terms {
+        let unique: HashSet<&str> = doc.iter().map(String::as_str).collect();
+        for term in unique {
+            *df.entry(term).or_default() += 1;
+        }
+    }
+
+    let all: Vec<&Vec<String>> = terms.iter().collect();
+    let mut keywords = rank(&all, terms.len(), &df);
+    keywords.truncate(KEYWORD_LIMIT);
+
+    let mut topics = Vec::new();
+    let mut window_start = 0;
+    while window_start < segments.len() {
+        let start = segments[window_start].start;
+        let window_end = segments[window_start..]
+            .iter()
+            .position(|segment| segment.start >= start + TOPIC_WINDOW_SECS)
+            .map(|offset| window_start + offset)
+            .unwrap_or(segments.len());
+        let window: Vec<&Vec<String>> = terms[window_start..window_end].iter().collect();
+        let top: Vec<String> = rank(&window, terms.len(), &df)
+            .into_iter()
+            .take(TOPIC_KEYWORDS)
+            .map(|keyword| keyword.term)
+            .collect();
+        if !top.is_empty() {
+            let last = &segments[window_end - 1];
+            topics.push(Topic {
+                start,
+                end: last.end.unwrap_or(last.start),
+                keywords: top,
+            });
+        }
+        window_start = window_end;
+    }
+
+    KeywordReport { keywords, topics }
+}
+
+pub(crate) fn render(report: &KeywordReport) -> String {
+    let mut output = String::from("\n## キーワード\n");
+    let terms: Vec<&str> = report
+        .keywords
+        .iter()
+        .map(|keyword| keyword.term.as_str())
+        .collect();
+    output.push_str(&terms.join("、"));
+    output.push('\n');
+    if !report.topics.is_empty() {
+        output.push_str("\n## トピック\n");
+        for topic in &report.topics {
+            output.push_str(&format!(
+                "- {}〜{} {}\n",
+                format_seconds(topic.start),
+                format_seconds(topic.end),
+                topic.keywords.join("、")
+            ));
+        }
+    }
+    output
+}
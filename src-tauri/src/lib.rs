@@ -1,7 +1,7 @@
 use std::cmp::Ordering;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::{Path, PathBuf};
-use std::sync::Mutex;
+use std::sync::{LazyLock, Mutex};
 
 use anyhow::{anyhow, Context, Result};
 use aws_config::BehaviorVersion;
@@ -11,13 +11,63 @@ use aws_sdk_s3::error::SdkError;
 use aws_sdk_s3::Client;
 use chrono::{Datelike, NaiveDate, NaiveTime, Timelike};
 use directories::{ProjectDirs, UserDirs};
+use percent_encoding::percent_decode_str;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
-use tauri::State;
+use tauri::{AppHandle, Emitter, State};
 use tokio::fs;
 use tokio::io::AsyncBufReadExt;
 use tokio::process::Command;
 use uuid::Uuid;
 
+mod action_items;
+mod archive;
+mod azure;
+mod backend;
+mod config_crypto;
+mod config_watcher;
+mod diarize;
+mod editor;
+mod env_overrides;
+mod gcs;
+mod gdrive;
+mod header;
+#[cfg(feature = "http-api")]
+mod http_api;
+mod integrations;
+mod ja_normalize;
+mod keywords;
+mod listing_cache;
+mod llm;
+mod models;
+mod notifications;
+mod postprocess;
+mod scheduler;
+mod search_index;
+mod silence;
+mod storage;
+mod store;
+mod tags;
+mod timeline;
+mod tls;
+mod transcript_cache;
+mod transcripts;
+mod translate;
+mod validate;
+mod watcher;
+mod waveform;
+
+use backend::{
+    align_segments, create_backend, detect_gpu_backend, gpu_backend_log, whisper_cli_args,
+    TranscriptionBackend,
+};
+use listing_cache::{cached_dates, cached_meetings, Listing};
+use models::{
+    is_model_file, DownloadState, FirstRunStatus, KnownModel, LocalModel, ModelDiskUsage,
+    ModelRecommendation,
+};
+use storage::{create_source, DeleteResult, FileSource, ObjectInfo, StorageSource, UrlSource};
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase", default)]
 struct MinioConfig {
@@ -28,6 +78,100 @@ struct MinioConfig {
     secret_key: String,
     bucket: String,
     region: String,
+    provider: String,
+    #[serde(alias = "ca_bundle_path")]
+    ca_bundle_path: String,
+    #[serde(alias = "insecure_skip_verify")]
+    insecure_skip_verify: bool,
+    #[serde(alias = "download_concurrency")]
+    download_concurrency: Option<u32>,
+    #[serde(alias = "retry_attempts")]
+    retry_attempts: Option<u32>,
+    prefix: String,
+    #[serde(alias = "local_root")]
+    local_root: String,
+    #[serde(alias = "sftp_host")]
+    sftp_host: String,
+    #[serde(alias = "sftp_port")]
+    sftp_port: Option<u16>,
+    #[serde(alias = "sftp_username")]
+    sftp_username: String,
+    #[serde(alias = "sftp_password")]
+    sftp_password: String,
+    #[serde(alias = "sftp_private_key_path")]
+    sftp_private_key_path: String,
+    #[serde(alias = "sftp_host_fingerprint")]
+    sftp_host_fingerprint: String,
+    #[serde(alias = "sftp_root")]
+    sftp_root: String,
+    #[serde(alias = "webdav_url")]
+    webdav_url: String,
+    #[serde(alias = "webdav_username")]
+    webdav_username: String,
+    #[serde(alias = "webdav_password")]
+    webdav_password: String,
+    #[serde(alias = "gdrive_client_id")]
+    gdrive_client_id: String,
+    #[serde(alias = "gdrive_client_secret")]
+    gdrive_client_secret: String,
+    #[serde(alias = "gdrive_folder_id")]
+    gdrive_folder_id: String,
+    #[serde(alias = "azure_connection_string")]
+    azure_connection_string: String,
+    #[serde(alias = "azure_sas_url")]
+    azure_sas_url: String,
+    #[serde(alias = "azure_container")]
+    azure_container: String,
+    #[serde(alias = "gcs_service_account_path")]
+    gcs_service_account_path: String,
+    #[serde(alias = "upload_transcripts")]
+    upload_transcripts: bool,
+    #[serde(alias = "transcript_upload_dir")]
+    transcript_upload_dir: String,
+}
+
+impl MinioConfig {
+    fn transcript_upload_dir(&self) -> String {
+        let trimmed = self.transcript_upload_dir.trim().trim_matches('/');
+        if trimmed.is_empty() {
+            DEFAULT_TRANSCRIPT_UPLOAD_DIR.to_string()
+        } else {
+            trimmed.to_string()
+        }
+    }
+
+    fn key_root(&self) -> String {
+        let trimmed = self.prefix.trim().trim_matches('/');
+        if trimmed.is_empty() {
+            String::new()
+        } else {
+            format!("{trimmed}/")
+        }
+    }
+
+    fn secrets_mut(&mut self) -> [&mut String; 6] {
+        [
+            &mut self.secret_key,
+            &mut self.sftp_password,
+            &mut self.webdav_password,
+            &mut self.gdrive_client_secret,
+            &mut self.azure_connection_string,
+            &mut self.azure_sas_url,
+        ]
+    }
+
+    fn keep_secrets_from(&mut self, current: &MinioConfig) {
+        let mut current = current.clone();
+        fill_empty_secrets(self.secrets_mut(), current.secrets_mut());
+    }
+}
+
+fn fill_empty_secrets<const N: usize>(mine: [&mut String; N], current: [&mut String; N]) {
+    for (mine, current) in mine.into_iter().zip(current) {
+        if mine.is_empty() {
+            *mine = current.clone();
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -45,6 +189,115 @@ struct WhisperConfig {
     include_timestamps: bool,
     #[serde(alias = "include_speaker")]
     include_speaker: bool,
+    language: String,
+    #[serde(alias = "output_format")]
+    output_format: String,
+    #[serde(alias = "beam_size")]
+    beam_size: Option<u32>,
+    #[serde(alias = "best_of")]
+    best_of: Option<u32>,
+    temperature: Option<f32>,
+    #[serde(alias = "word_timestamps")]
+    word_timestamps: bool,
+    #[serde(alias = "speaker_timeline")]
+    speaker_timeline: bool,
+    #[serde(alias = "overwrite_outputs")]
+    overwrite_outputs: bool,
+    #[serde(alias = "metadata_header")]
+    metadata_header: bool,
+    #[serde(alias = "metadata_header_template")]
+    metadata_header_template: String,
+    gpu: bool,
+    #[serde(alias = "gpu_device")]
+    gpu_device: Option<u32>,
+    backend: String,
+    #[serde(alias = "server_binary_path")]
+    server_binary_path: String,
+    #[serde(alias = "openai_api_key")]
+    openai_api_key: String,
+    #[serde(alias = "openai_base_url")]
+    openai_base_url: String,
+    #[serde(alias = "openai_model")]
+    openai_model: String,
+    #[serde(alias = "external_command")]
+    external_command: String,
+    #[serde(alias = "azure_speech_key")]
+    azure_speech_key: String,
+    #[serde(alias = "azure_speech_region")]
+    azure_speech_region: String,
+    #[serde(alias = "deepgram_api_key")]
+    deepgram_api_key: String,
+    #[serde(alias = "deepgram_model")]
+    deepgram_model: String,
+    #[serde(alias = "vosk_model_path")]
+    vosk_model_path: String,
+    #[serde(alias = "alignment_command")]
+    alignment_command: String,
+    diarization: String,
+    #[serde(alias = "diarization_command")]
+    diarization_command: String,
+    #[serde(alias = "stream_partial_results")]
+    stream_partial_results: bool,
+    threads: Option<u32>,
+    #[serde(alias = "parallel_tracks")]
+    parallel_tracks: Option<u32>,
+    #[serde(alias = "chunk_minutes")]
+    chunk_minutes: Option<u32>,
+    #[serde(alias = "chunk_overlap_secs")]
+    chunk_overlap_secs: Option<u32>,
+    #[serde(alias = "noise_preset")]
+    noise_preset: String,
+    #[serde(alias = "audio_filters")]
+    audio_filters: String,
+    #[serde(alias = "stereo_speakers")]
+    stereo_speakers: bool,
+    #[serde(alias = "stereo_speaker_names")]
+    stereo_speaker_names: Vec<String>,
+    #[serde(alias = "reuse_model")]
+    reuse_model: bool,
+    #[serde(alias = "min_speech_ratio")]
+    min_speech_ratio: Option<f32>,
+    #[serde(alias = "trim_silence_secs")]
+    trim_silence_secs: Option<u32>,
+    #[serde(alias = "speed_up")]
+    speed_up: Option<f32>,
+    #[serde(alias = "concat_speakers")]
+    concat_speakers: bool,
+    #[serde(alias = "keep_temp_files")]
+    keep_temp_files: bool,
+    #[serde(alias = "temp_retention_days")]
+    temp_retention_days: Option<u32>,
+    #[serde(alias = "model_mirror_url")]
+    model_mirror_url: String,
+    #[serde(alias = "action_items")]
+    action_items: String,
+    #[serde(alias = "action_items_model")]
+    action_items_model: String,
+    #[serde(alias = "hallucination_filter")]
+    hallucination_filter: bool,
+    #[serde(alias = "hallucination_phrases")]
+    hallucination_phrases: Vec<String>,
+    #[serde(alias = "normalize_width")]
+    normalize_width: bool,
+    #[serde(alias = "trim_spaces")]
+    trim_spaces: bool,
+    #[serde(alias = "normalize_punctuation")]
+    normalize_punctuation: bool,
+    #[serde(alias = "polite_form")]
+    polite_form: bool,
+    #[serde(alias = "ng_words")]
+    ng_words: Vec<String>,
+    #[serde(alias = "ng_word_mode")]
+    ng_word_mode: String,
+    #[serde(alias = "translation_provider")]
+    translation_provider: String,
+    #[serde(alias = "translation_target")]
+    translation_target: String,
+    #[serde(alias = "translation_api_key")]
+    translation_api_key: String,
+    #[serde(alias = "translation_model")]
+    translation_model: String,
+    keywords: String,
 }
 
 impl Default for WhisperConfig {
@@ -56,15 +309,267 @@ impl Default for WhisperConfig {
             output_dir: String::new(),
             include_timestamps: false,
             include_speaker: true,
+            language: "ja".to_string(),
+            output_format: "txt".to_string(),
+            beam_size: None,
+            best_of: None,
+            temperature: None,
+            word_timestamps: false,
+            speaker_timeline: false,
+            overwrite_outputs: false,
+            metadata_header: false,
+            metadata_header_template: String::new(),
+            gpu: true,
+            gpu_device: None,
+            backend: "cli".to_string(),
+            server_binary_path: String::new(),
+            openai_api_key: String::new(),
+            openai_base_url: String::new(),
+            openai_model: String::new(),
+            external_command: String::new(),
+            azure_speech_key: String::new(),
+            azure_speech_region: String::new(),
+            deepgram_api_key: String::new(),
+            deepgram_model: String::new(),
+            vosk_model_path: String::new(),
+            alignment_command: String::new(),
+            diarization: String::new(),
+            diarization_command: String::new(),
+            stream_partial_results: false,
+            threads: None,
+            parallel_tracks: None,
+            chunk_minutes: None,
+            chunk_overlap_secs: None,
+            noise_preset: String::new(),
+            audio_filters: String::new(),
+            stereo_speakers: false,
+            stereo_speaker_names: Vec::new(),
+            reuse_model: false,
+            min_speech_ratio: None,
+            trim_silence_secs: None,
+            speed_up: None,
+            concat_speakers: false,
+            keep_temp_files: false,
+            temp_retention_days: None,
+            model_mirror_url: String::new(),
+            action_items: String::new(),
+            action_items_model: String::new(),
+            hallucination_filter: true,
+            hallucination_phrases: Vec::new(),
+            normalize_width: false,
+            trim_spaces: false,
+            normalize_punctuation: false,
+            polite_form: false,
+            ng_words: Vec::new(),
+            ng_word_mode: "mask".to_string(),
+            translation_provider: String::new(),
+            translation_target: String::new(),
+            translation_api_key: String::new(),
+            translation_model: String::new(),
+            keywords: String::new(),
+        }
+    }
+}
+
+impl WhisperConfig {
+    fn language_code(&self) -> &str {
+        match self.language.trim() {
+            "" => "ja",
+            value => value,
+        }
+    }
+
+    fn output_extension(&self) -> &'static str {
+        match self.output_format.trim() {
+            "srt" => "srt",
+            "vtt" => "vtt",
+            "json" => "json",
+            _ => "txt",
+        }
+    }
+
+    fn audio_filter(&self) -> Option<String> {
+        let preset = match self.noise_preset.trim() {
+            "meeting_room" => MEETING_ROOM_FILTER,
+            "call_recording" => CALL_RECORDING_FILTER,
+            _ => "",
+        };
+        let filters: Vec<&str> = [preset, self.audio_filters.trim()]
+            .into_iter()
+            .filter(|filter| !filter.is_empty())
+            .collect();
+        if filters.is_empty() {
+            None
+        } else {
+            Some(filters.join(","))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct JobOptions {
+    language: Option<String>,
+    model: Option<String>,
+    include_timestamps: Option<bool>,
+    include_speaker: Option<bool>,
+    output_format: Option<String>,
+    overwrite: Option<bool>,
+}
+
+impl JobOptions {
+    fn apply(self, whisper: &mut WhisperConfig) {
+        if let Some(language) = self.language.filter(|value| !value.trim().is_empty()) {
+            whisper.language = language.trim().to_string();
+        }
+        if let Some(model) = self.model.filter(|value| !value.trim().is_empty()) {
+            let model = model.trim().to_string();
+            match whisper.backend.as_str() {
+                "openai" => whisper.openai_model = model,
+                "deepgram" => whisper.deepgram_model = model,
+                "vosk" => whisper.vosk_model_path = model,
+                _ => whisper.model_path = model,
+            }
+        }
+        if let Some(include_timestamps) = self.include_timestamps {
+            whisper.include_timestamps = include_timestamps;
+        }
+        if let Some(include_speaker) = self.include_speaker {
+            whisper.include_speaker = include_speaker;
+        }
+        if let Some(output_format) = self.output_format.filter(|value| !value.trim().is_empty()) {
+            whisper.output_format = output_format.trim().to_lowercase();
+        }
+        if let Some(overwrite) = self.overwrite {
+            whisper.overwrite_outputs = overwrite;
         }
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct RoomLabelRule {
+    pattern: String,
+    label: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct StorageProfile {
+    name: String,
+    #[serde(flatten)]
+    minio: MinioConfig,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase", default)]
 struct AppConfig {
     minio: MinioConfig,
+    #[serde(alias = "storage_profiles")]
+    storage_profiles: Vec<StorageProfile>,
+    #[serde(alias = "active_profile")]
+    active_profile: String,
+    #[serde(alias = "proxy_url")]
+    proxy_url: String,
+    #[serde(alias = "watch_interval_secs")]
+    watch_interval_secs: u64,
+    #[serde(alias = "watch_notifications")]
+    watch_notifications: bool,
+    #[serde(alias = "job_notifications")]
+    job_notifications: bool,
+    #[serde(alias = "schedule_mode")]
+    schedule_mode: String,
+    #[serde(alias = "schedule_time")]
+    schedule_time: String,
+    #[serde(alias = "schedule_interval_mins")]
+    schedule_interval_mins: u64,
+    #[serde(alias = "room_labels")]
+    room_labels: HashMap<String, String>,
+    #[serde(alias = "room_label_rules")]
+    room_label_rules: Vec<RoomLabelRule>,
+    replacements: Vec<postprocess::ReplacementRule>,
+    #[serde(alias = "encrypt_config")]
+    encrypt_config: bool,
     whisper: WhisperConfig,
+    integrations: integrations::IntegrationConfig,
+}
+
+impl AppConfig {
+    fn with_profile(mut self, name: Option<&str>) -> Result<Self> {
+        let name = name
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .unwrap_or(self.active_profile.trim())
+            .to_string();
+        if name.is_empty() {
+            return Ok(self);
+        }
+        let profile = self
+            .storage_profiles
+            .iter()
+            .find(|profile| profile.name == name)
+            .ok_or_else(|| anyhow!("Storage profile not found: {name}"))?;
+        self.minio = profile.minio.clone();
+        self.active_profile = name;
+        Ok(self)
+    }
+
+    fn whisper_secrets_mut(&mut self) -> [&mut String; 4] {
+        [
+            &mut self.whisper.openai_api_key,
+            &mut self.whisper.azure_speech_key,
+            &mut self.whisper.deepgram_api_key,
+            &mut self.whisper.translation_api_key,
+        ]
+    }
+
+    fn strip_secrets(&mut self) {
+        self.minio.secrets_mut().into_iter().for_each(String::clear);
+        for profile in &mut self.storage_profiles {
+            profile
+                .minio
+                .secrets_mut()
+                .into_iter()
+                .for_each(String::clear);
+        }
+        self.whisper_secrets_mut()
+            .into_iter()
+            .for_each(String::clear);
+        self.integrations
+            .secrets_mut()
+            .into_iter()
+            .for_each(String::clear);
+    }
+
+    fn keep_secrets_from(&mut self, current: &AppConfig) {
+        self.minio.keep_secrets_from(&current.minio);
+        for profile in &mut self.storage_profiles {
+            if let Some(existing) = current
+                .storage_profiles
+                .iter()
+                .find(|existing| existing.name == profile.name)
+            {
+                profile.minio.keep_secrets_from(&existing.minio);
+            }
+        }
+        let mut current = current.clone();
+        fill_empty_secrets(self.whisper_secrets_mut(), current.whisper_secrets_mut());
+        fill_empty_secrets(
+            self.integrations.secrets_mut(),
+            current.integrations.secrets_mut(),
+        );
+    }
+
+    fn store_active_profile(&mut self) {
+        let active = self.active_profile.trim();
+        if let Some(profile) = self
+            .storage_profiles
+            .iter_mut()
+            .find(|profile| profile.name == active)
+        {
+            profile.minio = self.minio.clone();
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -76,7 +581,16 @@ struct MeetingSummary {
     room_label: String,
     meeting_time: String,
     speaker_count: usize,
+    speakers: Vec<String>,
     track_count: usize,
+    #[serde(default)]
+    total_size: Option<u64>,
+    #[serde(default)]
+    estimated_duration_secs: Option<f64>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    favorite: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -84,12 +598,44 @@ struct TrackEntry {
     key: String,
     speaker: String,
     track_time: String,
+    etag: Option<String>,
+    size: Option<u64>,
+    pieces: Vec<TrackPiece>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone)]
+struct TrackPiece {
+    key: String,
+    offset: f64,
+    start: f64,
+}
+
+fn track_start_seconds(track: &TrackEntry) -> f64 {
+    parse_time_any(&track.track_time)
+        .map(|t| t.num_seconds_from_midnight() as f64)
+        .unwrap_or(0.0)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WordTiming {
+    start: f64,
+    end: f64,
+    text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct WhisperSegment {
     start: f64,
+    #[serde(default)]
+    end: Option<f64>,
     text: String,
+    #[serde(default)]
+    words: Vec<WordTiming>,
+    #[serde(default)]
+    speaker: Option<String>,
+    #[serde(default)]
+    speaker_turn_next: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -97,11 +643,21 @@ struct WhisperJson {
     segments: Vec<WhisperSegment>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct TranscriptionSegment {
     start: f64,
+    end: Option<f64>,
     speaker: String,
     text: String,
+    words: Vec<WordTiming>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct TranscriptManifest {
+    tracks: Vec<String>,
+    segments: Vec<TranscriptionSegment>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -117,13 +673,30 @@ struct JobStatus {
 
 type JobState = std::sync::Arc<Mutex<HashMap<String, JobStatus>>>;
 
+type SegmentCallback = std::sync::Arc<dyn Fn(WhisperSegment) + Send + Sync>;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PartialSegmentEvent {
+    job_id: String,
+    segment: TranscriptionSegment,
+}
+
 fn project_dirs() -> Result<ProjectDirs> {
     ProjectDirs::from("com", "whisperdesktop", "WhisperDesktop")
         .ok_or_else(|| anyhow!("Failed to resolve config directory"))
 }
 
 async fn effective_config() -> Result<AppConfig> {
-    load_saved_config().await
+    profile_config(None).await
+}
+
+async fn profile_config(profile: Option<&str>) -> Result<AppConfig> {
+    env_overrides::apply(load_saved_config().await?.with_profile(profile)?)
+}
+
+async fn editable_config() -> Result<AppConfig> {
+    load_saved_config().await?.with_profile(None)
 }
 
 fn config_path() -> Result<PathBuf> {
@@ -139,7 +712,7 @@ async fn load_saved_config() -> Result<AppConfig> {
             if trimmed.is_empty() {
                 return Ok(AppConfig::default());
             }
-            let config: AppConfig = serde_json::from_str(trimmed)?;
+            let config: AppConfig = serde_json::from_str(&config_crypto::decrypt(trimmed)?)?;
             Ok(config)
         }
         Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(AppConfig::default()),
@@ -152,19 +725,28 @@ async fn save_config_file(config: &AppConfig) -> Result<()> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent).await?;
     }
-    let payload = serde_json::to_string_pretty(config)?;
-    fs::write(path, payload).await?;
+    let mut payload = serde_json::to_string_pretty(config)?;
+    if config.encrypt_config {
+        payload = config_crypto::encrypt(&payload)?;
+    }
+    fs::write(path, &payload).await?;
+    config_watcher::note_saved(&payload);
     Ok(())
 }
 
 
 async fn s3_client(config: &AppConfig) -> Result<Client> {
     let minio = &config.minio;
-    if minio.url.is_empty()
-        || minio.access_key.is_empty()
-        || minio.secret_key.is_empty()
-        || minio.bucket.is_empty()
-    {
+    let provider = match minio.provider.trim() {
+        "" => "minio",
+        value => value,
+    };
+    if !matches!(provider, "minio" | "aws" | "other") {
+        return Err(anyhow!("Unknown storage provider: {provider}"));
+    }
+    let is_aws = provider == "aws";
+    let has_keys = !minio.access_key.is_empty() && !minio.secret_key.is_empty();
+    if (!is_aws && (minio.url.is_empty() || !has_keys)) || minio.bucket.is_empty() {
         return Err(anyhow!("MinIO config is incomplete"));
     }
 
@@ -174,46 +756,169 @@ async fn s3_client(config: &AppConfig) -> Result<Client> {
         Region::new(minio.region.clone())
     };
 
-    let creds = Credentials::new(
-        minio.access_key.clone(),
-        minio.secret_key.clone(),
-        None,
-        None,
-        "static",
-    );
+    let mut loader = aws_config::defaults(BehaviorVersion::latest()).region(region);
+    if has_keys {
+        loader = loader.credentials_provider(Credentials::new(
+            minio.access_key.clone(),
+            minio.secret_key.clone(),
+            None,
+            None,
+            "static",
+        ));
+    }
+    let shared = loader.load().await;
 
-    let shared = aws_config::defaults(BehaviorVersion::latest())
-        .region(region)
-        .credentials_provider(creds)
-        .load()
-        .await;
+    let mut builder =
+        aws_sdk_s3::config::Builder::from(&shared).force_path_style(provider == "minio");
+    if !minio.url.is_empty() {
+        builder = builder.endpoint_url(minio.url.clone());
+    }
+    if let Some(http_client) = tls::s3_http_client(minio, &config.proxy_url)? {
+        builder = builder.http_client(http_client);
+    }
+
+    Ok(Client::from_conf(builder.build()))
+}
 
-    let conf = aws_sdk_s3::config::Builder::from(&shared)
-        .endpoint_url(minio.url.clone())
-        .force_path_style(true)
-        .build();
+#[tauri::command]
+async fn check_minio(profile: Option<String>) -> Result<(), String> {
+    let config = profile_config(profile.as_deref())
+        .await
+        .map_err(|err| err.to_string())?;
+    let source = create_source(&config)
+        .await
+        .map_err(|err| err.to_string())?;
+    with_retry(
+        s3_retry_attempts(&config.minio),
+        "check_minio",
+        log_retry,
+        || source.check(),
+    )
+    .await
+    .map_err(|err| err.to_string())
+}
 
-    Ok(Client::from_conf(conf))
+#[tauri::command]
+async fn validate_config(profile: Option<String>) -> Result<Vec<validate::ConfigCheck>, String> {
+    let config = profile_config(profile.as_deref())
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(validate::validate(&config).await)
 }
 
 #[tauri::command]
-async fn check_minio() -> Result<(), String> {
+async fn detect_everything() -> Result<validate::SetupReport, String> {
     let config = effective_config().await.map_err(|err| err.to_string())?;
-    let client = s3_client(&config).await.map_err(|err| err.to_string())?;
-    client
-        .list_objects_v2()
-        .bucket(&config.minio.bucket)
-        .max_keys(1)
-        .send()
+    validate::detect_everything(&config)
         .await
-        .map_err(format_sdk_error)?;
-    Ok(())
+        .map_err(|err| err.to_string())
 }
 
 fn format_sdk_error<E: std::fmt::Debug>(err: SdkError<E>) -> String {
     format!("{err:?}")
 }
 
+const DEFAULT_S3_RETRY_ATTEMPTS: u32 = 3;
+const RETRY_BASE_DELAY_MS: u64 = 500;
+const RETRY_MAX_DELAY_MS: u64 = 10_000;
+const DEFAULT_TRANSCRIPT_UPLOAD_DIR: &str = "transcript";
+const TRANSCRIPT_UPLOAD_SIDECARS: &[&str] = &[
+    "words.json",
+    "timeline.json",
+    "keywords.json",
+    "actions.txt",
+];
+
+fn s3_retry_attempts(minio: &MinioConfig) -> u32 {
+    minio
+        .retry_attempts
+        .filter(|value| *value > 0)
+        .unwrap_or(DEFAULT_S3_RETRY_ATTEMPTS)
+}
+
+fn retry_delay(attempt: u32) -> std::time::Duration {
+    let base = RETRY_BASE_DELAY_MS
+        .saturating_mul(1 << attempt.saturating_sub(1).min(16))
+        .min(RETRY_MAX_DELAY_MS);
+    let jitter = (Uuid::new_v4().as_u128() % (base as u128 / 2 + 1)) as u64;
+    std::time::Duration::from_millis(base + jitter)
+}
+
+fn log_retry(line: &str) {
+    eprintln!("{line}");
+}
+
+const PERMANENT_ERROR_MARKERS: &[&str] = &[
+    "AccessDenied",
+    "NoSuchKey",
+    "NoSuchBucket",
+    "InvalidBucketName",
+    "InvalidAccessKeyId",
+    "SignatureDoesNotMatch",
+    "AuthorizationHeaderMalformed",
+    "ExpiredToken",
+    "authentication failed",
+    "host key",
+];
+const TRANSIENT_ERROR_MARKERS: &[&str] = &[
+    "slowdown",
+    "throttl",
+    "timeout",
+    "timed out",
+    "dispatchfailure",
+    "responseerror",
+    "internalerror",
+    "serviceunavailable",
+    "connection",
+    "broken pipe",
+    "unexpected eof",
+];
+static TRANSIENT_STATUS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?:StatusCode\(|failed with |: )(?:5\d\d|429)\b").unwrap());
+
+fn is_transient_error(message: &str) -> bool {
+    if PERMANENT_ERROR_MARKERS
+        .iter()
+        .any(|marker| message.contains(marker))
+    {
+        return false;
+    }
+    let lower = message.to_lowercase();
+    TRANSIENT_ERROR_MARKERS
+        .iter()
+        .any(|marker| lower.contains(marker))
+        || TRANSIENT_STATUS.is_match(message)
+}
+
+async fn with_retry<T, E, F, Fut>(
+    attempts: u32,
+    label: &str,
+    on_retry: impl Fn(&str),
+    mut op: F,
+) -> Result<T, E>
+where
+    E: std::fmt::Display + std::fmt::Debug,
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T, E>>,
+{
+    let mut attempt = 1;
+    loop {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(err) if attempt < attempts && is_transient_error(&format!("{err:?}")) => {
+                let delay = retry_delay(attempt);
+                on_retry(&format!(
+                    "{label} failed (attempt {attempt}/{attempts}): {err}; retrying in {}ms",
+                    delay.as_millis()
+                ));
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
 fn parse_key(key: &str) -> Option<(String, String, String, String, String)> {
     let mut parts = key.split('/');
     let date = parts.next()?.to_string();
@@ -226,7 +931,11 @@ fn parse_key(key: &str) -> Option<(String, String, String, String, String)> {
         return None;
     }
 
-    let file = file.strip_suffix(".ogg").unwrap_or(&file);
+    let file = match file.rsplit_once('.') {
+        Some((stem, extension)) if is_media_extension(extension) => stem,
+        Some(_) => return None,
+        None => file.as_str(),
+    };
     let (track_time, _) = match file.split_once('_') {
         Some((time, rest)) => (time.to_string(), rest.to_string()),
         None => (file.to_string(), String::new()),
@@ -235,6 +944,21 @@ fn parse_key(key: &str) -> Option<(String, String, String, String, String)> {
     Some((date, room_id, meeting_time, speaker, track_time))
 }
 
+const AUDIO_EXTENSIONS: &[&str] = &["ogg", "opus", "m4a", "mp3", "aac", "webm", "flac", "wav"];
+const VIDEO_EXTENSIONS: &[&str] = &["mp4", "mkv", "mov"];
+
+fn is_media_extension(extension: &str) -> bool {
+    let extension = extension.to_ascii_lowercase();
+    AUDIO_EXTENSIONS.contains(&extension.as_str()) || VIDEO_EXTENSIONS.contains(&extension.as_str())
+}
+
+fn is_video(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| VIDEO_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()))
+        .unwrap_or(false)
+}
+
 fn parse_japanese_time(value: &str) -> Option<NaiveTime> {
     let trimmed = value.trim();
     let (hour_part, rest) = trimmed.split_once('時')?;
@@ -314,6 +1038,59 @@ fn format_date_japanese(value: &str) -> Option<String> {
     Some(format!("{}年{}月{}日", date.year(), date.month(), date.day()))
 }
 
+struct RoomLabeler {
+    labels: HashMap<String, String>,
+    rules: Vec<(Regex, String)>,
+}
+
+impl RoomLabeler {
+    fn new(config: &AppConfig) -> Self {
+        let rules = config
+            .room_label_rules
+            .iter()
+            .filter(|rule| !rule.pattern.trim().is_empty())
+            .filter_map(|rule| match Regex::new(rule.pattern.trim()) {
+                Ok(regex) => Some((regex, rule.label.clone())),
+                Err(err) => {
+                    eprintln!("Invalid room label rule {}: {err}", rule.pattern);
+                    None
+                }
+            })
+            .collect();
+        Self {
+            labels: config.room_labels.clone(),
+            rules,
+        }
+    }
+
+    fn label(&self, room_id: &str) -> String {
+        if let Some(label) = self.labels.get(room_id).filter(|label| !label.is_empty()) {
+            return label.clone();
+        }
+        for (regex, template) in &self.rules {
+            if let Some(captures) = regex.captures(room_id) {
+                let mut label = String::new();
+                captures.expand(template, &mut label);
+                if !label.is_empty() {
+                    return label;
+                }
+            }
+        }
+        extract_room_label(room_id)
+    }
+
+    fn relabel(&self, meetings: &mut [MeetingSummary]) {
+        for meeting in meetings {
+            meeting.room_label = self.label(&meeting.room_id);
+        }
+    }
+}
+
+async fn annotate_meetings(config: &AppConfig, meetings: &mut [MeetingSummary]) {
+    RoomLabeler::new(config).relabel(meetings);
+    tags::apply_tags(config, meetings).await;
+}
+
 fn extract_room_label(room_id: &str) -> String {
     const PREFIX: &str = "localWorld.";
     if let Some(rest) = room_id.strip_prefix(PREFIX) {
@@ -341,6 +1118,104 @@ fn output_root(config: &AppConfig) -> Result<PathBuf> {
     default_output_dir()
 }
 
+fn meeting_output_path(config: &AppConfig, meeting_id: &str) -> Result<PathBuf> {
+    let output_root = output_root(config)?;
+    let mut parts = meeting_id.splitn(3, '/');
+    let date_part = parts.next().unwrap_or(meeting_id);
+    let room_part = parts.next().unwrap_or("unknown_room");
+    let time_part = parts.next().unwrap_or("unknown_time");
+    let formatted_date = format_date_japanese(date_part)
+        .unwrap_or_else(|| date_part.replace(['/', '\\'], "_"));
+    let safe_date = formatted_date.replace(['/', '\\'], "_");
+    let safe_room = room_part.replace(['/', '\\'], "_");
+    let formatted_time =
+        format_time_japanese(time_part).unwrap_or_else(|| time_part.to_string());
+    let safe_time = formatted_time.replace(['/', '\\'], "_");
+    let output_file = format!("{safe_date}_{safe_room}_{safe_time}");
+    Ok(output_root
+        .join(output_file)
+        .with_extension(config.whisper.output_extension()))
+}
+
+fn manifest_path(output_path: &Path) -> PathBuf {
+    output_path.with_extension("manifest.json")
+}
+
+fn output_version_path(base: &Path, version: u32) -> PathBuf {
+    if version <= 1 {
+        return base.to_path_buf();
+    }
+    let stem = base
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let mut name = format!("{stem}-v{version}");
+    if let Some(extension) = base.extension() {
+        name.push('.');
+        name.push_str(&extension.to_string_lossy());
+    }
+    base.with_file_name(name)
+}
+
+fn latest_output_version(base: &Path) -> Option<(u32, PathBuf)> {
+    let mut latest = None;
+    let mut version = 1;
+    loop {
+        let path = output_version_path(base, version);
+        if !path.exists() && !manifest_path(&path).exists() {
+            return latest;
+        }
+        latest = Some((version, path));
+        version += 1;
+    }
+}
+
+fn versioned_output_path(config: &AppConfig, base: &Path) -> (PathBuf, Option<PathBuf>) {
+    match latest_output_version(base) {
+        None => (base.to_path_buf(), None),
+        Some((_, latest)) if config.whisper.overwrite_outputs => (latest.clone(), Some(latest)),
+        Some((version, latest)) => (output_version_path(base, version + 1), Some(latest)),
+    }
+}
+
+fn record_output_lineage(
+    jobs_state: &JobState,
+    job_id: &str,
+    output_path: &Path,
+    previous: Option<&Path>,
+) {
+    let Some(previous) = previous else {
+        return;
+    };
+    if previous == output_path {
+        append_log(
+            jobs_state,
+            job_id,
+            &format!("Overwriting {}", output_path.display()),
+        );
+    } else {
+        append_log(
+            jobs_state,
+            job_id,
+            &format!(
+                "Keeping {} and writing {}",
+                previous.display(),
+                output_path.display()
+            ),
+        );
+    }
+    if let Err(err) = store::record_job_previous_output(job_id, &previous.to_string_lossy()) {
+        append_log(jobs_state, job_id, &format!("Failed to record job: {err}"));
+    }
+}
+
+async fn load_manifest(output_path: &Path) -> TranscriptManifest {
+    match fs::read_to_string(manifest_path(output_path)).await {
+        Ok(data) => serde_json::from_str(&data).unwrap_or_default(),
+        Err(_) => TranscriptManifest::default(),
+    }
+}
+
 fn default_output_dir() -> Result<PathBuf> {
     if let Some(user_dirs) = UserDirs::new() {
         if let Some(downloads) = user_dirs.download_dir() {
@@ -388,6 +1263,21 @@ fn default_whisper_binary_candidates() -> Vec<&'static str> {
     }
 }
 
+fn bundled_whisper_binary() -> Option<PathBuf> {
+    let exe = std::env::current_exe().ok()?;
+    let name = if cfg!(target_os = "windows") {
+        "whisper-cli.exe"
+    } else {
+        "whisper-cli"
+    };
+    let candidate = exe.parent()?.join(name);
+    if candidate.is_file() {
+        Some(candidate)
+    } else {
+        None
+    }
+}
+
 fn default_whisper_binary_paths() -> Vec<PathBuf> {
     if cfg!(target_os = "macos") {
         vec![
@@ -465,11 +1355,13 @@ fn resolve_whisper_paths(config: &AppConfig) -> Result<(PathBuf, PathBuf)> {
     let model_root = default_whisper_model_root()?;
     let requested_binary = config.whisper.binary_path.trim();
     let binary = if requested_binary.is_empty() {
-        let mut found: Option<PathBuf> = None;
-        for candidate in default_whisper_binary_candidates() {
-            if let Some(path) = find_in_path(candidate) {
-                found = Some(path);
-                break;
+        let mut found: Option<PathBuf> = bundled_whisper_binary();
+        if found.is_none() {
+            for candidate in default_whisper_binary_candidates() {
+                if let Some(path) = find_in_path(candidate) {
+                    found = Some(path);
+                    break;
+                }
             }
         }
         if found.is_none() {
@@ -504,18 +1396,73 @@ fn resolve_whisper_paths(config: &AppConfig) -> Result<(PathBuf, PathBuf)> {
         requested_model
     };
     let model = if requested_model.is_empty() {
-        model_root.join("ggml-large-v3.bin")
+        find_default_model(&model_root).unwrap_or_else(|| model_root.join("ggml-large-v3.bin"))
     } else {
         let requested_path = PathBuf::from(requested_model);
-        if requested_path.is_absolute() {
+        let requested_path = if requested_path.is_absolute() {
             requested_path
         } else {
             model_root.join(cleaned_model)
-        }
+        };
+        resolve_model_file(&requested_path).unwrap_or(requested_path)
     };
     Ok((binary, model))
 }
 
+const DEFAULT_MODEL_CANDIDATES: &[&str] = &[
+    "ggml-large-v3.bin",
+    "ggml-large-v3-q5_0.bin",
+    "ggml-large-v3-turbo.bin",
+    "ggml-large-v3-turbo-q8_0.bin",
+    "ggml-large-v3-turbo-q5_0.bin",
+    "ggml-large-v2.bin",
+    "ggml-large-v2-q5_0.bin",
+    "ggml-medium.bin",
+    "ggml-medium-q5_0.bin",
+    "ggml-small.bin",
+    "ggml-small-q5_1.bin",
+    "ggml-base.bin",
+    "ggml-base-q5_1.bin",
+];
+
+fn resolve_model_file(requested: &Path) -> Option<PathBuf> {
+    if requested.is_file() {
+        return Some(requested.to_path_buf());
+    }
+    if is_model_file(requested) {
+        return None;
+    }
+    let dir = requested.parent()?;
+    let name = requested.file_name()?.to_string_lossy().to_string();
+    let mut names = vec![name.clone()];
+    if !name.starts_with("ggml-") {
+        names.push(format!("ggml-{name}"));
+    }
+    names
+        .iter()
+        .flat_map(|name| [format!("{name}.bin"), format!("{name}.gguf")])
+        .map(|candidate| dir.join(candidate))
+        .find(|candidate| candidate.is_file())
+}
+
+fn find_default_model(model_root: &Path) -> Option<PathBuf> {
+    if let Some(found) = DEFAULT_MODEL_CANDIDATES
+        .iter()
+        .map(|name| model_root.join(name))
+        .find(|candidate| candidate.is_file())
+    {
+        return Some(found);
+    }
+    let mut models: Vec<PathBuf> = std::fs::read_dir(model_root)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && is_model_file(path))
+        .collect();
+    models.sort();
+    models.into_iter().next()
+}
+
 fn resolve_ffmpeg_path(config: &AppConfig) -> Result<PathBuf> {
     let requested = config.whisper.ffmpeg_path.trim();
     if !requested.is_empty() {
@@ -606,7 +1553,7 @@ async fn ensure_whisper_resources(config: &AppConfig) -> Result<(PathBuf, PathBu
 
     if !model_path.exists() {
         return Err(anyhow!(
-            "Whisper model not found at {}. Set WHISPER_MODEL to a local model file.",
+            "Whisper model not found at {}. Set WHISPER_MODEL to a local model file or download the default model.",
             model_path.display()
         ));
     }
@@ -614,36 +1561,23 @@ async fn ensure_whisper_resources(config: &AppConfig) -> Result<(PathBuf, PathBu
     Ok((binary_path, model_path))
 }
 
-async fn download_object(client: &Client, bucket: &str, key: &str, dest: &Path) -> Result<()> {
-    if let Some(parent) = dest.parent() {
-        fs::create_dir_all(parent).await?;
-    }
-    let obj = client
-        .get_object()
-        .bucket(bucket)
-        .key(key)
-        .send()
-        .await
-        .with_context(|| format!("Failed to download {key}"))?;
-    let data = obj
-        .body
-        .collect()
-        .await
-        .with_context(|| "Failed to read object stream")?
-        .into_bytes();
-    fs::write(dest, data)
-        .await
-        .with_context(|| format!("Failed to write file: {}", dest.display()))?;
-    Ok(())
-}
+const DEFAULT_DOWNLOAD_CONCURRENCY: u32 = 3;
+const DEFAULT_CHUNK_OVERLAP_SECS: u32 = 5;
+const WAV_HEADER_BYTES: u64 = 44;
+const WAV_BYTES_PER_SEC: f64 = 32000.0;
+const SILENCE_NOISE_DB: i32 = -45;
+const SILENCE_MIN_SECS: f32 = 0.5;
 
+#[allow(clippy::too_many_arguments)]
 async fn run_whisper_segments(
     binary_path: &Path,
     model_path: &Path,
     input: &Path,
     output_base: &Path,
+    extra_args: &[String],
     jobs_state: &JobState,
     job_id: &str,
+    on_segment: Option<SegmentCallback>,
 ) -> Result<Vec<WhisperSegment>> {
     let output_base_str = output_base.to_string_lossy().to_string();
     let mut child = Command::new(binary_path)
@@ -651,8 +1585,7 @@ async fn run_whisper_segments(
         .arg(model_path)
         .arg("-f")
         .arg(input)
-        .arg("-l")
-        .arg("ja")
+        .args(extra_args)
         .arg("-oj")
         .arg("-otxt")
         .arg("-of")
@@ -680,22 +1613,48 @@ async fn run_whisper_segments(
             if !line.trim().is_empty() {
                 append_log(&stdout_state, &stdout_job, &line);
             }
-        }
+            if let Some(callback) = on_segment.as_ref() {
+                if let Some(segment) = parse_stdout_segment(&line) {
+                    callback(segment);
+                }
+            }
+        }
         Ok::<(), anyhow::Error>(())
     });
     let stderr_task = tokio::spawn(async move {
+        let mut coreml_loaded = false;
         let mut lines = tokio::io::BufReader::new(stderr).lines();
         while let Some(line) = lines.next_line().await? {
+            if line.contains("Core ML model loaded") {
+                coreml_loaded = true;
+            }
             if !line.trim().is_empty() {
                 append_log(&stderr_state, &stderr_job, &line);
             }
+            if let Some(message) = gpu_backend_log(&line) {
+                append_log(&stderr_state, &stderr_job, &message);
+            }
         }
-        Ok::<(), anyhow::Error>(())
+        Ok::<bool, anyhow::Error>(coreml_loaded)
     });
 
     let status = child.wait().await?;
     let _ = stdout_task.await;
-    let _ = stderr_task.await;
+    let coreml_loaded = matches!(stderr_task.await, Ok(Ok(true)));
+    if cfg!(target_os = "macos") {
+        let message = if coreml_loaded {
+            "CoreML encoder: used".to_string()
+        } else {
+            match models::coreml_encoder_path(model_path) {
+                Some(encoder) if encoder.is_dir() => format!(
+                    "CoreML encoder: not used (whisper binary may lack CoreML support; found {})",
+                    encoder.display()
+                ),
+                _ => "CoreML encoder: not used".to_string(),
+            }
+        };
+        append_log(jobs_state, job_id, &message);
+    }
 
     if !status.success() {
         return Err(anyhow!("Whisper command failed"));
@@ -739,7 +1698,11 @@ async fn run_whisper_segments(
             eprintln!("whisper json parse failed; using txt fallback");
             return Ok(vec![WhisperSegment {
                 start: 0.0,
+                end: None,
                 text: cleaned,
+                words: Vec::new(),
+                speaker: None,
+                speaker_turn_next: false,
             }]);
         }
     }
@@ -747,6 +1710,9 @@ async fn run_whisper_segments(
     Err(anyhow!("Failed to parse whisper JSON output"))
 }
 
+const MEETING_ROOM_FILTER: &str = "highpass=f=100,lowpass=f=8000,afftdn=nf=-25";
+const CALL_RECORDING_FILTER: &str = "highpass=f=200,lowpass=f=3400,afftdn=nf=-20";
+
 fn is_wav(path: &Path) -> bool {
     path
         .extension()
@@ -755,18 +1721,232 @@ fn is_wav(path: &Path) -> bool {
         .unwrap_or(false)
 }
 
+async fn wav_duration(config: &AppConfig, input: &Path) -> Option<f64> {
+    let probed = match resolve_ffprobe_path(config) {
+        Some(ffprobe_path) => probe_duration(&ffprobe_path, &input.to_string_lossy()).await,
+        None => None,
+    };
+    probed.or_else(|| {
+        std::fs::metadata(input)
+            .ok()
+            .map(|meta| meta.len().saturating_sub(WAV_HEADER_BYTES) as f64 / WAV_BYTES_PER_SEC)
+    })
+}
+
+async fn concat_speaker_tracks(
+    config: &AppConfig,
+    source: &dyn StorageSource,
+    tracks: Vec<TrackEntry>,
+    job_id: &str,
+    jobs_state: &JobState,
+) -> Result<Vec<TrackEntry>> {
+    let ffmpeg_path = resolve_ffmpeg_path(config)?;
+    let audio_filter = config.whisper.audio_filter();
+    let retry_attempts = s3_retry_attempts(&config.minio);
+    let work_dir = temp_base_dir().join(job_id).join("speakers");
+    fs::create_dir_all(&work_dir).await?;
+    let mut speakers: Vec<(String, Vec<TrackEntry>)> = Vec::new();
+    for track in tracks {
+        match speakers
+            .iter_mut()
+            .find(|(speaker, _)| *speaker == track.speaker)
+        {
+            Some((_, list)) => list.push(track),
+            None => speakers.push((track.speaker.clone(), vec![track])),
+        }
+    }
+
+    let no_progress = |_: u64, _: Option<u64>| {};
+    let mut result = Vec::new();
+    for (speaker_index, (speaker, speaker_tracks)) in speakers.into_iter().enumerate() {
+        append_log(
+            jobs_state,
+            job_id,
+            &format!(
+                "Speaker {speaker}: concatenating {} tracks",
+                speaker_tracks.len()
+            ),
+        );
+        let mut list = String::new();
+        let mut pieces = Vec::new();
+        let mut parts = Vec::new();
+        let mut offset = 0.0;
+        for (track_index, track) in speaker_tracks.iter().enumerate() {
+            let local_file = work_dir.join(format!("speaker_{speaker_index}_{track_index}.src"));
+            with_retry(
+                retry_attempts,
+                "get_object",
+                |line| append_log(jobs_state, job_id, line),
+                || source.get_object(&track.key, &local_file, &no_progress),
+            )
+            .await?;
+            let wav_path = work_dir.join(format!("speaker_{speaker_index}_{track_index}.wav"));
+            convert_to_wav(
+                &local_file,
+                &wav_path,
+                &ffmpeg_path,
+                audio_filter.as_deref(),
+                jobs_state,
+                job_id,
+            )
+            .await?;
+            let _ = fs::remove_file(&local_file).await;
+            let duration = wav_duration(config, &wav_path)
+                .await
+                .ok_or_else(|| anyhow!("Failed to read duration of {}", track.key))?;
+            pieces.push(TrackPiece {
+                key: track.key.clone(),
+                offset,
+                start: track_start_seconds(track),
+            });
+            offset += duration;
+            list.push_str(&format!(
+                "file '{}'\n",
+                wav_path.to_string_lossy().replace('\'', "'\\''")
+            ));
+            parts.push(wav_path);
+        }
+
+        let list_path = work_dir.join(format!("speaker_{speaker_index}.txt"));
+        fs::write(&list_path, list).await?;
+        let output = work_dir.join(format!("speaker_{speaker_index}.wav"));
+        let concat = Command::new(&ffmpeg_path)
+            .arg("-y")
+            .arg("-nostdin")
+            .arg("-f")
+            .arg("concat")
+            .arg("-safe")
+            .arg("0")
+            .arg("-i")
+            .arg(&list_path)
+            .arg("-c")
+            .arg("copy")
+            .arg(&output)
+            .output()
+            .await
+            .with_context(|| format!("Failed to execute ffmpeg: {}", ffmpeg_path.display()))?;
+        if !concat.status.success() {
+            let stderr = String::from_utf8_lossy(&concat.stderr);
+            return Err(anyhow!(
+                "ffmpeg failed to concatenate tracks for {speaker}: {}",
+                stderr.trim()
+            ));
+        }
+        for part in parts {
+            let _ = fs::remove_file(part).await;
+        }
+        result.push(TrackEntry {
+            key: output.to_string_lossy().to_string(),
+            speaker,
+            track_time: speaker_tracks[0].track_time.clone(),
+            etag: None,
+            size: fs::metadata(&output).await.ok().map(|meta| meta.len()),
+            pieces,
+        });
+    }
+    Ok(result)
+}
+
+async fn extract_wav_chunk(
+    ffmpeg_path: &Path,
+    input: &Path,
+    output: &Path,
+    start: f64,
+    length: f64,
+) -> Result<()> {
+    let result = Command::new(ffmpeg_path)
+        .arg("-y")
+        .arg("-nostdin")
+        .arg("-ss")
+        .arg(format!("{start:.3}"))
+        .arg("-t")
+        .arg(format!("{length:.3}"))
+        .arg("-i")
+        .arg(input)
+        .arg("-ar")
+        .arg("16000")
+        .arg("-ac")
+        .arg("1")
+        .arg(output)
+        .output()
+        .await
+        .with_context(|| format!("Failed to execute ffmpeg: {}", ffmpeg_path.display()))?;
+    if !result.status.success() {
+        let stderr = String::from_utf8_lossy(&result.stderr);
+        return Err(anyhow!("ffmpeg failed to split audio: {}", stderr.trim()));
+    }
+    Ok(())
+}
+
+async fn change_tempo(ffmpeg_path: &Path, input: &Path, output: &Path, speed: f64) -> Result<()> {
+    let mut filters = Vec::new();
+    let mut remaining = speed;
+    while remaining > 2.0 {
+        filters.push("atempo=2.0".to_string());
+        remaining /= 2.0;
+    }
+    filters.push(format!("atempo={remaining}"));
+    let result = Command::new(ffmpeg_path)
+        .arg("-y")
+        .arg("-nostdin")
+        .arg("-i")
+        .arg(input)
+        .arg("-af")
+        .arg(filters.join(","))
+        .arg("-ar")
+        .arg("16000")
+        .arg("-ac")
+        .arg("1")
+        .arg(output)
+        .output()
+        .await
+        .with_context(|| format!("Failed to execute ffmpeg: {}", ffmpeg_path.display()))?;
+    if !result.status.success() {
+        let stderr = String::from_utf8_lossy(&result.stderr);
+        return Err(anyhow!("ffmpeg failed to change tempo: {}", stderr.trim()));
+    }
+    Ok(())
+}
+
+fn scale_segment(mut segment: WhisperSegment, factor: f64) -> WhisperSegment {
+    segment.start *= factor;
+    segment.end = segment.end.map(|end| end * factor);
+    for word in &mut segment.words {
+        word.start *= factor;
+        word.end *= factor;
+    }
+    segment
+}
+
+fn shift_segment(mut segment: WhisperSegment, offset: f64) -> WhisperSegment {
+    segment.start += offset;
+    segment.end = segment.end.map(|end| end + offset);
+    for word in &mut segment.words {
+        word.start += offset;
+        word.end += offset;
+    }
+    segment
+}
+
 async fn convert_to_wav(
     input: &Path,
     output: &Path,
     ffmpeg_path: &Path,
+    audio_filter: Option<&str>,
     jobs_state: &JobState,
     job_id: &str,
 ) -> Result<()> {
-    let mut child = Command::new(ffmpeg_path)
+    let mut command = Command::new(ffmpeg_path);
+    command
         .arg("-y")
         .arg("-nostdin")
         .arg("-i")
         .arg(input)
+        .arg("-vn");
+    if let Some(filter) = audio_filter {
+        command.arg("-af").arg(filter);
+    }
+    let mut child = command
         .arg("-ar")
         .arg("16000")
         .arg("-ac")
@@ -864,8 +2044,101 @@ fn segment_from_value(value: &serde_json::Value) -> Option<WhisperSegment> {
     } else {
         0.0
     };
+    let end = if let Some(end) = obj.get("end").and_then(|v| v.as_f64()) {
+        Some(end)
+    } else if let Some(offsets) = obj.get("offsets") {
+        offsets.get("to").and_then(|v| v.as_f64()).map(|to| to / 1000.0)
+    } else if let Some(timestamps) = obj.get("timestamps") {
+        timestamps
+            .get("to")
+            .and_then(|v| v.as_str())
+            .and_then(parse_timestamp_to_seconds)
+    } else {
+        obj.get("t1").and_then(|v| v.as_f64()).map(|t1| t1 / 100.0)
+    };
+    let words = obj
+        .get("words")
+        .and_then(|v| v.as_array())
+        .map(|items| items.iter().filter_map(word_from_value).collect())
+        .unwrap_or_default();
+    let speaker_turn_next = obj
+        .get("speaker_turn_next")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+
+    Some(WhisperSegment {
+        start,
+        end,
+        text,
+        words,
+        speaker: None,
+        speaker_turn_next,
+    })
+}
+
+fn word_from_value(value: &serde_json::Value) -> Option<WordTiming> {
+    let obj = value.as_object()?;
+    let text = obj
+        .get("word")
+        .or_else(|| obj.get("text"))
+        .and_then(|v| v.as_str())?
+        .to_string();
+    let start = obj.get("start").and_then(|v| v.as_f64())?;
+    let end = obj.get("end").and_then(|v| v.as_f64()).unwrap_or(start);
+    Some(WordTiming { start, end, text })
+}
 
-    Some(WhisperSegment { start, text })
+fn group_word_segments(segments: Vec<WhisperSegment>) -> Vec<WhisperSegment> {
+    const MAX_WORD_GAP_SECONDS: f64 = 1.0;
+    let mut grouped: Vec<WhisperSegment> = Vec::new();
+    let mut current: Option<WhisperSegment> = None;
+    for segment in segments {
+        if segment.text.trim().is_empty() {
+            continue;
+        }
+        let word = WordTiming {
+            start: segment.start,
+            end: segment.end.unwrap_or(segment.start),
+            text: segment.text.clone(),
+        };
+        if let Some(active) = current.as_mut() {
+            let gap = segment.start - active.end.unwrap_or(active.start);
+            if gap <= MAX_WORD_GAP_SECONDS {
+                active.text.push_str(&segment.text);
+                active.end = Some(word.end);
+                active.words.push(word);
+                active.speaker_turn_next |= segment.speaker_turn_next;
+            } else {
+                grouped.extend(current.take());
+                current = Some(WhisperSegment {
+                    start: segment.start,
+                    end: Some(word.end),
+                    text: segment.text.clone(),
+                    words: vec![word],
+                    speaker: None,
+                    speaker_turn_next: segment.speaker_turn_next,
+                });
+            }
+        } else {
+            current = Some(WhisperSegment {
+                start: segment.start,
+                end: Some(word.end),
+                text: segment.text.clone(),
+                words: vec![word],
+                speaker: None,
+                speaker_turn_next: segment.speaker_turn_next,
+            });
+        }
+        let ends_sentence = segment
+            .text
+            .trim_end()
+            .ends_with(['。', '？', '！', '.', '?', '!']);
+        if ends_sentence {
+            grouped.extend(current.take());
+        }
+    }
+    grouped.extend(current);
+    grouped
 }
 
 fn parse_json_lines(contents: &str) -> Option<Vec<WhisperSegment>> {
@@ -894,6 +2167,28 @@ fn parse_json_lines(contents: &str) -> Option<Vec<WhisperSegment>> {
     }
 }
 
+fn parse_stdout_segment(line: &str) -> Option<WhisperSegment> {
+    let rest = line.trim().strip_prefix('[')?;
+    let (range, text) = rest.split_once(']')?;
+    let (from, to) = range.split_once("-->")?;
+    let text = text.trim();
+    let (text, speaker_turn_next) = match text.strip_suffix("[SPEAKER_TURN]") {
+        Some(text) => (text.trim_end(), true),
+        None => (text, false),
+    };
+    if text.is_empty() {
+        return None;
+    }
+    Some(WhisperSegment {
+        start: parse_timestamp_to_seconds(from.trim())?,
+        end: parse_timestamp_to_seconds(to.trim()),
+        text: text.to_string(),
+        words: Vec::new(),
+        speaker: None,
+        speaker_turn_next,
+    })
+}
+
 fn parse_timestamp_to_seconds(value: &str) -> Option<f64> {
     let mut parts = value.split(':');
     let hours: f64 = parts.next()?.parse().ok()?;
@@ -957,68 +2252,94 @@ fn format_segments(
     output
 }
 
-#[tauri::command]
-async fn list_dates() -> Result<Vec<String>, String> {
-    let config = effective_config().await.map_err(|err| err.to_string())?;
-    let client = s3_client(&config).await.map_err(|err| err.to_string())?;
-
-    let mut dates = Vec::new();
-    let mut continuation: Option<String> = None;
-    let mut saw_prefixes = false;
-    loop {
-        let mut req = client
-            .list_objects_v2()
-            .bucket(&config.minio.bucket)
-            .delimiter("/");
-        if let Some(token) = &continuation {
-            req = req.continuation_token(token);
-        }
-        let resp = req.send().await.map_err(format_sdk_error)?;
-
-        for prefix in resp.common_prefixes() {
-            saw_prefixes = true;
-            if let Some(value) = prefix.prefix() {
-                let trimmed = value.trim_end_matches('/');
-                if !trimmed.is_empty() {
-                    dates.push(trimmed.to_string());
-                }
-            }
-        }
+fn format_cue_time(value: f64, separator: char) -> String {
+    let total_ms = (value.max(0.0) * 1000.0).round() as u64;
+    let hours = total_ms / 3_600_000;
+    let minutes = (total_ms % 3_600_000) / 60_000;
+    let seconds = (total_ms % 60_000) / 1000;
+    let millis = total_ms % 1000;
+    format!("{hours:02}:{minutes:02}:{seconds:02}{separator}{millis:03}")
+}
 
-        if resp.is_truncated().unwrap_or(false) {
-            continuation = resp.next_continuation_token().map(|s| s.to_string());
-            if continuation.is_none() {
-                break;
-            }
-        } else {
-            break;
+fn format_cues(segments: &[TranscriptionSegment], include_speaker: bool, vtt: bool) -> String {
+    let separator = if vtt { '.' } else { ',' };
+    let mut output = if vtt {
+        "WEBVTT\n\n".to_string()
+    } else {
+        String::new()
+    };
+    for (index, segment) in segments.iter().enumerate() {
+        let end = segment
+            .end
+            .filter(|end| *end > segment.start)
+            .or_else(|| segments.get(index + 1).map(|next| next.start))
+            .filter(|end| *end > segment.start)
+            .unwrap_or(segment.start + 2.0);
+        if !vtt {
+            output.push_str(&format!("{}\n", index + 1));
         }
+        output.push_str(&format!(
+            "{} --> {}\n",
+            format_cue_time(segment.start, separator),
+            format_cue_time(end, separator)
+        ));
+        let text = match (include_speaker, vtt) {
+            (true, true) => format!("<v {}>{}", segment.speaker, segment.text),
+            (true, false) => format!("{}：{}", segment.speaker, segment.text),
+            (false, _) => segment.text.clone(),
+        };
+        output.push_str(&format!("{text}\n\n"));
     }
+    output
+}
 
-    if !saw_prefixes {
-        let mut continuation: Option<String> = None;
-        loop {
-            let mut req = client.list_objects_v2().bucket(&config.minio.bucket);
-            if let Some(token) = &continuation {
-                req = req.continuation_token(token);
-            }
-            let resp = req.send().await.map_err(format_sdk_error)?;
-            for object in resp.contents() {
-                if let Some(key) = object.key() {
-                    if let Some(date) = key.split('/').next() {
-                        if !date.is_empty() {
-                            dates.push(date.to_string());
-                        }
-                    }
-                }
-            }
-            if resp.is_truncated().unwrap_or(false) {
-                continuation = resp.next_continuation_token().map(|s| s.to_string());
-                if continuation.is_none() {
-                    break;
+fn render_transcript(segments: &[TranscriptionSegment], whisper: &WhisperConfig) -> Result<String> {
+    Ok(match whisper.output_extension() {
+        "srt" => format_cues(segments, whisper.include_speaker, false),
+        "vtt" => format_cues(segments, whisper.include_speaker, true),
+        "json" => serde_json::to_string_pretty(segments)?,
+        _ => format_segments(
+            segments,
+            whisper.include_timestamps,
+            whisper.include_speaker,
+        ),
+    })
+}
+
+#[tauri::command]
+async fn list_dates(profile: Option<String>) -> Result<Listing<Vec<String>>, String> {
+    let config = profile_config(profile.as_deref())
+        .await
+        .map_err(|err| err.to_string())?;
+    let source = create_source(&config)
+        .await
+        .map_err(|err| err.to_string())?;
+    cached_dates(
+        &config.minio,
+        source.as_ref(),
+        s3_retry_attempts(&config.minio),
+        false,
+    )
+    .await
+    .map_err(|err| err.to_string())
+}
+
+async fn fetch_dates(source: &dyn StorageSource, retry_attempts: u32) -> Result<Vec<String>> {
+    let mut dates = with_retry(retry_attempts, "list_prefixes", log_retry, || {
+        source.list_prefixes("")
+    })
+    .await?;
+
+    if dates.is_empty() {
+        let keys = with_retry(retry_attempts, "list_objects", log_retry, || {
+            source.list_objects("")
+        })
+        .await?;
+        for key in keys {
+            if let Some(date) = key.split('/').next() {
+                if !date.is_empty() {
+                    dates.push(date.to_string());
                 }
-            } else {
-                break;
             }
         }
     }
@@ -1030,260 +2351,1617 @@ async fn list_dates() -> Result<Vec<String>, String> {
 }
 
 #[tauri::command]
-async fn list_meetings(date: String) -> Result<Vec<MeetingSummary>, String> {
-    let config = effective_config().await.map_err(|err| err.to_string())?;
-    let client = s3_client(&config).await.map_err(|err| err.to_string())?;
+async fn list_meetings(
+    date: String,
+    room: Option<String>,
+    profile: Option<String>,
+) -> Result<Listing<Vec<MeetingSummary>>, String> {
+    let config = profile_config(profile.as_deref())
+        .await
+        .map_err(|err| err.to_string())?;
+    let source = create_source(&config)
+        .await
+        .map_err(|err| err.to_string())?;
+    let mut listing = cached_meetings(
+        &config,
+        source.as_ref(),
+        s3_retry_attempts(&config.minio),
+        &date,
+        false,
+    )
+    .await
+    .map_err(|err| err.to_string())?;
+    filter_by_room(&mut listing.items, room.as_deref());
+    Ok(listing)
+}
+
+fn filter_by_room(meetings: &mut Vec<MeetingSummary>, room: Option<&str>) {
+    let Some(room) = room.map(str::trim).filter(|room| !room.is_empty()) else {
+        return;
+    };
+    let needle = room.to_lowercase();
+    meetings
+        .retain(|meeting| meeting.room_id == room || meeting.room_label.to_lowercase() == needle);
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RefreshedListings {
+    dates: Listing<Vec<String>>,
+    meetings: Option<Listing<Vec<MeetingSummary>>>,
+}
 
+#[tauri::command]
+async fn refresh_listings(
+    date: Option<String>,
+    profile: Option<String>,
+) -> Result<RefreshedListings, String> {
+    let config = profile_config(profile.as_deref())
+        .await
+        .map_err(|err| err.to_string())?;
+    let source = create_source(&config)
+        .await
+        .map_err(|err| err.to_string())?;
+    let retry_attempts = s3_retry_attempts(&config.minio);
+    let dates = cached_dates(&config.minio, source.as_ref(), retry_attempts, true)
+        .await
+        .map_err(|err| err.to_string())?;
+    let meetings = match date.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+        Some(date) => Some(
+            cached_meetings(&config, source.as_ref(), retry_attempts, date, true)
+                .await
+                .map_err(|err| err.to_string())?,
+        ),
+        None => None,
+    };
+    Ok(RefreshedListings { dates, meetings })
+}
+
+async fn fetch_meetings(
+    source: &dyn StorageSource,
+    retry_attempts: u32,
+    date: &str,
+) -> Result<Vec<MeetingSummary>> {
     let prefix = format!("{date}/");
-    let mut meetings: HashMap<String, (String, String, String, HashSet<String>, usize)> =
-        HashMap::new();
+    let objects = with_retry(retry_attempts, "list_objects", log_retry, || {
+        source.list_object_info(&prefix)
+    })
+    .await?;
 
-    let mut continuation: Option<String> = None;
-    loop {
-        let mut req = client
-            .list_objects_v2()
-            .bucket(&config.minio.bucket)
-            .prefix(prefix.clone());
-        if let Some(token) = &continuation {
-            req = req.continuation_token(token);
-        }
-        let resp = req.send().await.map_err(format_sdk_error)?;
-
-        for object in resp.contents() {
-            if let Some(key) = object.key() {
-                if let Some((date, room_id, meeting_time, speaker, _)) = parse_key(key) {
-                    let meeting_id = format!("{}/{}/{}", date, room_id, meeting_time);
-                    let entry = meetings
-                        .entry(meeting_id.clone())
-                        .or_insert((date, room_id, meeting_time, HashSet::new(), 0));
-                    entry.3.insert(speaker);
-                    entry.4 += 1;
+    let mut list = summarize_meetings(objects);
+    list.sort_by(|a, b| compare_time_string(&b.meeting_time, &a.meeting_time));
+    Ok(list)
+}
+
+#[tauri::command]
+async fn list_meetings_range(
+    from: Option<String>,
+    to: Option<String>,
+    days: Option<u32>,
+    room: Option<String>,
+    profile: Option<String>,
+) -> Result<Vec<MeetingSummary>, String> {
+    let (from, to) = match days {
+        Some(days) => {
+            let today = chrono::Local::now().date_naive();
+            let start = today - chrono::Duration::days(i64::from(days.saturating_sub(1)));
+            (Some(start), Some(today))
+        }
+        None => {
+            let parse = |value: Option<String>| -> Result<Option<NaiveDate>, String> {
+                match value.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+                    Some(value) => parse_date_any(value)
+                        .map(Some)
+                        .ok_or_else(|| format!("Invalid date: {value}")),
+                    None => Ok(None),
                 }
-            }
+            };
+            (parse(from)?, parse(to)?)
         }
+    };
+    let config = profile_config(profile.as_deref())
+        .await
+        .map_err(|err| err.to_string())?;
+    let source = create_source(&config)
+        .await
+        .map_err(|err| err.to_string())?;
+    let retry_attempts = s3_retry_attempts(&config.minio);
 
-        if resp.is_truncated().unwrap_or(false) {
-            continuation = resp.next_continuation_token().map(|s| s.to_string());
-            if continuation.is_none() {
-                break;
-            }
-        } else {
-            break;
+    let dates = cached_dates(&config.minio, source.as_ref(), retry_attempts, false)
+        .await
+        .map_err(|err| err.to_string())?
+        .items;
+    let mut list = Vec::new();
+    for date in dates {
+        let Some(parsed) = parse_date_any(&date) else {
+            continue;
+        };
+        if from.is_some_and(|from| parsed < from) || to.is_some_and(|to| parsed > to) {
+            continue;
+        }
+        list.extend(
+            cached_meetings(&config, source.as_ref(), retry_attempts, &date, false)
+                .await
+                .map_err(|err| err.to_string())?
+                .items,
+        );
+    }
+    filter_by_room(&mut list, room.as_deref());
+    list.sort_by(|a, b| {
+        parse_date_any(&b.date)
+            .cmp(&parse_date_any(&a.date))
+            .then_with(|| compare_time_string(&b.meeting_time, &a.meeting_time))
+    });
+    Ok(list)
+}
+
+const ESTIMATED_AUDIO_BYTES_PER_SEC: f64 = 4000.0;
+
+fn estimate_duration_secs(size: u64) -> f64 {
+    size as f64 / ESTIMATED_AUDIO_BYTES_PER_SEC
+}
+
+type MeetingAccumulator = (String, String, String, HashSet<String>, usize, Option<u64>);
+
+fn summarize_meetings(objects: Vec<ObjectInfo>) -> Vec<MeetingSummary> {
+    let mut meetings: HashMap<String, MeetingAccumulator> = HashMap::new();
+    for object in objects {
+        if let Some((date, room_id, meeting_time, speaker, _)) = parse_key(&object.key) {
+            let meeting_id = format!("{}/{}/{}", date, room_id, meeting_time);
+            let entry = meetings.entry(meeting_id.clone()).or_insert((
+                date,
+                room_id,
+                meeting_time,
+                HashSet::new(),
+                0,
+                Some(0),
+            ));
+            entry.3.insert(speaker);
+            entry.4 += 1;
+            entry.5 = entry.5.zip(object.size).map(|(total, size)| total + size);
         }
     }
 
-    let mut list: Vec<MeetingSummary> = meetings
+    meetings
         .into_iter()
         .map(
-            |(id, (date, room_id, meeting_time, speakers, track_count))| {
+            |(id, (date, room_id, meeting_time, speakers, track_count, total_size))| {
                 let room_label = extract_room_label(&room_id);
+                let mut speakers: Vec<String> = speakers.into_iter().collect();
+                speakers.sort();
                 MeetingSummary {
-                id,
-                date,
-                room_id,
-                room_label,
-                meeting_time,
-                speaker_count: speakers.len(),
-                track_count,
+                    id,
+                    date,
+                    room_id,
+                    room_label,
+                    meeting_time,
+                    speaker_count: speakers.len(),
+                    speakers,
+                    track_count,
+                    total_size,
+                    estimated_duration_secs: total_size.map(estimate_duration_secs),
+                    tags: Vec::new(),
+                    favorite: false,
                 }
             },
         )
-        .collect();
+        .collect()
+}
 
-    list.sort_by(|a, b| compare_time_string(&b.meeting_time, &a.meeting_time));
-    Ok(list)
+/// Search hits from the cached listings; `stale` tells the caller to run `refresh_listings`.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct SearchResults {
+    meetings: Vec<MeetingSummary>,
+    stale: bool,
 }
 
 #[tauri::command]
-async fn start_transcribe(meeting_id: String, jobs: State<'_, JobState>) -> Result<String, String> {
-    let config = effective_config().await.map_err(|err| err.to_string())?;
-    let client = s3_client(&config).await.map_err(|err| err.to_string())?;
-
-    let job_id = Uuid::new_v4().to_string();
-    let mut map = jobs.lock().unwrap();
-    map.insert(
-        job_id.clone(),
-        JobStatus {
-            state: "running".to_string(),
-            completed: 0,
-            total: 0,
-            output_path: None,
-            error: None,
-            log: Some(String::new()),
-        },
-    );
-    drop(map);
-
-    let jobs_state = jobs.inner().clone();
-    let config_for_task = config.clone();
-    let client_for_task = client.clone();
-    let job_id_for_task = job_id.clone();
-    let meeting_id_for_task = meeting_id.clone();
-    tokio::spawn(async move {
-        if let Err(err) = run_transcription(
-            &config_for_task,
-            &client_for_task,
-            &meeting_id_for_task,
-            &job_id_for_task,
-            &jobs_state,
-        )
+async fn search_meetings(query: String, profile: Option<String>) -> Result<SearchResults, String> {
+    let needle = query.trim().to_lowercase();
+    if needle.is_empty() {
+        return Ok(SearchResults::default());
+    }
+    let config = profile_config(profile.as_deref())
         .await
-        {
-            let mut map = jobs_state.lock().unwrap();
-            if let Some(status) = map.get_mut(&job_id_for_task) {
-                status.state = "failed".to_string();
-                status.error = Some(err.to_string());
-            }
-        }
+        .map_err(|err| err.to_string())?;
+    let source = create_source(&config)
+        .await
+        .map_err(|err| err.to_string())?;
+    let retry_attempts = s3_retry_attempts(&config.minio);
+    let dates = cached_dates(&config.minio, source.as_ref(), retry_attempts, false)
+        .await
+        .map_err(|err| err.to_string())?;
+
+    let mut results = SearchResults {
+        meetings: Vec::new(),
+        stale: dates.stale,
+    };
+    for date in &dates.items {
+        let meetings = cached_meetings(&config, source.as_ref(), retry_attempts, date, false)
+            .await
+            .map_err(|err| err.to_string())?;
+        results.stale |= meetings.stale;
+        results
+            .meetings
+            .extend(meetings.items.into_iter().filter(|meeting| {
+                meeting
+                    .speakers
+                    .iter()
+                    .any(|speaker| speaker.to_lowercase().contains(&needle))
+            }));
+    }
+    results.meetings.sort_by(|a, b| {
+        parse_date_any(&b.date)
+            .cmp(&parse_date_any(&a.date))
+            .then_with(|| compare_time_string(&b.meeting_time, &a.meeting_time))
     });
+    Ok(results)
+}
 
-    Ok(job_id)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TrackDetail {
+    key: String,
+    speaker: String,
+    track_time: String,
+    size: Option<u64>,
+    duration_secs: Option<f64>,
 }
 
-async fn run_transcription(
-    config: &AppConfig,
-    client: &Client,
-    meeting_id: &str,
-    job_id: &str,
-    jobs_state: &JobState,
-) -> Result<()> {
-    let (binary_path, model_path) = ensure_whisper_resources(config).await?;
-    let ffmpeg_path = resolve_ffmpeg_path(config)?;
-    let prefix = format!("{}/", meeting_id);
-    let mut tracks = Vec::new();
-    let mut continuation: Option<String> = None;
-    loop {
-        let mut req = client
-            .list_objects_v2()
-            .bucket(&config.minio.bucket)
-            .prefix(prefix.clone());
-        if let Some(token) = &continuation {
-            req = req.continuation_token(token);
-        }
-        let resp = req
-            .send()
-            .await
-            .map_err(|err| anyhow!(format_sdk_error(err)))?;
-
-        for object in resp.contents() {
-            if let Some(key) = object.key() {
-                if let Some((_, _, _, speaker, track_time)) = parse_key(key) {
-                    tracks.push(TrackEntry {
-                        key: key.to_string(),
-                        speaker,
-                        track_time: sanitize_time(&track_time),
-                    });
-                }
-            }
-        }
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MeetingDetail {
+    id: String,
+    date: String,
+    room_id: String,
+    room_label: String,
+    meeting_time: String,
+    total_duration_secs: Option<f64>,
+    duration_is_estimate: bool,
+    tracks: Vec<TrackDetail>,
+}
 
-        if resp.is_truncated().unwrap_or(false) {
-            continuation = resp.next_continuation_token().map(|s| s.to_string());
-            if continuation.is_none() {
-                break;
-            }
-        } else {
-            break;
-        }
+fn resolve_ffprobe_path(config: &AppConfig) -> Option<PathBuf> {
+    let binary = if cfg!(target_os = "windows") {
+        "ffprobe.exe"
+    } else {
+        "ffprobe"
+    };
+    resolve_ffmpeg_path(config)
+        .ok()
+        .and_then(|ffmpeg| ffmpeg.parent().map(|dir| dir.join(binary)))
+        .filter(|candidate| candidate.is_file())
+        .or_else(|| find_in_path(binary))
+}
+
+async fn probe_duration(ffprobe_path: &Path, input: &str) -> Option<f64> {
+    let output = Command::new(ffprobe_path)
+        .arg("-v")
+        .arg("error")
+        .arg("-show_entries")
+        .arg("format=duration")
+        .arg("-of")
+        .arg("default=noprint_wrappers=1:nokey=1")
+        .arg(input)
+        .output()
+        .await
+        .ok()?;
+    if !output.status.success() {
+        return None;
     }
+    String::from_utf8_lossy(&output.stdout).trim().parse().ok()
+}
 
+struct MediaInfo {
+    duration: Option<f64>,
+    codec: Option<String>,
+}
+
+async fn probe_media(ffprobe_path: &Path, input: &Path) -> Result<MediaInfo> {
+    let output = Command::new(ffprobe_path)
+        .arg("-v")
+        .arg("error")
+        .arg("-show_entries")
+        .arg("format=duration:stream=codec_type,codec_name")
+        .arg("-of")
+        .arg("json")
+        .arg(input)
+        .output()
+        .await
+        .with_context(|| format!("Failed to execute ffprobe: {}", ffprobe_path.display()))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow!("ffprobe failed: {}", stderr.trim()));
+    }
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let duration = value
+        .pointer("/format/duration")
+        .and_then(|v| v.as_str())
+        .and_then(|v| v.parse().ok());
+    let codec = value
+        .get("streams")
+        .and_then(|v| v.as_array())
+        .and_then(|streams| {
+            streams
+                .iter()
+                .find(|stream| stream.get("codec_type").and_then(|v| v.as_str()) == Some("audio"))
+        })
+        .and_then(|stream| stream.get("codec_name"))
+        .and_then(|v| v.as_str())
+        .map(str::to_string);
+    Ok(MediaInfo { duration, codec })
+}
+
+#[tauri::command]
+async fn get_meeting_detail(
+    meeting_id: String,
+    profile: Option<String>,
+) -> Result<MeetingDetail, String> {
+    let config = profile_config(profile.as_deref())
+        .await
+        .map_err(|err| err.to_string())?;
+    let source = create_source(&config)
+        .await
+        .map_err(|err| err.to_string())?;
+    let retry_attempts = s3_retry_attempts(&config.minio);
+    let meeting_id = meeting_id.trim().trim_matches('/').to_string();
+    let prefix = format!("{meeting_id}/");
+    let objects = with_retry(retry_attempts, "list_objects", log_retry, || {
+        source.list_object_info(&prefix)
+    })
+    .await
+    .map_err(|err| err.to_string())?;
+
+    let ffprobe_path = resolve_ffprobe_path(&config);
+    let mut tracks = Vec::new();
+    for object in objects {
+        let Some((_, _, _, speaker, track_time)) = parse_key(&object.key) else {
+            continue;
+        };
+        let duration_secs = match &ffprobe_path {
+            Some(ffprobe_path) => match source.probe_location(&object.key).await {
+                Ok(Some(location)) => probe_duration(ffprobe_path, &location).await,
+                _ => None,
+            },
+            None => None,
+        };
+        tracks.push(TrackDetail {
+            key: object.key,
+            speaker,
+            track_time: sanitize_time(&track_time),
+            size: object.size,
+            duration_secs,
+        });
+    }
     tracks.sort_by(|a, b| compare_time_string(&a.track_time, &b.track_time));
-    eprintln!(
-        "run_transcription meeting_id={} tracks_found={}",
+    let probed: Option<f64> = tracks.iter().map(|track| track.duration_secs).sum();
+    let duration_is_estimate = probed.is_none();
+    let total_duration_secs = probed.or_else(|| {
+        tracks
+            .iter()
+            .map(|track| track.size)
+            .sum::<Option<u64>>()
+            .map(estimate_duration_secs)
+    });
+
+    let mut parts = meeting_id.splitn(3, '/');
+    let date = parts.next().unwrap_or_default().to_string();
+    let room_id = parts.next().unwrap_or_default().to_string();
+    let meeting_time = parts.next().unwrap_or_default().to_string();
+    Ok(MeetingDetail {
+        room_label: RoomLabeler::new(&config).label(&room_id),
+        id: meeting_id,
+        date,
+        room_id,
+        meeting_time,
+        total_duration_secs,
+        duration_is_estimate,
+        tracks,
+    })
+}
+
+#[tauri::command]
+async fn get_track_presigned_url(
+    key: String,
+    expires_in_secs: Option<u64>,
+    profile: Option<String>,
+) -> Result<String, String> {
+    const DEFAULT_EXPIRES_SECS: u64 = 15 * 60;
+    const MAX_EXPIRES_SECS: u64 = 7 * 24 * 60 * 60;
+    let config = profile_config(profile.as_deref())
+        .await
+        .map_err(|err| err.to_string())?;
+    let source = create_source(&config)
+        .await
+        .map_err(|err| err.to_string())?;
+    let expires_in = expires_in_secs
+        .filter(|secs| *secs > 0)
+        .unwrap_or(DEFAULT_EXPIRES_SECS)
+        .min(MAX_EXPIRES_SECS);
+    source
+        .presigned_url(&key, std::time::Duration::from_secs(expires_in))
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn get_waveform(
+    target: String,
+    resolution_secs: Option<f64>,
+    profile: Option<String>,
+) -> Result<waveform::Waveform, String> {
+    let config = profile_config(profile.as_deref())
+        .await
+        .map_err(|err| err.to_string())?;
+    waveform::build(&config, &target, resolution_secs)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn delete_meeting(
+    meeting_id: String,
+    confirm: bool,
+    profile: Option<String>,
+) -> Result<DeleteResult, String> {
+    if !confirm {
+        return Err("Deleting a meeting requires confirmation".to_string());
+    }
+    let meeting_id = meeting_id.trim().trim_matches('/');
+    if meeting_id
+        .split('/')
+        .filter(|part| !part.is_empty())
+        .count()
+        != 3
+    {
+        return Err(format!("Invalid meeting id: {meeting_id}"));
+    }
+    let config = profile_config(profile.as_deref())
+        .await
+        .map_err(|err| err.to_string())?;
+    let source = create_source(&config)
+        .await
+        .map_err(|err| err.to_string())?;
+    let keys = source
+        .list_objects(&format!("{meeting_id}/"))
+        .await
+        .map_err(|err| err.to_string())?;
+    source
+        .delete_objects(&keys)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn start_transcribe(
+    meeting_id: String,
+    profile: Option<String>,
+    tracks: Option<Vec<String>>,
+    incremental: Option<bool>,
+    options: Option<JobOptions>,
+    jobs: State<'_, JobState>,
+    app: AppHandle,
+) -> Result<String, String> {
+    start_meeting_job(
+        app,
+        &jobs,
         meeting_id,
-        tracks.len()
+        profile.as_deref(),
+        tracks,
+        incremental.unwrap_or(false),
+        options,
+    )
+    .await
+    .map_err(|err| err.to_string())
+}
+
+async fn start_meeting_job(
+    app: AppHandle,
+    jobs: &JobState,
+    meeting_id: String,
+    profile: Option<&str>,
+    tracks: Option<Vec<String>>,
+    incremental: bool,
+    options: Option<JobOptions>,
+) -> Result<String> {
+    let mut config = profile_config(profile).await?;
+    if let Some(options) = options {
+        options.apply(&mut config.whisper);
+    }
+    let source = create_source(&config).await?;
+
+    let job_id = register_job(jobs);
+
+    let jobs_state = jobs.clone();
+    let config_for_task = config.clone();
+    let source_for_task = source.clone();
+    let job_id_for_task = job_id.clone();
+    let meeting_id_for_task = meeting_id.clone();
+    let selected_tracks: Option<HashSet<String>> =
+        tracks.map(|tracks| tracks.into_iter().collect());
+    tokio::spawn(async move {
+        let result = run_transcription(
+            &config_for_task,
+            source_for_task,
+            &meeting_id_for_task,
+            selected_tracks.as_ref(),
+            incremental,
+            &job_id_for_task,
+            &jobs_state,
+            &app,
+        )
+        .await;
+        finish_job(
+            &app,
+            &config_for_task,
+            &jobs_state,
+            &job_id_for_task,
+            &meeting_label(&config_for_task, &meeting_id_for_task),
+            result,
+        )
+        .await;
+    });
+
+    Ok(job_id)
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LocalFileInput {
+    path: String,
+    speaker: Option<String>,
+}
+
+#[tauri::command]
+async fn transcribe_local_files(
+    files: Vec<LocalFileInput>,
+    jobs: State<'_, JobState>,
+    app: AppHandle,
+) -> Result<String, String> {
+    let config = effective_config().await.map_err(|err| err.to_string())?;
+    if files.is_empty() {
+        return Err("No files selected".to_string());
+    }
+    let mut tracks = Vec::new();
+    for file in &files {
+        let path = PathBuf::from(file.path.trim());
+        if !path.is_file() {
+            return Err(format!("File not found: {}", path.display()));
+        }
+        let stem = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        let speaker = file
+            .speaker
+            .as_deref()
+            .map(str::trim)
+            .filter(|label| !label.is_empty())
+            .map(str::to_string)
+            .unwrap_or(stem);
+        tracks.push(TrackEntry {
+            key: path.to_string_lossy().to_string(),
+            speaker,
+            track_time: String::new(),
+            etag: None,
+            size: std::fs::metadata(&path).ok().map(|meta| meta.len()),
+            pieces: Vec::new(),
+        });
+    }
+    let output_root = output_root(&config).map_err(|err| err.to_string())?;
+    let output_name = Path::new(&tracks[0].key)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| "transcript".to_string());
+    let label = output_name.clone();
+    let (output_path, previous_output) = versioned_output_path(
+        &config,
+        &output_root
+            .join(output_name)
+            .with_extension(config.whisper.output_extension()),
+    );
+
+    let job_id = register_job(&jobs);
+    record_output_lineage(&jobs, &job_id, &output_path, previous_output.as_deref());
+
+    let jobs_state = jobs.inner().clone();
+    let job_id_for_task = job_id.clone();
+    tokio::spawn(async move {
+        let result = transcribe_tracks(
+            &config,
+            std::sync::Arc::new(FileSource),
+            tracks,
+            TranscriptManifest::default(),
+            output_path,
+            &job_id_for_task,
+            &jobs_state,
+            &app,
+        )
+        .await;
+        finish_job(&app, &config, &jobs_state, &job_id_for_task, &label, result).await;
+    });
+
+    Ok(job_id)
+}
+
+#[tauri::command]
+async fn transcribe_url(
+    url: String,
+    speaker: Option<String>,
+    jobs: State<'_, JobState>,
+    app: AppHandle,
+) -> Result<String, String> {
+    let config = effective_config().await.map_err(|err| err.to_string())?;
+    let parsed = reqwest::Url::parse(url.trim()).map_err(|err| format!("Invalid URL: {err}"))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(format!("Unsupported URL scheme: {}", parsed.scheme()));
+    }
+    let file_name = parsed
+        .path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .filter(|name| !name.is_empty())
+        .map(|name| percent_decode_str(name).decode_utf8_lossy().to_string())
+        .unwrap_or_else(|| "download".to_string());
+    let stem = Path::new(&file_name)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| "download".to_string());
+    let source = UrlSource::new(&config.proxy_url).map_err(|err| err.to_string())?;
+    let tracks = vec![TrackEntry {
+        etag: None,
+        size: None,
+        pieces: Vec::new(),
+        key: parsed.to_string(),
+        speaker: speaker
+            .as_deref()
+            .map(str::trim)
+            .filter(|label| !label.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| stem.clone()),
+        track_time: String::new(),
+    }];
+    let output_root = output_root(&config).map_err(|err| err.to_string())?;
+    let output_name = stem.replace(['/', '\\'], "_");
+    let label = stem.clone();
+    let (output_path, previous_output) = versioned_output_path(
+        &config,
+        &output_root
+            .join(output_name)
+            .with_extension(config.whisper.output_extension()),
+    );
+
+    let job_id = register_job(&jobs);
+    record_output_lineage(&jobs, &job_id, &output_path, previous_output.as_deref());
+
+    let jobs_state = jobs.inner().clone();
+    let job_id_for_task = job_id.clone();
+    tokio::spawn(async move {
+        let result = transcribe_tracks(
+            &config,
+            std::sync::Arc::new(source),
+            tracks,
+            TranscriptManifest::default(),
+            output_path,
+            &job_id_for_task,
+            &jobs_state,
+            &app,
+        )
+        .await;
+        finish_job(&app, &config, &jobs_state, &job_id_for_task, &label, result).await;
+    });
+
+    Ok(job_id)
+}
+
+fn register_job(jobs: &JobState) -> String {
+    let job_id = Uuid::new_v4().to_string();
+    let mut map = jobs.lock().unwrap();
+    map.insert(
+        job_id.clone(),
+        JobStatus {
+            state: "running".to_string(),
+            completed: 0,
+            total: 0,
+            output_path: None,
+            error: None,
+            log: Some(String::new()),
+        },
     );
+    if let Err(err) = store::record_job_started(&job_id) {
+        eprintln!("Failed to record job {job_id}: {err}");
+    }
+    job_id
+}
 
+fn mark_job_failed(jobs_state: &JobState, job_id: &str, err: &anyhow::Error) {
     {
         let mut map = jobs_state.lock().unwrap();
         if let Some(status) = map.get_mut(job_id) {
-            status.total = tracks.len();
-            status.completed = 0;
+            status.state = "failed".to_string();
+            status.error = Some(err.to_string());
         }
     }
+    if let Err(store_err) =
+        store::record_job_finished(job_id, "failed", None, Some(&err.to_string()))
+    {
+        eprintln!("Failed to record job {job_id}: {store_err}");
+    }
+}
 
-    if tracks.is_empty() {
-        return Err(anyhow!("No tracks found for meeting: {meeting_id}"));
+fn meeting_label(config: &AppConfig, meeting_id: &str) -> String {
+    let mut parts = meeting_id.splitn(3, '/').skip(1);
+    let room = RoomLabeler::new(config).label(parts.next().unwrap_or(meeting_id));
+    match parts
+        .next()
+        .and_then(parse_time_any)
+        .map(|time| time.format("%H:%M").to_string())
+    {
+        Some(time) => format!("{room} {time}"),
+        None => room,
+    }
+}
+
+async fn finish_job(
+    app: &AppHandle,
+    config: &AppConfig,
+    jobs_state: &JobState,
+    job_id: &str,
+    label: &str,
+    result: Result<()>,
+) {
+    if let Err(err) = &result {
+        mark_job_failed(jobs_state, job_id, err);
     }
+    let Some(status) = jobs_state.lock().unwrap().get(job_id).cloned() else {
+        return;
+    };
+    notifications::job_finished(
+        app,
+        config,
+        notifications::JobFinishedEvent {
+            job_id: job_id.to_string(),
+            state: status.state,
+            label: label.to_string(),
+            output_path: status.output_path,
+            error: status.error,
+        },
+        |line| append_log(jobs_state, job_id, line),
+    )
+    .await;
+}
 
-    let output_root = output_root(config)?;
-    let mut parts = meeting_id.splitn(3, '/');
-    let date_part = parts.next().unwrap_or(meeting_id);
-    let room_part = parts.next().unwrap_or("unknown_room");
-    let time_part = parts.next().unwrap_or("unknown_time");
-    let formatted_date = format_date_japanese(date_part)
-        .unwrap_or_else(|| date_part.replace(['/', '\\'], "_"));
-    let safe_date = formatted_date.replace(['/', '\\'], "_");
-    let safe_room = room_part.replace(['/', '\\'], "_");
-    let formatted_time =
-        format_time_japanese(time_part).unwrap_or_else(|| time_part.to_string());
-    let safe_time = formatted_time.replace(['/', '\\'], "_");
-    let output_file = format!("{safe_date}_{safe_room}_{safe_time}");
-    let output_path = output_root.join(output_file).with_extension("txt");
-    if let Some(parent) = output_path.parent() {
-        fs::create_dir_all(parent)
-            .await
-            .with_context(|| format!("Failed to create output dir: {}", parent.display()))?;
+fn mark_job_done(jobs_state: &JobState, job_id: &str, output_path: &Path) {
+    let output_path = output_path.to_string_lossy().to_string();
+    {
+        let mut map = jobs_state.lock().unwrap();
+        if let Some(status) = map.get_mut(job_id) {
+            status.state = "done".to_string();
+            status.completed = status.total;
+            status.output_path = Some(output_path.clone());
+        }
+    }
+    if let Err(err) = store::record_job_finished(job_id, "done", Some(&output_path), None) {
+        eprintln!("Failed to record job {job_id}: {err}");
     }
+}
 
-    let temp_root = std::env::temp_dir().join("whisperdesktop").join(job_id);
-    fs::create_dir_all(&temp_root).await?;
+#[allow(clippy::too_many_arguments)]
+async fn run_transcription(
+    config: &AppConfig,
+    source: std::sync::Arc<dyn StorageSource>,
+    meeting_id: &str,
+    selected_tracks: Option<&HashSet<String>>,
+    incremental: bool,
+    job_id: &str,
+    jobs_state: &JobState,
+    app: &AppHandle,
+) -> Result<()> {
+    if let Err(err) = store::record_job_meeting(job_id, meeting_id) {
+        append_log(jobs_state, job_id, &format!("Failed to record job: {err}"));
+    }
+    let retry_attempts = s3_retry_attempts(&config.minio);
+    let prefix = format!("{meeting_id}/");
+    let objects = with_retry(
+        retry_attempts,
+        "list_objects",
+        |line| append_log(jobs_state, job_id, line),
+        || source.list_object_info(&prefix),
+    )
+    .await?;
+    let mut tracks = Vec::new();
+    for object in objects {
+        if selected_tracks.is_some_and(|selected| !selected.contains(&object.key)) {
+            continue;
+        }
+        if let Some((_, _, _, speaker, track_time)) = parse_key(&object.key) {
+            tracks.push(TrackEntry {
+                key: object.key,
+                speaker,
+                track_time: sanitize_time(&track_time),
+                etag: object.etag,
+                size: object.size,
+                pieces: Vec::new(),
+            });
+        }
+    }
 
-    let mut all_segments: Vec<TranscriptionSegment> = Vec::new();
-    let include_timestamps = config.whisper.include_timestamps;
-    let include_speaker = config.whisper.include_speaker;
+    tracks.sort_by(|a, b| compare_time_string(&a.track_time, &b.track_time));
+    eprintln!(
+        "run_transcription meeting_id={} tracks_found={}",
+        meeting_id,
+        tracks.len()
+    );
 
-    for (index, track) in tracks.iter().enumerate() {
-        let progress_label = format!("Track {}/{}", index + 1, tracks.len());
-        let local_file = temp_root.join(format!("track_{index}.ogg"));
+    if tracks.is_empty() {
+        return Err(anyhow!("No tracks found for meeting: {meeting_id}"));
+    }
+
+    let base_output_path = meeting_output_path(config, meeting_id)?;
+    let (output_path, previous_output) = versioned_output_path(config, &base_output_path);
+    let previous = match previous_output.as_deref() {
+        Some(previous_output) if incremental => load_manifest(previous_output).await,
+        _ => TranscriptManifest::default(),
+    };
+    if !previous.tracks.is_empty() {
+        let done: HashSet<&String> = previous.tracks.iter().collect();
+        tracks.retain(|track| !done.contains(&track.key));
         append_log(
             jobs_state,
             job_id,
-            &format!("{progress_label}: downloading audio"),
+            &format!(
+                "Incremental: {} tracks already transcribed, {} new",
+                done.len(),
+                tracks.len()
+            ),
         );
-        download_object(client, &config.minio.bucket, &track.key, &local_file).await?;
+        if tracks.is_empty() {
+            append_log(jobs_state, job_id, "No new tracks since last transcription");
+            mark_job_done(
+                jobs_state,
+                job_id,
+                previous_output.as_deref().unwrap_or(&output_path),
+            );
+            return Ok(());
+        }
+    }
+    record_output_lineage(jobs_state, job_id, &output_path, previous_output.as_deref());
+    let (track_source, tracks) = if config.whisper.concat_speakers && tracks.len() > 1 {
+        let tracks =
+            concat_speaker_tracks(config, source.as_ref(), tracks, job_id, jobs_state).await?;
+        (
+            std::sync::Arc::new(FileSource) as std::sync::Arc<dyn StorageSource>,
+            tracks,
+        )
+    } else {
+        (source.clone(), tracks)
+    };
+    transcribe_tracks(
+        config,
+        track_source,
+        tracks,
+        previous,
+        output_path.clone(),
+        job_id,
+        jobs_state,
+        app,
+    )
+    .await?;
+
+    if config.minio.upload_transcripts {
+        upload_transcript(
+            config,
+            source.as_ref(),
+            meeting_id,
+            &output_path,
+            jobs_state,
+            job_id,
+        )
+        .await;
+    }
+    Ok(())
+}
 
-        let output_base = temp_root.join(format!("out_{index}"));
-        let input_for_whisper = if is_wav(&local_file) {
-            local_file.clone()
+async fn upload_transcript(
+    config: &AppConfig,
+    source: &dyn StorageSource,
+    meeting_id: &str,
+    output_path: &Path,
+    jobs_state: &JobState,
+    job_id: &str,
+) {
+    let retry_attempts = s3_retry_attempts(&config.minio);
+    let key_prefix = format!("{meeting_id}/{}", config.minio.transcript_upload_dir());
+    let mut uploads = vec![(
+        output_path.to_path_buf(),
+        format!(
+            "{key_prefix}/transcript.{}",
+            config.whisper.output_extension()
+        ),
+    )];
+    for suffix in TRANSCRIPT_UPLOAD_SIDECARS {
+        let path = output_path.with_extension(suffix);
+        if fs::metadata(&path)
+            .await
+            .map(|meta| meta.is_file())
+            .unwrap_or(false)
+        {
+            uploads.push((path, format!("{key_prefix}/transcript.{suffix}")));
+        }
+    }
+    for (path, key) in uploads {
+        match with_retry(
+            retry_attempts,
+            "put_object",
+            |line| append_log(jobs_state, job_id, line),
+            || source.put_object(&key, &path),
+        )
+        .await
+        {
+            Ok(()) => append_log(jobs_state, job_id, &format!("Uploaded transcript: {key}")),
+            Err(err) => append_log(
+                jobs_state,
+                job_id,
+                &format!("Transcript upload failed for {key}: {err}"),
+            ),
+        }
+    }
+}
+
+struct TrackJob {
+    config: AppConfig,
+    source: std::sync::Arc<dyn StorageSource>,
+    tracks: Vec<TrackEntry>,
+    temp_root: PathBuf,
+    ffmpeg_path: Option<PathBuf>,
+    ffprobe_path: Option<PathBuf>,
+    retry_attempts: u32,
+    download_concurrency: usize,
+    convert_slots: std::sync::Arc<tokio::sync::Semaphore>,
+    jobs_state: JobState,
+    job_id: String,
+    app: AppHandle,
+}
+
+enum PreparedInput {
+    Audio(PathBuf),
+    Stereo(PathBuf, PathBuf),
+    Cached(Vec<WhisperSegment>),
+}
+
+type PreparedTrack = tokio::task::JoinHandle<Result<PreparedInput>>;
+
+struct TrackQueue {
+    next: usize,
+    pending: VecDeque<PreparedTrack>,
+    failed: bool,
+}
+
+impl TrackJob {
+    fn cache_key(&self, index: usize) -> Option<String> {
+        self.tracks[index]
+            .etag
+            .as_deref()
+            .map(|etag| transcript_cache::cache_key(&self.config.whisper, etag))
+    }
+
+    fn spawn_prepare(&self, index: usize) -> PreparedTrack {
+        let track = &self.tracks[index];
+        let cache_key = self.cache_key(index);
+        let source = self.source.clone();
+        let key = track.key.clone();
+        let extension = Path::new(key.split(['?', '#']).next().unwrap_or(&key))
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .filter(|ext| ext.chars().all(|c| c.is_ascii_alphanumeric()))
+            .unwrap_or("ogg");
+        let local_file = self.temp_root.join(format!("track_{index}.{extension}"));
+        let progress_state = self.jobs_state.clone();
+        let progress_job = self.job_id.clone();
+        let progress_label = format!("Track {}/{}", index + 1, self.tracks.len());
+        let retry_attempts = self.retry_attempts;
+        let ffmpeg_path = self.ffmpeg_path.clone();
+        let ffprobe_path = self.ffprobe_path.clone();
+        let audio_filter = self.config.whisper.audio_filter();
+        let stereo = self.config.whisper.stereo_speakers;
+        let convert_slots = self.convert_slots.clone();
+        let wav_path = if is_wav(&local_file) {
+            self.temp_root.join(format!("track_{index}.filtered.wav"))
+        } else {
+            self.temp_root.join(format!("track_{index}.wav"))
+        };
+        tokio::spawn(async move {
+            if let Some(cache_key) = &cache_key {
+                if let Some(segments) = transcript_cache::load(cache_key).await {
+                    append_log(
+                        &progress_state,
+                        &progress_job,
+                        &format!("{progress_label}: cached"),
+                    );
+                    return Ok(PreparedInput::Cached(segments));
+                }
+            }
+            append_log(
+                &progress_state,
+                &progress_job,
+                &format!("{progress_label}: downloading audio"),
+            );
+            let on_progress = |downloaded: u64, total: Option<u64>| {
+                let line = match total {
+                    Some(total) => format!(
+                        "{progress_label}: downloaded {:.1}/{:.1} MB",
+                        downloaded as f64 / 1_048_576.0,
+                        total as f64 / 1_048_576.0
+                    ),
+                    None => format!(
+                        "{progress_label}: downloaded {:.1} MB",
+                        downloaded as f64 / 1_048_576.0
+                    ),
+                };
+                append_log(&progress_state, &progress_job, &line);
+            };
+            with_retry(
+                retry_attempts,
+                &format!("{progress_label}: get_object"),
+                |line| append_log(&progress_state, &progress_job, line),
+                || source.get_object(&key, &local_file, &on_progress),
+            )
+            .await?;
+            if let Some(ffprobe_path) = &ffprobe_path {
+                let info = probe_media(ffprobe_path, &local_file)
+                    .await
+                    .map_err(|err| {
+                        anyhow!("{progress_label}: corrupt or zero-length audio: {key} ({err})")
+                    })?;
+                match (info.duration, info.codec) {
+                    (Some(duration), Some(codec)) if duration > 0.0 => append_log(
+                        &progress_state,
+                        &progress_job,
+                        &format!("{progress_label}: {duration:.1}s, codec {codec}"),
+                    ),
+                    _ => {
+                        return Err(anyhow!(
+                            "{progress_label}: corrupt or zero-length audio: {key}"
+                        ))
+                    }
+                }
+            }
+            let Some(ffmpeg_path) =
+                ffmpeg_path.filter(|_| stereo || audio_filter.is_some() || !is_wav(&local_file))
+            else {
+                return Ok::<PreparedInput, anyhow::Error>(PreparedInput::Audio(local_file));
+            };
+            let _permit = convert_slots.acquire().await?;
+            if stereo {
+                append_log(
+                    &progress_state,
+                    &progress_job,
+                    &format!("{progress_label}: splitting stereo channels"),
+                );
+                let left = wav_path.with_extension("FL.wav");
+                let right = wav_path.with_extension("FR.wav");
+                for (channel, channel_path) in [("FL", &left), ("FR", &right)] {
+                    let filter = match audio_filter.as_deref() {
+                        Some(filter) => format!("pan=mono|c0={channel},{filter}"),
+                        None => format!("pan=mono|c0={channel}"),
+                    };
+                    convert_to_wav(
+                        &local_file,
+                        channel_path,
+                        &ffmpeg_path,
+                        Some(&filter),
+                        &progress_state,
+                        &progress_job,
+                    )
+                    .await?;
+                }
+                return Ok(PreparedInput::Stereo(left, right));
+            }
+            append_log(
+                &progress_state,
+                &progress_job,
+                &format!("{progress_label}: converting to wav"),
+            );
+            convert_to_wav(
+                &local_file,
+                &wav_path,
+                &ffmpeg_path,
+                audio_filter.as_deref(),
+                &progress_state,
+                &progress_job,
+            )
+            .await?;
+            Ok(PreparedInput::Audio(wav_path))
+        })
+    }
+
+    async fn next_track(
+        &self,
+        queue: &tokio::sync::Mutex<TrackQueue>,
+    ) -> Option<(usize, PreparedTrack)> {
+        let mut queue = queue.lock().await;
+        if queue.failed || queue.next >= self.tracks.len() {
+            return None;
+        }
+        let index = queue.next;
+        queue.next += 1;
+        let prepared = queue.pending.pop_front()?;
+        let ahead = index + self.download_concurrency;
+        if ahead < self.tracks.len() {
+            let next_prepared = self.spawn_prepare(ahead);
+            queue.pending.push_back(next_prepared);
+        }
+        Some((index, prepared))
+    }
+
+    async fn transcribe_input(
+        &self,
+        backend: &mut dyn TranscriptionBackend,
+        index: usize,
+        input: &Path,
+        output_base: &Path,
+        offset: f64,
+        progress_label: &str,
+    ) -> Result<Vec<WhisperSegment>> {
+        let config = &self.config;
+        let jobs_state = &self.jobs_state;
+        let job_id = self.job_id.as_str();
+        let track = &self.tracks[index];
+        let app = &self.app;
+        let track_start_seconds = parse_time_any(&track.track_time)
+            .map(|t| t.num_seconds_from_midnight() as f64)
+            .unwrap_or(0.0)
+            + offset;
+        let on_segment: Option<SegmentCallback> = if config.whisper.stream_partial_results {
+            let app = app.clone();
+            let job_id = job_id.to_string();
+            let speaker = track.speaker.clone();
+            Some(std::sync::Arc::new(move |segment: WhisperSegment| {
+                let event = PartialSegmentEvent {
+                    job_id: job_id.clone(),
+                    segment: TranscriptionSegment {
+                        start: track_start_seconds + segment.start,
+                        end: segment.end.map(|end| track_start_seconds + end),
+                        speaker: speaker.clone(),
+                        text: segment.text.trim().to_string(),
+                        words: Vec::new(),
+                    },
+                };
+                let _ = app.emit("transcribe://partial", event);
+            }))
+        } else {
+            None
+        };
+        let segments = backend
+            .transcribe(input, output_base, jobs_state, job_id, on_segment)
+            .await?;
+        let segments = if config.whisper.alignment_command.trim().is_empty() {
+            segments
         } else {
             append_log(
                 jobs_state,
                 job_id,
-                &format!("{progress_label}: converting to wav"),
+                &format!("{progress_label}: aligning timestamps"),
             );
-            let wav_path = temp_root.join(format!("track_{index}.wav"));
-            convert_to_wav(&local_file, &wav_path, &ffmpeg_path, jobs_state, job_id).await?;
-            wav_path
+            match align_segments(
+                &config.whisper.alignment_command,
+                input,
+                output_base,
+                &segments,
+                config.whisper.language_code(),
+                jobs_state,
+                job_id,
+            )
+            .await
+            {
+                Ok(aligned) => aligned,
+                Err(err) => {
+                    append_log(
+                        jobs_state,
+                        job_id,
+                        &format!("{progress_label}: alignment skipped: {err}"),
+                    );
+                    segments
+                }
+            }
+        };
+        Ok(segments)
+    }
+
+    async fn speech_ratio(&self, input: &Path) -> Option<f64> {
+        self.config
+            .whisper
+            .min_speech_ratio
+            .filter(|value| *value > 0.0)?;
+        let ffmpeg_path = self.ffmpeg_path.as_deref()?;
+        let duration = wav_duration(&self.config, input)
+            .await
+            .filter(|duration| *duration > 0.0)?;
+        match silence::speech_ratio(ffmpeg_path, input, duration, SILENCE_MIN_SECS.into()).await {
+            Ok(ratio) => Some(ratio),
+            Err(err) => {
+                append_log(
+                    &self.jobs_state,
+                    &self.job_id,
+                    &format!("Speech detection failed: {err}"),
+                );
+                None
+            }
+        }
+    }
+
+    async fn speed_up(&self, index: usize, input: PathBuf, progress_label: &str) -> (PathBuf, f64) {
+        let Some(speed) = self
+            .config
+            .whisper
+            .speed_up
+            .map(f64::from)
+            .filter(|value| *value > 1.0)
+        else {
+            return (input, 1.0);
+        };
+        let Some(ffmpeg_path) = self.ffmpeg_path.as_deref() else {
+            return (input, 1.0);
+        };
+        let output = self.temp_root.join(format!("track_{index}.tempo.wav"));
+        match change_tempo(ffmpeg_path, &input, &output, speed).await {
+            Ok(()) => {
+                append_log(
+                    &self.jobs_state,
+                    &self.job_id,
+                    &format!("{progress_label}: sped up {speed}x"),
+                );
+                (output, speed)
+            }
+            Err(err) => {
+                append_log(
+                    &self.jobs_state,
+                    &self.job_id,
+                    &format!("{progress_label}: speed-up failed: {err}"),
+                );
+                (input, 1.0)
+            }
+        }
+    }
+
+    async fn trim_silence(
+        &self,
+        index: usize,
+        input: PathBuf,
+        progress_label: &str,
+    ) -> (PathBuf, Option<silence::SilenceMap>) {
+        let Some(min_secs) = self
+            .config
+            .whisper
+            .trim_silence_secs
+            .filter(|value| *value > 0)
+        else {
+            return (input, None);
+        };
+        let Some(ffmpeg_path) = self.ffmpeg_path.as_deref() else {
+            return (input, None);
+        };
+        let output = self.temp_root.join(format!("track_{index}.trimmed.wav"));
+        match silence::trim(ffmpeg_path, &input, &output, f64::from(min_secs)).await {
+            Ok(Some(map)) => {
+                append_log(
+                    &self.jobs_state,
+                    &self.job_id,
+                    &format!(
+                        "{progress_label}: trimmed {} silences ({:.1}s)",
+                        map.spans(),
+                        map.removed_secs()
+                    ),
+                );
+                (output, Some(map))
+            }
+            Ok(None) => (input, None),
+            Err(err) => {
+                append_log(
+                    &self.jobs_state,
+                    &self.job_id,
+                    &format!("{progress_label}: silence trimming failed: {err}"),
+                );
+                (input, None)
+            }
+        }
+    }
+
+    async fn plan_chunks(&self, input: &Path) -> Vec<(f64, f64)> {
+        let whisper = &self.config.whisper;
+        let Some(chunk_secs) = whisper
+            .chunk_minutes
+            .filter(|value| *value > 0)
+            .map(|value| f64::from(value) * 60.0)
+        else {
+            return Vec::new();
+        };
+        if self.ffmpeg_path.is_none() {
+            return Vec::new();
+        }
+        let overlap = f64::from(
+            whisper
+                .chunk_overlap_secs
+                .unwrap_or(DEFAULT_CHUNK_OVERLAP_SECS),
+        );
+        let duration = wav_duration(&self.config, input).await;
+        let Some(duration) = duration.filter(|duration| *duration > chunk_secs + overlap) else {
+            return Vec::new();
         };
+        let mut chunks = Vec::new();
+        let mut start = 0.0;
+        while start < duration {
+            chunks.push((start, chunk_secs + overlap));
+            start += chunk_secs;
+        }
+        chunks
+    }
+
+    async fn transcribe_audio(
+        &self,
+        backend: &mut dyn TranscriptionBackend,
+        index: usize,
+        input_for_whisper: PathBuf,
+    ) -> Result<Vec<WhisperSegment>> {
+        let progress_label = format!("Track {}/{}", index + 1, self.tracks.len());
+        let mut segments = self
+            .transcribe_track(backend, index, input_for_whisper.clone(), &progress_label)
+            .await?;
+        if self.tracks.len() == 1 {
+            self.diarize(&input_for_whisper, index, &mut segments, &progress_label)
+                .await;
+        }
+        self.store_cache(index, &segments, &progress_label).await;
+        Ok(segments)
+    }
+
+    async fn transcribe_stereo(
+        &self,
+        backend: &mut dyn TranscriptionBackend,
+        index: usize,
+        left: PathBuf,
+        right: PathBuf,
+    ) -> Result<Vec<WhisperSegment>> {
+        let progress_label = format!("Track {}/{}", index + 1, self.tracks.len());
+        let names = &self.config.whisper.stereo_speaker_names;
+        let mut segments = Vec::new();
+        for (channel, input, default_name) in [(0, left, "Speaker L"), (1, right, "Speaker R")] {
+            let name = names
+                .get(channel)
+                .map(|name| name.trim())
+                .filter(|name| !name.is_empty())
+                .unwrap_or(default_name);
+            let mut channel_segments = self
+                .transcribe_track(backend, index, input, &format!("{progress_label} {name}"))
+                .await?;
+            for segment in channel_segments.iter_mut() {
+                segment.speaker = Some(name.to_string());
+            }
+            segments.extend(channel_segments);
+        }
+        segments.sort_by(|a, b| {
+            a.start
+                .partial_cmp(&b.start)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        self.store_cache(index, &segments, &progress_label).await;
+        Ok(segments)
+    }
+
+    async fn store_cache(&self, index: usize, segments: &[WhisperSegment], progress_label: &str) {
+        if let Some(cache_key) = self.cache_key(index) {
+            if let Err(err) = transcript_cache::store(&cache_key, segments).await {
+                append_log(
+                    &self.jobs_state,
+                    &self.job_id,
+                    &format!("{progress_label}: failed to cache transcript: {err}"),
+                );
+            }
+        }
+    }
+
+    async fn transcribe_track(
+        &self,
+        backend: &mut dyn TranscriptionBackend,
+        index: usize,
+        input_for_whisper: PathBuf,
+        progress_label: &str,
+    ) -> Result<Vec<WhisperSegment>> {
+        let jobs_state = &self.jobs_state;
+        let job_id = self.job_id.as_str();
+        let temp_root = &self.temp_root;
+        if let Some(ratio) = self.speech_ratio(&input_for_whisper).await {
+            let threshold = self.config.whisper.min_speech_ratio.unwrap_or_default();
+            if ratio < f64::from(threshold) {
+                append_log(
+                    jobs_state,
+                    job_id,
+                    &format!(
+                        "{progress_label}: track skipped: no speech ({:.1}% speech)",
+                        ratio * 100.0
+                    ),
+                );
+                return Ok(Vec::new());
+            }
+        }
+        let (input_for_whisper, silence_map) = self
+            .trim_silence(index, input_for_whisper, progress_label)
+            .await;
+        let (input_for_whisper, speed) = self
+            .speed_up(index, input_for_whisper, progress_label)
+            .await;
         append_log(
             jobs_state,
             job_id,
             &format!("{progress_label}: transcribing"),
         );
-        let segments = run_whisper_segments(
-            &binary_path,
-            &model_path,
-            &input_for_whisper,
-            &output_base,
-            jobs_state,
-            job_id,
-        )
-        .await?;
-        let track_start_seconds = parse_time_any(&track.track_time)
-            .map(|t| t.num_seconds_from_midnight() as f64)
-            .unwrap_or(0.0);
+        let chunks = self.plan_chunks(&input_for_whisper).await;
+        let segments = if chunks.len() > 1 {
+            append_log(
+                jobs_state,
+                job_id,
+                &format!("{progress_label}: splitting into {} chunks", chunks.len()),
+            );
+            let ffmpeg_path = self
+                .ffmpeg_path
+                .as_deref()
+                .ok_or_else(|| anyhow!("ffmpeg is required for chunking"))?;
+            let half_overlap = f64::from(
+                self.config
+                    .whisper
+                    .chunk_overlap_secs
+                    .unwrap_or(DEFAULT_CHUNK_OVERLAP_SECS),
+            ) / 2.0;
+            let mut segments = Vec::new();
+            for (chunk_index, (start, length)) in chunks.iter().enumerate() {
+                let chunk_label = format!(
+                    "{progress_label} chunk {}/{}",
+                    chunk_index + 1,
+                    chunks.len()
+                );
+                let chunk_path = temp_root.join(format!("track_{index}_chunk_{chunk_index}.wav"));
+                extract_wav_chunk(
+                    ffmpeg_path,
+                    &input_for_whisper,
+                    &chunk_path,
+                    *start,
+                    *length,
+                )
+                .await?;
+                let chunk_segments = self
+                    .transcribe_input(
+                        backend,
+                        index,
+                        &chunk_path,
+                        &temp_root.join(format!("out_{index}_{chunk_index}")),
+                        *start,
+                        &chunk_label,
+                    )
+                    .await?;
+                let keep_from = if chunk_index == 0 {
+                    f64::MIN
+                } else {
+                    start + half_overlap
+                };
+                let keep_until = chunks
+                    .get(chunk_index + 1)
+                    .map(|(next, _)| next + half_overlap)
+                    .unwrap_or(f64::MAX);
+                segments.extend(
+                    chunk_segments
+                        .into_iter()
+                        .map(|segment| shift_segment(segment, *start))
+                        .filter(|segment| segment.start >= keep_from && segment.start < keep_until),
+                );
+            }
+            segments
+        } else {
+            self.transcribe_input(
+                backend,
+                index,
+                &input_for_whisper,
+                &temp_root.join(format!("out_{index}")),
+                0.0,
+                progress_label,
+            )
+            .await?
+        };
+        let mut segments: Vec<WhisperSegment> = segments
+            .into_iter()
+            .map(|segment| scale_segment(segment, speed))
+            .collect();
+        if let Some(map) = &silence_map {
+            map.restore(&mut segments);
+        }
+        Ok(segments)
+    }
+
+    async fn diarize(
+        &self,
+        input: &Path,
+        index: usize,
+        segments: &mut [WhisperSegment],
+        progress_label: &str,
+    ) {
+        let whisper = &self.config.whisper;
+        let result = match whisper.diarization.trim() {
+            "tinydiarize" => {
+                diarize::label_turns(segments);
+                Ok(())
+            }
+            "external" => {
+                diarize::diarize_external(
+                    &whisper.diarization_command,
+                    input,
+                    &self.temp_root.join(format!("speakers_{index}")),
+                    segments,
+                    &self.jobs_state,
+                    &self.job_id,
+                )
+                .await
+            }
+            _ => return,
+        };
+        let message = match result {
+            Ok(()) => {
+                let mut speakers: Vec<&str> = segments
+                    .iter()
+                    .filter_map(|segment| segment.speaker.as_deref())
+                    .collect();
+                speakers.sort_unstable();
+                speakers.dedup();
+                format!("{progress_label}: diarized {} speakers", speakers.len())
+            }
+            Err(err) => format!("{progress_label}: diarization failed: {err}"),
+        };
+        append_log(&self.jobs_state, &self.job_id, &message);
+    }
+
+    fn track_segments(
+        &self,
+        index: usize,
+        segments: Vec<WhisperSegment>,
+    ) -> Vec<TranscriptionSegment> {
+        let track = &self.tracks[index];
+        let single_track = self.tracks.len() == 1;
+        let track_start_seconds = track_start_seconds(track);
+        let to_absolute = |seconds: f64| match track
+            .pieces
+            .iter()
+            .rev()
+            .find(|piece| piece.offset <= seconds)
+            .or(track.pieces.first())
+        {
+            Some(piece) => piece.start + seconds - piece.offset,
+            None => track_start_seconds + seconds,
+        };
         let mut track_segments: Vec<TranscriptionSegment> = Vec::new();
         for segment in segments {
             let cleaned = segment.text.trim();
             if cleaned.is_empty() {
                 continue;
             }
-            let start_abs = track_start_seconds + segment.start;
+            let start_abs = to_absolute(segment.start);
+            let words = segment
+                .words
+                .iter()
+                .map(|word| WordTiming {
+                    start: to_absolute(word.start),
+                    end: to_absolute(word.end),
+                    text: word.text.trim().to_string(),
+                })
+                .collect();
             track_segments.push(TranscriptionSegment {
                 start: start_abs,
-                speaker: track.speaker.clone(),
+                end: segment.end.map(to_absolute),
+                speaker: match segment.speaker.as_deref() {
+                    Some(label) if single_track || self.config.whisper.stereo_speakers => {
+                        label.to_string()
+                    }
+                    _ => track.speaker.clone(),
+                },
                 text: cleaned.to_string(),
+                words,
             });
         }
 
@@ -1292,32 +3970,371 @@ async fn run_transcription(
                 .partial_cmp(&b.start)
                 .unwrap_or(std::cmp::Ordering::Equal)
         });
-        all_segments.extend(track_segments.iter().cloned());
+        track_segments
+    }
+}
+
+async fn run_track_worker(
+    job: std::sync::Arc<TrackJob>,
+    queue: std::sync::Arc<tokio::sync::Mutex<TrackQueue>>,
+    mut backend: Box<dyn TranscriptionBackend>,
+) -> Result<Vec<(usize, Vec<TranscriptionSegment>)>> {
+    let mut results = Vec::new();
+    let mut outcome = Ok(());
+    while let Some((index, prepared)) = job.next_track(&queue).await {
+        let track_result = match prepared.await {
+            Ok(Ok(PreparedInput::Cached(segments))) => Ok(job.track_segments(index, segments)),
+            Ok(Ok(PreparedInput::Audio(input))) => job
+                .transcribe_audio(backend.as_mut(), index, input)
+                .await
+                .map(|segments| job.track_segments(index, segments)),
+            Ok(Ok(PreparedInput::Stereo(left, right))) => job
+                .transcribe_stereo(backend.as_mut(), index, left, right)
+                .await
+                .map(|segments| job.track_segments(index, segments)),
+            Ok(Err(err)) => Err(err),
+            Err(err) => Err(err.into()),
+        };
+        match track_result {
+            Ok(segments) => {
+                results.push((index, segments));
+                let mut map = job.jobs_state.lock().unwrap();
+                if let Some(status) = map.get_mut(&job.job_id) {
+                    status.completed += 1;
+                }
+            }
+            Err(err) => {
+                queue.lock().await.failed = true;
+                outcome = Err(err);
+                break;
+            }
+        }
+    }
+    backend.shutdown().await;
+    outcome.map(|_| results)
+}
+
+async fn write_outputs(
+    config: &AppConfig,
+    all_segments: &[TranscriptionSegment],
+    output_path: &Path,
+    jobs_state: &JobState,
+    job_id: &str,
+) -> Result<()> {
+    let mut segments = all_segments.to_vec();
+    postprocess::apply(config, &mut segments, |line| {
+        append_log(jobs_state, job_id, line)
+    });
+    let mut output = render_transcript(&segments, &config.whisper)?;
+    let header = config
+        .whisper
+        .metadata_header
+        .then(|| header::render(config, output_path, &segments));
+    if let Some(header) = &header {
+        output = header::prepend(header, output, config.whisper.output_extension());
+    }
+    match config.whisper.action_items.trim() {
+        "" | "off" => {}
+        mode => {
+            let items =
+                action_items::extract(&config.whisper, &config.proxy_url, &segments, |line| {
+                    append_log(jobs_state, job_id, line)
+                })
+                .await;
+            append_log(
+                jobs_state,
+                job_id,
+                &format!("Action items found: {}", items.len()),
+            );
+            let section = action_items::render(&items);
+            if mode == "append" && config.whisper.output_extension() == "txt" {
+                output.push_str(&section);
+            } else {
+                let actions_path = output_path.with_extension("actions.txt");
+                fs::write(&actions_path, section.trim_start())
+                    .await
+                    .with_context(|| {
+                        format!("Failed to write output: {}", actions_path.display())
+                    })?;
+            }
+        }
+    }
+
+    match config.whisper.keywords.trim() {
+        "" | "off" => {}
+        mode => {
+            let report = keywords::extract(&segments);
+            if mode == "append" && config.whisper.output_extension() == "txt" {
+                output.push_str(&keywords::render(&report));
+            } else {
+                let keywords_path = output_path.with_extension("keywords.json");
+                fs::write(&keywords_path, serde_json::to_string_pretty(&report)?)
+                    .await
+                    .with_context(|| {
+                        format!("Failed to write output: {}", keywords_path.display())
+                    })?;
+            }
+        }
+    }
+
+    fs::write(output_path, output)
+        .await
+        .with_context(|| format!("Failed to write output: {}", output_path.display()))?;
+    if let Err(err) = search_index::index_transcript(output_path, &segments).await {
+        append_log(
+            jobs_state,
+            job_id,
+            &format!("Failed to update search index: {err}"),
+        );
+    }
+    if config.whisper.word_timestamps {
+        let words_path = output_path.with_extension("words.json");
+        let payload = serde_json::to_string_pretty(&segments)?;
+        fs::write(&words_path, payload)
+            .await
+            .with_context(|| format!("Failed to write output: {}", words_path.display()))?;
+    }
+    if config.whisper.speaker_timeline {
+        let timeline_path = output_path.with_extension("timeline.json");
+        let payload = serde_json::to_string_pretty(&timeline::build(&segments))?;
+        fs::write(&timeline_path, payload)
+            .await
+            .with_context(|| format!("Failed to write output: {}", timeline_path.display()))?;
+    }
+    if let Some(target) = translate::target_language(&config.whisper) {
+        append_log(
+            jobs_state,
+            job_id,
+            &format!("Translating transcript to {target}"),
+        );
+        let translated =
+            translate::translate_segments(&config.whisper, &config.proxy_url, target, &segments)
+                .await
+                .and_then(|translated| render_transcript(&translated, &config.whisper))
+                .map(|output| match &header {
+                    Some(header) => {
+                        header::prepend(header, output, config.whisper.output_extension())
+                    }
+                    None => output,
+                });
+        let translated_path =
+            output_path.with_extension(format!("{target}.{}", config.whisper.output_extension()));
+        match translated {
+            Ok(output) => fs::write(&translated_path, output).await.with_context(|| {
+                format!("Failed to write output: {}", translated_path.display())
+            })?,
+            Err(err) => append_log(jobs_state, job_id, &format!("Translation failed: {err}")),
+        }
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn transcribe_tracks(
+    config: &AppConfig,
+    source: std::sync::Arc<dyn StorageSource>,
+    tracks: Vec<TrackEntry>,
+    previous: TranscriptManifest,
+    output_path: PathBuf,
+    job_id: &str,
+    jobs_state: &JobState,
+    app: &AppHandle,
+) -> Result<()> {
+    let workers = config
+        .whisper
+        .parallel_tracks
+        .filter(|value| *value > 1)
+        .map(|value| value as usize)
+        .unwrap_or(1)
+        .min(tracks.len())
+        .max(1);
+    let mut worker_config = config.clone();
+    if workers > 1 {
+        let total_threads = config
+            .whisper
+            .threads
+            .filter(|value| *value > 0)
+            .map(|value| value as usize)
+            .or_else(|| {
+                std::thread::available_parallelism()
+                    .ok()
+                    .map(|value| value.get())
+            })
+            .unwrap_or(workers);
+        worker_config.whisper.threads = Some((total_threads / workers).max(1) as u32);
+    }
+    let mut backends = Vec::with_capacity(workers);
+    for _ in 0..workers {
+        match create_backend(&worker_config, jobs_state, job_id, tracks.len() == 1).await {
+            Ok(backend) => backends.push(backend),
+            Err(err) => {
+                for mut backend in backends {
+                    backend.shutdown().await;
+                }
+                return Err(err);
+            }
+        }
+    }
+    let has_video = tracks.iter().any(|track| is_video(Path::new(&track.key)));
+    let ffmpeg_path = if backends[0].accepts_original_audio() && !has_video {
+        None
+    } else {
+        Some(resolve_ffmpeg_path(config)?)
+    };
+    let retry_attempts = s3_retry_attempts(&config.minio);
+    {
         let mut map = jobs_state.lock().unwrap();
         if let Some(status) = map.get_mut(job_id) {
-            status.completed = index + 1;
+            status.total = tracks.len();
+            status.completed = 0;
+        }
+    }
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent)
+            .await
+            .with_context(|| format!("Failed to create output dir: {}", parent.display()))?;
+    }
+
+    let temp_root = temp_base_dir().join(job_id);
+    fs::create_dir_all(&temp_root).await?;
+    if let Err(err) = preflight_disk_space(&tracks, ffmpeg_path.is_some(), &temp_root, &output_path)
+    {
+        for mut backend in backends {
+            backend.shutdown().await;
+        }
+        let _ = fs::remove_dir_all(&temp_root).await;
+        return Err(err);
+    }
+
+    append_log(
+        jobs_state,
+        job_id,
+        &format!("Transcription backend: {}", backends[0].name()),
+    );
+    if workers > 1 {
+        append_log(
+            jobs_state,
+            job_id,
+            &format!(
+                "Parallel tracks: {workers} workers, {} threads each",
+                worker_config.whisper.threads.unwrap_or_default()
+            ),
+        );
+    }
+    append_log(
+        jobs_state,
+        job_id,
+        &format!("Storage source: {}", source.name()),
+    );
+
+    let download_concurrency = config
+        .minio
+        .download_concurrency
+        .filter(|value| *value > 0)
+        .unwrap_or(DEFAULT_DOWNLOAD_CONCURRENCY) as usize;
+    let job = std::sync::Arc::new(TrackJob {
+        config: worker_config,
+        source,
+        tracks,
+        temp_root,
+        ffmpeg_path,
+        ffprobe_path: resolve_ffprobe_path(config),
+        retry_attempts,
+        download_concurrency: download_concurrency.max(workers + 1),
+        convert_slots: std::sync::Arc::new(tokio::sync::Semaphore::new(workers)),
+        jobs_state: jobs_state.clone(),
+        job_id: job_id.to_string(),
+        app: app.clone(),
+    });
+    let pending = (0..job.tracks.len().min(job.download_concurrency))
+        .map(|index| job.spawn_prepare(index))
+        .collect();
+    let queue = std::sync::Arc::new(tokio::sync::Mutex::new(TrackQueue {
+        next: 0,
+        pending,
+        failed: false,
+    }));
+    let mut worker_set = tokio::task::JoinSet::new();
+    for backend in backends {
+        worker_set.spawn(run_track_worker(job.clone(), queue.clone(), backend));
+    }
+    let mut track_results = Vec::new();
+    let mut first_error = None;
+    while let Some(result) = worker_set.join_next().await {
+        match result
+            .map_err(anyhow::Error::from)
+            .and_then(|result| result)
+        {
+            Ok(results) => track_results.extend(results),
+            Err(err) => {
+                first_error.get_or_insert(err);
+            }
+        }
+    }
+    for prepared in queue.lock().await.pending.drain(..) {
+        prepared.abort();
+    }
+    if !config.whisper.keep_temp_files {
+        if let Err(err) = fs::remove_dir_all(&job.temp_root).await {
+            append_log(
+                jobs_state,
+                job_id,
+                &format!("Failed to remove temp files: {err}"),
+            );
         }
     }
+    if let Some(err) = first_error {
+        return Err(err);
+    }
+    track_results.sort_by_key(|(index, _)| *index);
+    let track_records: Vec<(String, usize)> = track_results
+        .iter()
+        .map(|(index, segments)| (job.tracks[*index].key.clone(), segments.len()))
+        .collect();
+    if let Err(err) = store::record_tracks(job_id, &track_records) {
+        append_log(
+            jobs_state,
+            job_id,
+            &format!("Failed to record tracks: {err}"),
+        );
+    }
+    let mut all_segments = previous.segments;
+    all_segments.extend(track_results.into_iter().flat_map(|(_, segments)| segments));
 
     all_segments.sort_by(|a, b| {
         a.start
             .partial_cmp(&b.start)
             .unwrap_or(std::cmp::Ordering::Equal)
     });
-    let output = format_segments(&all_segments, include_timestamps, include_speaker);
-
-    fs::write(&output_path, output)
-        .await
-        .with_context(|| format!("Failed to write output: {}", output_path.display()))?;
+    write_outputs(config, &all_segments, &output_path, jobs_state, job_id).await?;
+    let mut manifest_tracks = previous.tracks;
+    for track in &job.tracks {
+        if track.pieces.is_empty() {
+            manifest_tracks.push(track.key.clone());
+        } else {
+            manifest_tracks.extend(track.pieces.iter().map(|piece| piece.key.clone()));
+        }
+    }
+    let manifest = TranscriptManifest {
+        tracks: manifest_tracks,
+        segments: all_segments,
+    };
+    if let Err(err) = fs::write(
+        manifest_path(&output_path),
+        serde_json::to_string(&manifest)?,
+    )
+    .await
+    {
+        append_log(
+            jobs_state,
+            job_id,
+            &format!("Failed to write transcript manifest: {err}"),
+        );
+    }
 
     append_log(jobs_state, job_id, "");
     append_log(jobs_state, job_id, "Done");
-    let mut map = jobs_state.lock().unwrap();
-    if let Some(status) = map.get_mut(job_id) {
-        status.state = "done".to_string();
-        status.completed = status.total;
-        status.output_path = Some(output_path.to_string_lossy().to_string());
-    }
+    mark_job_done(jobs_state, job_id, &output_path);
 
     Ok(())
 }
@@ -1327,24 +4344,218 @@ async fn get_transcribe_status(
     job_id: String,
     jobs: State<'_, JobState>,
 ) -> Result<JobStatus, String> {
-    let map = jobs.lock().unwrap();
-    map.get(&job_id)
-        .cloned()
-        .ok_or_else(|| "Job not found".to_string())
+    job_status(&jobs, job_id)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+async fn job_status(jobs: &JobState, job_id: String) -> Result<JobStatus> {
+    if let Some(status) = jobs.lock().unwrap().get(&job_id).cloned() {
+        return Ok(status);
+    }
+    let record = tokio::task::spawn_blocking(move || store::job(&job_id))
+        .await??
+        .ok_or_else(|| anyhow!("Job not found"))?;
+    Ok(JobStatus {
+        state: record.state,
+        completed: 0,
+        total: 0,
+        output_path: record.output_path,
+        error: record.error,
+        log: None,
+    })
 }
 
 #[tauri::command]
 async fn get_config() -> Result<AppConfig, String> {
-    load_saved_config().await.map_err(|err| err.to_string())
+    editable_config().await.map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn set_config(mut config: AppConfig) -> Result<(), String> {
+    config.store_active_profile();
+    save_config_file(&config)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn export_config(path: String, include_secrets: Option<bool>) -> Result<(), String> {
+    let path = PathBuf::from(path.trim());
+    let mut config = load_saved_config().await.map_err(|err| err.to_string())?;
+    config.store_active_profile();
+    if !include_secrets.unwrap_or(false) {
+        config.strip_secrets();
+    }
+    let payload = serde_json::to_string_pretty(&config).map_err(|err| err.to_string())?;
+    fs::write(&path, payload)
+        .await
+        .map_err(|err| format!("Failed to write {}: {err}", path.display()))
+}
+
+#[tauri::command]
+async fn import_config(path: String) -> Result<AppConfig, String> {
+    let path = PathBuf::from(path.trim());
+    let contents = fs::read_to_string(&path)
+        .await
+        .map_err(|err| format!("Failed to read {}: {err}", path.display()))?;
+    let mut config: AppConfig = serde_json::from_str(contents.trim())
+        .map_err(|err| format!("Invalid config file {}: {err}", path.display()))?;
+    let current = load_saved_config().await.map_err(|err| err.to_string())?;
+    config.keep_secrets_from(&current);
+    save_config_file(&config)
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(config)
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConfigReset {
+    config: AppConfig,
+    backup_path: Option<String>,
+}
+
+async fn backup_config_file() -> Result<Option<PathBuf>> {
+    let path = config_path()?;
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let backup = path.with_file_name(format!("config.backup-{stamp}.json"));
+    fs::copy(&path, &backup)
+        .await
+        .with_context(|| format!("Failed to back up config to {}", backup.display()))?;
+    Ok(Some(backup))
+}
+
+async fn reset_config_with(
+    reset: impl FnOnce(&mut AppConfig) -> Result<()>,
+) -> Result<ConfigReset> {
+    let mut config = load_saved_config().await?;
+    reset(&mut config)?;
+    let backup_path = backup_config_file().await?;
+    save_config_file(&config).await?;
+    Ok(ConfigReset {
+        config: editable_config().await?,
+        backup_path: backup_path.map(|path| path.to_string_lossy().to_string()),
+    })
+}
+
+#[tauri::command]
+async fn reset_config() -> Result<ConfigReset, String> {
+    reset_config_with(|config| {
+        *config = AppConfig::default();
+        Ok(())
+    })
+    .await
+    .map_err(|err| err.to_string())
 }
 
 #[tauri::command]
-async fn set_config(config: AppConfig) -> Result<(), String> {
+async fn reset_section(section: String) -> Result<ConfigReset, String> {
+    reset_config_with(|config| {
+        match section.trim() {
+            "whisper" => config.whisper = WhisperConfig::default(),
+            "minio" => {
+                config.minio = MinioConfig::default();
+                config.store_active_profile();
+            }
+            other => return Err(anyhow!("Unknown config section: {other}")),
+        }
+        Ok(())
+    })
+    .await
+    .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn list_storage_profiles() -> Result<Vec<StorageProfile>, String> {
+    let config = load_saved_config().await.map_err(|err| err.to_string())?;
+    Ok(config.storage_profiles)
+}
+
+#[tauri::command]
+async fn add_storage_profile(name: String, minio: MinioConfig) -> Result<(), String> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("Profile name is required".to_string());
+    }
+    let mut config = load_saved_config().await.map_err(|err| err.to_string())?;
+    match config
+        .storage_profiles
+        .iter_mut()
+        .find(|profile| profile.name == name)
+    {
+        Some(profile) => profile.minio = minio,
+        None => config.storage_profiles.push(StorageProfile { name, minio }),
+    }
+    save_config_file(&config)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn remove_storage_profile(name: String) -> Result<(), String> {
+    let mut config = load_saved_config().await.map_err(|err| err.to_string())?;
+    let before = config.storage_profiles.len();
+    config
+        .storage_profiles
+        .retain(|profile| profile.name != name);
+    if config.storage_profiles.len() == before {
+        return Err(format!("Storage profile not found: {name}"));
+    }
+    if config.active_profile == name {
+        config.active_profile = String::new();
+    }
+    save_config_file(&config)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn select_storage_profile(name: String) -> Result<(), String> {
+    let config = load_saved_config().await.map_err(|err| err.to_string())?;
+    let config = config
+        .with_profile(Some(&name))
+        .map_err(|err| err.to_string())?;
     save_config_file(&config)
         .await
         .map_err(|err| err.to_string())
 }
 
+#[tauri::command]
+async fn start_gdrive_auth(profile: Option<String>) -> Result<gdrive::DeviceCode, String> {
+    let config = profile_config(profile.as_deref())
+        .await
+        .map_err(|err| err.to_string())?;
+    gdrive::start_device_auth(&config)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn complete_gdrive_auth(
+    device_code: String,
+    interval: Option<u64>,
+    profile: Option<String>,
+) -> Result<(), String> {
+    let config = profile_config(profile.as_deref())
+        .await
+        .map_err(|err| err.to_string())?;
+    gdrive::complete_device_auth(&config, &device_code, interval)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn test_replacement(
+    rule: postprocess::ReplacementRule,
+    sample: String,
+) -> Result<String, String> {
+    postprocess::test_rule(&rule, &sample).map_err(|err| err.to_string())
+}
+
 #[tauri::command]
 async fn get_default_output_dir() -> Result<String, String> {
     default_output_dir()
@@ -1352,16 +4563,19 @@ async fn get_default_output_dir() -> Result<String, String> {
         .map_err(|err| err.to_string())
 }
 
-#[tauri::command]
-async fn get_default_whisper_binary() -> Result<Option<String>, String> {
+fn default_whisper_binary() -> Option<PathBuf> {
+    if let Some(bundled) = bundled_whisper_binary() {
+        return Some(bundled);
+    }
     if cfg!(target_os = "windows") {
         if let Some(documents) = windows_documents_dir() {
-            let path = documents
-                .join("WhisperDesktop")
-                .join("whisper-bin-x64")
-                .join("Release")
-                .join("whisper-cli.exe");
-            return Ok(Some(path.to_string_lossy().to_string()));
+            return Some(
+                documents
+                    .join("WhisperDesktop")
+                    .join("whisper-bin-x64")
+                    .join("Release")
+                    .join("whisper-cli.exe"),
+            );
         }
     }
     let mut found: Option<PathBuf> = None;
@@ -1379,7 +4593,12 @@ async fn get_default_whisper_binary() -> Result<Option<String>, String> {
             }
         }
     }
-    Ok(found.map(|path| path.to_string_lossy().to_string()))
+    found
+}
+
+#[tauri::command]
+async fn get_default_whisper_binary() -> Result<Option<String>, String> {
+    Ok(default_whisper_binary().map(|path| path.to_string_lossy().to_string()))
 }
 
 #[tauri::command]
@@ -1394,24 +4613,458 @@ async fn get_default_ffmpeg_binary() -> Result<Option<String>, String> {
     Ok(default_ffmpeg_path().map(|path| path.to_string_lossy().to_string()))
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BenchmarkReport {
+    binary_path: String,
+    model_path: String,
+    audio_seconds: f64,
+    elapsed_seconds: f64,
+    realtime_factor: f64,
+    peak_memory_bytes: u64,
+    threads: usize,
+    sample: String,
+}
+
+const BENCHMARK_TONE_SECONDS: u32 = 30;
+
+/// Benchmarks `sample_path` when given; otherwise a generated sine tone, which decodes
+/// faster than speech and so understates the realtime factor.
+async fn benchmark_whisper(
+    config: &AppConfig,
+    sample_path: Option<&Path>,
+) -> Result<BenchmarkReport> {
+    if let Some(path) = sample_path.filter(|path| !path.is_file()) {
+        return Err(anyhow!("Benchmark sample not found: {}", path.display()));
+    }
+    let (binary_path, model_path) = ensure_whisper_resources(config).await?;
+    let ffmpeg_path = resolve_ffmpeg_path(config)?;
+    let temp_root = temp_base_dir().join(format!("benchmark-{}", Uuid::new_v4()));
+    fs::create_dir_all(&temp_root).await?;
+    let sample = temp_root.join("sample.wav");
+    let mut command = Command::new(&ffmpeg_path);
+    command.arg("-y").arg("-nostdin");
+    match sample_path {
+        Some(path) => command.arg("-i").arg(path),
+        None => command.arg("-f").arg("lavfi").arg("-i").arg(format!(
+            "sine=frequency=440:duration={BENCHMARK_TONE_SECONDS}"
+        )),
+    };
+    let status = command
+        .arg("-ar")
+        .arg("16000")
+        .arg("-ac")
+        .arg("1")
+        .arg(&sample)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .await
+        .with_context(|| format!("Failed to execute ffmpeg: {}", ffmpeg_path.display()))?;
+    if !status.success() {
+        let _ = fs::remove_dir_all(&temp_root).await;
+        return Err(anyhow!("ffmpeg failed to prepare the benchmark sample"));
+    }
+    let audio_seconds = match sample_path {
+        Some(_) => wav_duration(config, &sample)
+            .await
+            .filter(|seconds| *seconds > 0.0)
+            .unwrap_or_default(),
+        None => f64::from(BENCHMARK_TONE_SECONDS),
+    };
+    if audio_seconds <= 0.0 {
+        let _ = fs::remove_dir_all(&temp_root).await;
+        return Err(anyhow!("Failed to read the benchmark sample duration"));
+    }
+
+    let threads = config
+        .whisper
+        .threads
+        .map(|value| value as usize)
+        .unwrap_or_else(|| {
+            std::thread::available_parallelism()
+                .map(|value| value.get().min(4))
+                .unwrap_or(4)
+        });
+    let mut args = whisper_cli_args(&config.whisper);
+    if config.whisper.threads.is_none() {
+        args.push("-t".to_string());
+        args.push(threads.to_string());
+    }
+    let started = std::time::Instant::now();
+    let mut child = Command::new(&binary_path)
+        .arg("-m")
+        .arg(&model_path)
+        .arg("-f")
+        .arg(&sample)
+        .args(&args)
+        .arg("-np")
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .kill_on_drop(true)
+        .spawn()
+        .with_context(|| "Failed to execute whisper")?;
+
+    let mut peak_memory_bytes = 0;
+    let mut system = sysinfo::System::new();
+    let status = loop {
+        if let Some(pid) = child.id() {
+            let pid = sysinfo::Pid::from_u32(pid);
+            system.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid]), true);
+            if let Some(process) = system.process(pid) {
+                peak_memory_bytes = peak_memory_bytes.max(process.memory());
+            }
+        }
+        tokio::select! {
+            status = child.wait() => break status?,
+            _ = tokio::time::sleep(std::time::Duration::from_millis(200)) => {}
+        }
+    };
+    let elapsed_seconds = started.elapsed().as_secs_f64();
+    let _ = fs::remove_dir_all(&temp_root).await;
+    if !status.success() {
+        return Err(anyhow!("Whisper command failed during benchmark"));
+    }
+
+    Ok(BenchmarkReport {
+        binary_path: binary_path.to_string_lossy().to_string(),
+        model_path: model_path.to_string_lossy().to_string(),
+        audio_seconds,
+        elapsed_seconds,
+        realtime_factor: elapsed_seconds / audio_seconds,
+        peak_memory_bytes,
+        threads,
+        sample: sample_path
+            .map(|path| path.to_string_lossy().to_string())
+            .unwrap_or_else(|| "tone".to_string()),
+    })
+}
+
+#[tauri::command]
+async fn run_benchmark(sample_path: Option<String>) -> Result<BenchmarkReport, String> {
+    let config = effective_config().await.map_err(|err| err.to_string())?;
+    let sample_path = sample_path
+        .map(|path| path.trim().to_string())
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from);
+    benchmark_whisper(&config, sample_path.as_deref())
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn list_known_models() -> Result<Vec<KnownModel>, String> {
+    let config = effective_config().await.map_err(|err| err.to_string())?;
+    models::known_models(&config.whisper.model_mirror_url).map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn list_local_models() -> Result<Vec<LocalModel>, String> {
+    models::local_models().await.map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn delete_model(name: String) -> Result<(), String> {
+    models::delete_model(&name)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+const DEFAULT_TEMP_RETENTION_DAYS: u32 = 7;
+const WAV_EXPANSION_FACTOR: u64 = 8;
+const MIN_OUTPUT_FREE_BYTES: u64 = 50 * 1024 * 1024;
+
+fn available_space(path: &Path) -> Option<u64> {
+    let path = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    disks
+        .list()
+        .iter()
+        .filter(|disk| path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())
+        .map(|disk| disk.available_space())
+}
+
+fn format_megabytes(bytes: u64) -> String {
+    format!("{:.1} MB", bytes as f64 / 1_048_576.0)
+}
+
+fn preflight_disk_space(
+    tracks: &[TrackEntry],
+    converts_to_wav: bool,
+    temp_root: &Path,
+    output_path: &Path,
+) -> Result<()> {
+    let download_bytes: u64 = tracks.iter().filter_map(|track| track.size).sum();
+    let wav_bytes = if converts_to_wav {
+        tracks
+            .iter()
+            .filter(|track| !is_wav(Path::new(&track.key)))
+            .filter_map(|track| track.size)
+            .sum::<u64>()
+            .saturating_mul(WAV_EXPANSION_FACTOR)
+    } else {
+        0
+    };
+    let required = download_bytes.saturating_add(wav_bytes);
+    if let Some(available) = available_space(temp_root) {
+        if required > available {
+            return Err(anyhow!(
+                "Not enough free space in {}: about {} required, {} available",
+                temp_root.display(),
+                format_megabytes(required),
+                format_megabytes(available)
+            ));
+        }
+    }
+    let output_dir = output_path.parent().unwrap_or(output_path);
+    if let Some(available) = available_space(output_dir) {
+        if available < MIN_OUTPUT_FREE_BYTES {
+            return Err(anyhow!(
+                "Not enough free space in {}: {} available",
+                output_dir.display(),
+                format_megabytes(available)
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct CleanTempResult {
+    removed: usize,
+    freed_bytes: u64,
+}
+
+fn temp_base_dir() -> PathBuf {
+    std::env::temp_dir().join("whisperdesktop")
+}
+
+async fn sweep_temp(max_age_days: u32, active: HashSet<String>) -> Result<CleanTempResult> {
+    let base = temp_base_dir();
+    let max_age = std::time::Duration::from_secs(u64::from(max_age_days) * 24 * 60 * 60);
+    tokio::task::spawn_blocking(move || {
+        let mut result = CleanTempResult::default();
+        let Ok(entries) = std::fs::read_dir(&base) else {
+            return Ok(result);
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if !path.is_dir() || active.contains(&entry.file_name().to_string_lossy().to_string()) {
+                continue;
+            }
+            let age = entry
+                .metadata()
+                .and_then(|meta| meta.modified())
+                .ok()
+                .and_then(|modified| modified.elapsed().ok())
+                .unwrap_or_default();
+            if age < max_age {
+                continue;
+            }
+            let size = models::dir_size(&path);
+            match std::fs::remove_dir_all(&path) {
+                Ok(()) => {
+                    result.removed += 1;
+                    result.freed_bytes += size;
+                }
+                Err(err) => eprintln!("Failed to remove {}: {err}", path.display()),
+            }
+        }
+        Ok(result)
+    })
+    .await?
+}
+
+fn active_job_ids(jobs: &JobState) -> HashSet<String> {
+    jobs.lock()
+        .unwrap()
+        .iter()
+        .filter(|(_, status)| status.state == "running")
+        .map(|(id, _)| id.clone())
+        .collect()
+}
+
+#[tauri::command]
+async fn clean_temp(
+    older_than_days: Option<u32>,
+    jobs: State<'_, JobState>,
+) -> Result<CleanTempResult, String> {
+    sweep_temp(older_than_days.unwrap_or(0), active_job_ids(&jobs))
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn get_model_disk_usage() -> Result<ModelDiskUsage, String> {
+    models::model_disk_usage()
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn recommend_model() -> Result<ModelRecommendation, String> {
+    let config = effective_config().await.map_err(|err| err.to_string())?;
+    let gpu = resolve_whisper_paths(&config)
+        .ok()
+        .and_then(|(binary_path, _)| detect_gpu_backend(&binary_path));
+    models::recommend_model(gpu).map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn download_model(
+    name: String,
+    app: AppHandle,
+    downloads: State<'_, DownloadState>,
+) -> Result<String, String> {
+    let config = effective_config().await.map_err(|err| err.to_string())?;
+    let downloads = downloads.inner().clone();
+    models::download_model(&app, &downloads, &config, &name)
+        .await
+        .map(|path| path.to_string_lossy().to_string())
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn get_first_run_status() -> Result<FirstRunStatus, String> {
+    let config = effective_config().await.map_err(|err| err.to_string())?;
+    let configured_model = resolve_whisper_paths(&config)
+        .map(|(_, model_path)| model_path.is_file())
+        .unwrap_or(false);
+    models::first_run_status(configured_model)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn download_default_model(
+    app: AppHandle,
+    downloads: State<'_, DownloadState>,
+) -> Result<String, String> {
+    let config = effective_config().await.map_err(|err| err.to_string())?;
+    let downloads = downloads.inner().clone();
+    models::download_default_model(&app, &downloads, &config)
+        .await
+        .map(|path| path.to_string_lossy().to_string())
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn setup_coreml_encoder(
+    app: AppHandle,
+    downloads: State<'_, DownloadState>,
+) -> Result<String, String> {
+    let config = effective_config().await.map_err(|err| err.to_string())?;
+    let (_, model_path) = resolve_whisper_paths(&config).map_err(|err| err.to_string())?;
+    let downloads = downloads.inner().clone();
+    models::setup_coreml_encoder(&app, &downloads, &config, &model_path)
+        .await
+        .map(|path| path.to_string_lossy().to_string())
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn cancel_model_download(
+    name: String,
+    downloads: State<'_, DownloadState>,
+) -> Result<(), String> {
+    models::cancel_download(&downloads, &name).map_err(|err| err.to_string())
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
+        .plugin(tauri_plugin_notification::init())
+        .plugin(tauri_plugin_opener::init())
+        .setup(|app| {
+            watcher::spawn_watcher(app.handle().clone());
+            scheduler::spawn_scheduler(app.handle().clone());
+            #[cfg(feature = "http-api")]
+            http_api::spawn_server(app.handle().clone());
+            config_watcher::spawn_config_watcher(app.handle().clone());
+            tauri::async_runtime::spawn(async {
+                let days = effective_config()
+                    .await
+                    .ok()
+                    .and_then(|config| config.whisper.temp_retention_days)
+                    .unwrap_or(DEFAULT_TEMP_RETENTION_DAYS);
+                match sweep_temp(days, HashSet::new()).await {
+                    Ok(result) if result.removed > 0 => eprintln!(
+                        "Removed {} stale temp directories ({} bytes)",
+                        result.removed, result.freed_bytes
+                    ),
+                    Ok(_) => {}
+                    Err(err) => eprintln!("Temp cleanup failed: {err}"),
+                }
+            });
+            Ok(())
+        })
         .manage(std::sync::Arc::new(Mutex::new(
             HashMap::<String, JobStatus>::new(),
         )))
+        .manage(DownloadState::default())
         .invoke_handler(tauri::generate_handler![
             list_dates,
             list_meetings,
+            refresh_listings,
+            list_meetings_range,
+            get_meeting_detail,
+            search_meetings,
+            tags::set_meeting_tags,
+            tags::set_meeting_favorite,
+            tags::list_meeting_tags,
+            transcripts::list_transcripts,
+            transcripts::open_transcript,
+            transcripts::reveal_transcript,
+            transcripts::delete_transcript,
+            editor::get_transcript_segments,
+            editor::save_transcript,
+            editor::update_segment,
+            archive::export_transcripts_zip,
+            search_index::search_transcripts,
+            store::list_job_records,
+            store::get_job_record,
+            store::list_seen_meetings,
+            get_track_presigned_url,
+            get_waveform,
+            delete_meeting,
             start_transcribe,
+            transcribe_local_files,
+            transcribe_url,
             get_transcribe_status,
             get_config,
             set_config,
+            export_config,
+            reset_config,
+            reset_section,
+            import_config,
             get_default_output_dir,
             get_default_whisper_binary,
             get_default_whisper_model_root,
             get_default_ffmpeg_binary,
-            check_minio
+            run_benchmark,
+            list_known_models,
+            list_local_models,
+            delete_model,
+            get_model_disk_usage,
+            clean_temp,
+            recommend_model,
+            download_model,
+            cancel_model_download,
+            get_first_run_status,
+            download_default_model,
+            setup_coreml_encoder,
+            list_storage_profiles,
+            add_storage_profile,
+            remove_storage_profile,
+            select_storage_profile,
+            start_gdrive_auth,
+            complete_gdrive_auth,
+            check_minio,
+            validate_config,
+            detect_everything,
+            test_replacement
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+use crate::storage::StorageSource;
+use crate::store;
+use crate::{
+    annotate_meetings, fetch_dates, fetch_meetings, project_dirs, AppConfig, MeetingSummary,
+    MinioConfig,
+};
+
+const LISTING_STALE_AFTER_SECS: i64 = 5 * 60;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Listing<T> {
+    pub(crate) items: T,
+    fetched_at: i64,
+    cached: bool,
+    pub(crate) stale: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedEntry<T> {
+    fetched_at: i64,
+    items: T,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SourceListings {
+    dates: Option<CachedEntry<Vec<String>>>,
+    #[serde(default)]
+    meetings: HashMap<String, CachedEntry<Vec<MeetingSummary>>>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ListingCache {
+    #[serde(default)]
+    sources: HashMap<String, SourceListings>,
+}
+
+fn cache_path() -> Result<PathBuf> {
+    let dirs = project_dirs()?;
+    Ok(dirs.cache_dir().join("listings.json"))
+}
+
+fn source_key(minio: &MinioConfig) -> String {
+    [
+        minio.provider.trim(),
+        minio.url.trim(),
+        minio.bucket.trim(),
+        &minio.key_root(),
+        minio.local_root.trim(),
+        minio.sftp_host.trim(),
+        minio.sftp_root.trim(),
+        minio.webdav_url.trim(),
+        minio.gdrive_folder_id.trim(),
+        minio.azure_container.trim(),
+    ]
+    .join("|")
+}
+
+async fn load_cache() -> ListingCache {
+    let Ok(path) = cache_path() else {
+        return ListingCache::default();
+    };
+    match fs::read_to_string(&path).await {
+        Ok(data) => serde_json::from_str(&data).unwrap_or_default(),
+        Err(_) => ListingCache::default(),
+    }
+}
+
+async fn save_cache(cache: &ListingCache) -> Result<()> {
+    let path = cache_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+    fs::write(&path, serde_json::to_string(cache)?).await?;
+    Ok(())
+}
+
+fn to_listing<T: Clone>(entry: &CachedEntry<T>, cached: bool) -> Listing<T> {
+    let age = chrono::Utc::now().timestamp() - entry.fetched_at;
+    Listing {
+        items: entry.items.clone(),
+        fetched_at: entry.fetched_at,
+        cached,
+        stale: age > LISTING_STALE_AFTER_SECS,
+    }
+}
+
+pub(crate) async fn cached_dates(
+    minio: &MinioConfig,
+    source: &dyn StorageSource,
+    retry_attempts: u32,
+    refresh: bool,
+) -> Result<Listing<Vec<String>>> {
+    let key = source_key(minio);
+    let mut cache = load_cache().await;
+    if !refresh {
+        if let Some(entry) = cache
+            .sources
+            .get(&key)
+            .and_then(|listings| listings.dates.as_ref())
+        {
+            return Ok(to_listing(entry, true));
+        }
+    }
+    let entry = CachedEntry {
+        fetched_at: chrono::Utc::now().timestamp(),
+        items: fetch_dates(source, retry_attempts).await?,
+    };
+    let listing = to_listing(&entry, false);
+    let listings = cache.sources.entry(key).or_default();
+    listings.dates = Some(entry);
+    if refresh {
+        listings.meetings.clear();
+    }
+    if let Err(err) = save_cache(&cache).await {
+        eprintln!("Failed to save listing cache: {err}");
+    }
+    Ok(listing)
+}
+
+pub(crate) async fn cached_meetings(
+    config: &AppConfig,
+    source: &dyn StorageSource,
+    retry_attempts: u32,
+    date: &str,
+    refresh: bool,
+) -> Result<Listing<Vec<MeetingSummary>>> {
+    let key = source_key(&config.minio);
+    let mut cache = load_cache().await;
+    if !refresh {
+        if let Some(entry) = cache
+            .sources
+            .get(&key)
+            .and_then(|listings| listings.meetings.get(date))
+        {
+            let mut listing = to_listing(entry, true);
+            annotate_meetings(config, &mut listing.items).await;
+            return Ok(listing);
+        }
+    }
+    let entry = CachedEntry {
+        fetched_at: chrono::Utc::now().timestamp(),
+        items: fetch_meetings(source, retry_attempts, date).await?,
+    };
+    if let Err(err) = store::record_meetings(&entry.items) {
+        eprintln!("Failed to record meetings: {err}");
+    }
+    let mut listing = to_listing(&entry, false);
+    annotate_meetings(config, &mut listing.items).await;
+    cache
+        .sources
+        .entry(key)
+        .or_default()
+        .meetings
+        .insert(date.to_string(), entry);
+    if let Err(err) = save_cache(&cache).await {
+        eprintln!("Failed to save listing cache: {err}");
+    }
+    Ok(listing)
+}
@@ -0,0 +1,54 @@
+use anyhow::{anyhow, Context, Result};
+
+use crate::models::download_client;
+use crate::WhisperConfig;
+
+fn chat_url(whisper: &WhisperConfig) -> String {
+    let base_url = whisper.openai_base_url.trim().trim_end_matches('/');
+    let base_url = if base_url.is_empty() {
+        "https://api.openai.com/v1"
+    } else {
+        base_url
+    };
+    format!("{base_url}/chat/completions")
+}
+
+pub(crate) async fn chat_json(
+    whisper: &WhisperConfig,
+    proxy_url: &str,
+    model: &str,
+    instructions: &str,
+    input: &str,
+) -> Result<serde_json::Value> {
+    let api_key = whisper.openai_api_key.trim();
+    if api_key.is_empty() {
+        return Err(anyhow!("OpenAI API key is not configured"));
+    }
+    let payload = serde_json::json!({
+        "model": model,
+        "response_format": { "type": "json_object" },
+        "messages": [
+            { "role": "system", "content": instructions },
+            { "role": "user", "content": input },
+        ],
+    });
+    let response = download_client(proxy_url)?
+        .post(chat_url(whisper))
+        .bearer_auth(api_key)
+        .json(&payload)
+        .send()
+        .await
+        .with_context(|| "Failed to send request to OpenAI")?;
+    let status = response.status();
+    let body = response.text().await?;
+    if !status.is_success() {
+        return Err(anyhow!("OpenAI API returned {status}: {body}"));
+    }
+    let value: serde_json::Value =
+        serde_json::from_str(&body).with_context(|| "Failed to parse OpenAI response")?;
+    let content = value
+        .pointer("/choices/0/message/content")
+        .and_then(|content| content.as_str())
+        .ok_or_else(|| anyhow!("OpenAI response did not include a message"))?;
+    serde_json::from_str(content).with_context(|| "OpenAI returned invalid JSON")
+}
@@ -0,0 +1,549 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use anyhow::{anyhow, Context, Result};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use crate::{default_whisper_model_root, AppConfig};
+
+const HUGGING_FACE_BASE_URL: &str = "https://huggingface.co/ggerganov/whisper.cpp/resolve/main";
+
+pub(crate) type DownloadState = Arc<Mutex<HashMap<String, Arc<AtomicBool>>>>;
+
+const MIB: u64 = 1024 * 1024;
+
+const FIRST_RUN_MODEL: &str = "small";
+
+const PROGRESS_EMIT_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
+
+const KNOWN_MODELS: &[(&str, u64)] = &[
+    ("tiny", 75 * MIB),
+    ("tiny-q5_1", 31 * MIB),
+    ("base", 142 * MIB),
+    ("base-q5_1", 57 * MIB),
+    ("small", 466 * MIB),
+    ("small-q5_1", 181 * MIB),
+    ("medium", 1500 * MIB),
+    ("medium-q5_0", 514 * MIB),
+    ("large-v2", 2900 * MIB),
+    ("large-v2-q5_0", 1100 * MIB),
+    ("large-v3", 2900 * MIB),
+    ("large-v3-q5_0", 1100 * MIB),
+    ("large-v3-turbo", 1500 * MIB),
+    ("large-v3-turbo-q5_0", 547 * MIB),
+    ("large-v3-turbo-q8_0", 834 * MIB),
+];
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct KnownModel {
+    name: String,
+    file_name: String,
+    size_bytes: u64,
+    url: String,
+    installed: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct LocalModel {
+    name: String,
+    path: String,
+    size_bytes: u64,
+    quantization: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ModelDiskUsage {
+    root: String,
+    total_bytes: u64,
+    model_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ModelRecommendation {
+    pub(crate) name: String,
+    file_name: String,
+    reason: String,
+    pub(crate) installed: bool,
+    total_memory_bytes: u64,
+    cpu_cores: usize,
+    gpu: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct FirstRunStatus {
+    model_root: String,
+    has_model: bool,
+    default_model: String,
+    default_size_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DownloadProgress {
+    name: String,
+    state: &'static str,
+    downloaded: u64,
+    total: Option<u64>,
+}
+
+fn model_file_name(name: &str) -> String {
+    format!("ggml-{name}.bin")
+}
+
+fn mirror_base_url(mirror_url: &str) -> &str {
+    let base_url = mirror_url.trim().trim_end_matches('/');
+    if base_url.is_empty() {
+        HUGGING_FACE_BASE_URL
+    } else {
+        base_url
+    }
+}
+
+fn model_url(mirror_url: &str, name: &str) -> String {
+    format!("{}/{}", mirror_base_url(mirror_url), model_file_name(name))
+}
+
+pub(crate) fn known_models(mirror_url: &str) -> Result<Vec<KnownModel>> {
+    let model_root = default_whisper_model_root()?;
+    Ok(KNOWN_MODELS
+        .iter()
+        .map(|(name, size_bytes)| {
+            let file_name = model_file_name(name);
+            KnownModel {
+                name: name.to_string(),
+                installed: model_root.join(&file_name).is_file(),
+                file_name,
+                size_bytes: *size_bytes,
+                url: model_url(mirror_url, name),
+            }
+        })
+        .collect())
+}
+
+pub(crate) fn is_model_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.eq_ignore_ascii_case("bin") || ext.eq_ignore_ascii_case("gguf"))
+        .unwrap_or(false)
+}
+
+fn detect_quantization(file_stem: &str) -> Option<String> {
+    file_stem
+        .to_ascii_lowercase()
+        .split(['-', '.'])
+        .find(|part| {
+            let mut chars = part.chars();
+            chars.next() == Some('q') && chars.next().is_some_and(|c| c.is_ascii_digit())
+        })
+        .map(str::to_string)
+}
+
+pub(crate) async fn local_models() -> Result<Vec<LocalModel>> {
+    let model_root = default_whisper_model_root()?;
+    let mut models = Vec::new();
+    let mut entries = match fs::read_dir(&model_root).await {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(models),
+        Err(err) => return Err(err.into()),
+    };
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        if !is_model_file(&path) {
+            continue;
+        }
+        let metadata = entry.metadata().await?;
+        if !metadata.is_file() {
+            continue;
+        }
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let stem = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        models.push(LocalModel {
+            name,
+            path: path.to_string_lossy().to_string(),
+            size_bytes: metadata.len(),
+            quantization: detect_quantization(&stem),
+        });
+    }
+    models.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(models)
+}
+
+pub(crate) async fn first_run_status(configured_model: bool) -> Result<FirstRunStatus> {
+    let model_root = default_whisper_model_root()?;
+    let has_model = configured_model || !local_models().await?.is_empty();
+    let default_size_bytes = KNOWN_MODELS
+        .iter()
+        .find(|(name, _)| *name == FIRST_RUN_MODEL)
+        .map(|(_, size)| *size)
+        .unwrap_or_default();
+    Ok(FirstRunStatus {
+        model_root: model_root.to_string_lossy().to_string(),
+        has_model,
+        default_model: FIRST_RUN_MODEL.to_string(),
+        default_size_bytes,
+    })
+}
+
+pub(crate) async fn download_default_model(
+    app: &AppHandle,
+    downloads: &DownloadState,
+    config: &AppConfig,
+) -> Result<PathBuf> {
+    if let Some(existing) = local_models().await?.into_iter().next() {
+        return Ok(PathBuf::from(existing.path));
+    }
+    download_model(app, downloads, config, FIRST_RUN_MODEL).await
+}
+
+pub(crate) async fn delete_model(name: &str) -> Result<()> {
+    let file_name = Path::new(name)
+        .file_name()
+        .filter(|file_name| file_name.to_string_lossy() == name)
+        .ok_or_else(|| anyhow!("Invalid model name: {name}"))?;
+    let model_root = default_whisper_model_root()?;
+    let path = model_root.join(file_name);
+    if !is_model_file(&path) || !path.is_file() {
+        return Err(anyhow!("Model not found: {}", path.display()));
+    }
+    fs::remove_file(&path)
+        .await
+        .with_context(|| format!("Failed to delete model: {}", path.display()))?;
+    Ok(())
+}
+
+pub(crate) async fn model_disk_usage() -> Result<ModelDiskUsage> {
+    let model_root = default_whisper_model_root()?;
+    let model_count = local_models().await?.len();
+    let root = model_root.clone();
+    let total_bytes = tokio::task::spawn_blocking(move || dir_size(&root)).await?;
+    Ok(ModelDiskUsage {
+        root: model_root.to_string_lossy().to_string(),
+        total_bytes,
+        model_count,
+    })
+}
+
+pub(crate) fn dir_size(dir: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .map(|entry| match entry.metadata() {
+            Ok(meta) if meta.is_dir() => dir_size(&entry.path()),
+            Ok(meta) => meta.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
+pub(crate) fn recommend_model(gpu: Option<&str>) -> Result<ModelRecommendation> {
+    const GIB: u64 = 1024 * MIB;
+    let mut system = sysinfo::System::new();
+    system.refresh_memory();
+    let total_memory_bytes = system.total_memory();
+    let cpu_cores = std::thread::available_parallelism()
+        .map(|value| value.get())
+        .unwrap_or(1);
+    let (name, reason) = if gpu.is_some() && total_memory_bytes >= 16 * GIB {
+        (
+            "large-v3",
+            "GPU acceleration and at least 16GB of RAM are available",
+        )
+    } else if total_memory_bytes >= 16 * GIB && cpu_cores >= 8 {
+        (
+            "large-v3-turbo-q5_0",
+            "Plenty of RAM and CPU cores, but no GPU acceleration",
+        )
+    } else if total_memory_bytes >= 8 * GIB {
+        ("medium-q5_0", "8GB or more of RAM is available")
+    } else if total_memory_bytes >= 4 * GIB {
+        (
+            "small",
+            "Limited RAM; a small model keeps transcription responsive",
+        )
+    } else {
+        (
+            "base-q5_1",
+            "Very limited RAM; only a compact model will fit",
+        )
+    };
+    let file_name = model_file_name(name);
+    let installed = default_whisper_model_root()?.join(&file_name).is_file();
+    Ok(ModelRecommendation {
+        name: name.to_string(),
+        file_name,
+        reason: reason.to_string(),
+        installed,
+        total_memory_bytes,
+        cpu_cores,
+        gpu: gpu.map(str::to_string),
+    })
+}
+
+pub(crate) async fn download_model(
+    app: &AppHandle,
+    downloads: &DownloadState,
+    config: &AppConfig,
+    name: &str,
+) -> Result<PathBuf> {
+    if !KNOWN_MODELS.iter().any(|(known, _)| *known == name) {
+        return Err(anyhow!("Unknown model: {name}"));
+    }
+    let model_root = default_whisper_model_root()?;
+    fs::create_dir_all(&model_root)
+        .await
+        .with_context(|| format!("Failed to create model dir: {}", model_root.display()))?;
+    let dest = model_root.join(model_file_name(name));
+    if dest.is_file() {
+        return Ok(dest);
+    }
+    let partial = dest.with_extension("bin.part");
+    let url = model_url(&config.whisper.model_mirror_url, name);
+    download_tracked(app, downloads, config, name, &url, &partial).await?;
+    fs::rename(&partial, &dest)
+        .await
+        .with_context(|| format!("Failed to move model into place: {}", dest.display()))?;
+    emit_progress(app, name, "done", 0, None);
+    Ok(dest)
+}
+
+pub(crate) fn coreml_encoder_path(model_path: &Path) -> Option<PathBuf> {
+    let stem = model_path.file_stem()?.to_string_lossy().to_string();
+    let stem = match stem.rsplit_once('-') {
+        Some((base, suffix)) if detect_quantization(suffix).is_some() => base.to_string(),
+        _ => stem,
+    };
+    Some(model_path.with_file_name(format!("{stem}-encoder.mlmodelc")))
+}
+
+pub(crate) async fn setup_coreml_encoder(
+    app: &AppHandle,
+    downloads: &DownloadState,
+    config: &AppConfig,
+    model_path: &Path,
+) -> Result<PathBuf> {
+    if !cfg!(target_os = "macos") {
+        return Err(anyhow!("CoreML encoder is only supported on macOS"));
+    }
+    let encoder_path = coreml_encoder_path(model_path)
+        .ok_or_else(|| anyhow!("Invalid model path: {}", model_path.display()))?;
+    if encoder_path.is_dir() {
+        return Ok(encoder_path);
+    }
+    let encoder_name = encoder_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let model_dir = encoder_path
+        .parent()
+        .ok_or_else(|| anyhow!("Invalid model path: {}", model_path.display()))?;
+    let url = format!(
+        "{}/{encoder_name}.zip",
+        mirror_base_url(&config.whisper.model_mirror_url)
+    );
+    let partial = model_dir.join(format!("{encoder_name}.zip.part"));
+    download_tracked(app, downloads, config, &encoder_name, &url, &partial).await?;
+
+    let output = Command::new("unzip")
+        .arg("-o")
+        .arg("-q")
+        .arg(&partial)
+        .arg("-d")
+        .arg(model_dir)
+        .output()
+        .await
+        .with_context(|| "Failed to execute unzip")?;
+    let _ = fs::remove_file(&partial).await;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "Failed to extract CoreML encoder: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    if !encoder_path.is_dir() {
+        return Err(anyhow!(
+            "CoreML encoder not found after extraction: {}",
+            encoder_path.display()
+        ));
+    }
+    emit_progress(app, &encoder_name, "done", 0, None);
+    Ok(encoder_path)
+}
+
+pub(crate) fn download_client(proxy_url: &str) -> Result<reqwest::Client> {
+    let proxy_url = proxy_url.trim();
+    let mut builder = reqwest::Client::builder();
+    if !proxy_url.is_empty() {
+        let proxy = reqwest::Proxy::all(proxy_url)
+            .with_context(|| format!("Invalid proxy URL: {proxy_url}"))?;
+        builder = builder.proxy(proxy);
+    }
+    Ok(builder.build()?)
+}
+
+async fn download_tracked(
+    app: &AppHandle,
+    downloads: &DownloadState,
+    config: &AppConfig,
+    name: &str,
+    url: &str,
+    partial: &Path,
+) -> Result<()> {
+    let client = download_client(&config.proxy_url)?;
+    let cancelled = Arc::new(AtomicBool::new(false));
+    {
+        let mut downloads = downloads.lock().expect("downloads lock");
+        if downloads.contains_key(name) {
+            return Err(anyhow!("Model is already downloading: {name}"));
+        }
+        downloads.insert(name.to_string(), cancelled.clone());
+    }
+    let result = download_resumable(app, &client, name, url, partial, &cancelled).await;
+    downloads.lock().expect("downloads lock").remove(name);
+    if cancelled.load(Ordering::SeqCst) {
+        let _ = fs::remove_file(partial).await;
+        emit_progress(app, name, "cancelled", 0, None);
+        return Err(anyhow!("Model download cancelled: {name}"));
+    }
+    if let Err(err) = result {
+        emit_progress(app, name, "failed", 0, None);
+        return Err(err);
+    }
+    Ok(())
+}
+
+pub(crate) fn cancel_download(downloads: &DownloadState, name: &str) -> Result<()> {
+    let downloads = downloads.lock().expect("downloads lock");
+    let cancelled = downloads
+        .get(name)
+        .ok_or_else(|| anyhow!("No download in progress: {name}"))?;
+    cancelled.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+fn emit_progress(
+    app: &AppHandle,
+    name: &str,
+    state: &'static str,
+    downloaded: u64,
+    total: Option<u64>,
+) {
+    let _ = app.emit(
+        "model-download://progress",
+        DownloadProgress {
+            name: name.to_string(),
+            state,
+            downloaded,
+            total,
+        },
+    );
+}
+
+async fn download_resumable(
+    app: &AppHandle,
+    client: &reqwest::Client,
+    name: &str,
+    url: &str,
+    partial: &Path,
+    cancelled: &AtomicBool,
+) -> Result<()> {
+    let mut existing = fs::metadata(partial)
+        .await
+        .map(|meta| meta.len())
+        .unwrap_or(0);
+    let mut response = request_from(client, url, existing).await?;
+    if response.status() == reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+        if range_total(&response) == Some(existing) {
+            emit_progress(app, name, "downloading", existing, Some(existing));
+            return Ok(());
+        }
+        // The partial file does not match the remote object; start over.
+        fs::remove_file(partial)
+            .await
+            .with_context(|| format!("Failed to remove file: {}", partial.display()))?;
+        existing = 0;
+        response = request_from(client, url, existing).await?;
+    }
+    let status = response.status();
+    if !status.is_success() {
+        return Err(anyhow!("Model download failed with {status}"));
+    }
+    let resumed = status == reqwest::StatusCode::PARTIAL_CONTENT;
+    let mut downloaded = if resumed { existing } else { 0 };
+    let total = response.content_length().map(|length| length + downloaded);
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(resumed)
+        .truncate(!resumed)
+        .open(partial)
+        .await
+        .with_context(|| format!("Failed to open file: {}", partial.display()))?;
+
+    let mut last_emit = std::time::Instant::now();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .with_context(|| "Model download interrupted")?
+    {
+        if cancelled.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+        file.write_all(&chunk).await?;
+        downloaded += chunk.len() as u64;
+        if last_emit.elapsed() >= PROGRESS_EMIT_INTERVAL {
+            emit_progress(app, name, "downloading", downloaded, total);
+            last_emit = std::time::Instant::now();
+        }
+    }
+    file.flush().await?;
+    emit_progress(app, name, "downloading", downloaded, total);
+    Ok(())
+}
+
+async fn request_from(
+    client: &reqwest::Client,
+    url: &str,
+    offset: u64,
+) -> Result<reqwest::Response> {
+    let mut request = client.get(url);
+    if offset > 0 {
+        request = request.header(reqwest::header::RANGE, format!("bytes={offset}-"));
+    }
+    request
+        .send()
+        .await
+        .with_context(|| format!("Failed to download {url}"))
+}
+
+/// Total size from a `Content-Range: bytes */<total>` header on a 416 response.
+fn range_total(response: &reqwest::Response) -> Option<u64> {
+    response
+        .headers()
+        .get(reqwest::header::CONTENT_RANGE)?
+        .to_str()
+        .ok()?
+        .rsplit_once('/')?
+        .1
+        .trim()
+        .parse()
+        .ok()
+}
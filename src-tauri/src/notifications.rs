@@ -0,0 +1,58 @@
+use std::path::Path;
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_notification::NotificationExt;
+
+use crate::{integrations, AppConfig};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct JobFinishedEvent {
+    pub(crate) job_id: String,
+    pub(crate) state: String,
+    pub(crate) label: String,
+    pub(crate) output_path: Option<String>,
+    pub(crate) error: Option<String>,
+}
+
+fn notification_body(event: &JobFinishedEvent) -> String {
+    if event.state == "done" {
+        let file_name = event
+            .output_path
+            .as_deref()
+            .and_then(|path| Path::new(path).file_name())
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        format!("Transcription finished: {}\n{file_name}", event.label)
+    } else {
+        format!(
+            "Transcription failed: {}\n{}",
+            event.label,
+            event.error.as_deref().unwrap_or_default()
+        )
+    }
+}
+
+pub(crate) async fn job_finished(
+    app: &AppHandle,
+    config: &AppConfig,
+    event: JobFinishedEvent,
+    log: impl Fn(&str),
+) {
+    let _ = app.emit("transcribe://finished", event.clone());
+    if config.job_notifications {
+        let _ = app
+            .notification()
+            .builder()
+            .title("WhisperDesktop")
+            .body(notification_body(&event))
+            .show();
+    }
+    if let Err(err) = integrations::post_webhook(config, &event).await {
+        log(&format!("Webhook notification failed: {err}"));
+    }
+    if let Err(err) = integrations::send_email(config, &event).await {
+        log(&format!("Email notification failed: {err}"));
+    }
+}
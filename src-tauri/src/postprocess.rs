@@ -0,0 +1,242 @@
+use anyhow::Result;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::{ja_normalize, AppConfig, TranscriptionSegment};
+
+const REPEAT_MAX_GAP_SECS: f64 = 3.0;
+
+const HALLUCINATION_PHRASES: &[&str] = &[
+    "ご視聴ありがとうございました",
+    "ご視聴いただきありがとうございました",
+    "最後までご視聴いただきありがとうございました",
+    "チャンネル登録お願いします",
+    "チャンネル登録よろしくお願いします",
+    "チャンネル登録をお願いいたします",
+    "おやすみなさい",
+    "thanks for watching",
+    "thank you for watching",
+    "subtitles by the amara.org community",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub(crate) struct ReplacementRule {
+    pattern: String,
+    replacement: String,
+    regex: bool,
+}
+
+enum Replacement {
+    Literal(String, String),
+    Regex(Regex, String),
+}
+
+impl Replacement {
+    fn compile(rule: &ReplacementRule) -> Result<Option<Self>> {
+        if rule.pattern.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(if rule.regex {
+            Self::Regex(Regex::new(&rule.pattern)?, rule.replacement.clone())
+        } else {
+            Self::Literal(rule.pattern.clone(), rule.replacement.clone())
+        }))
+    }
+
+    fn apply(&self, text: &str) -> String {
+        match self {
+            Self::Literal(pattern, replacement) => text.replace(pattern, replacement),
+            Self::Regex(regex, replacement) => {
+                regex.replace_all(text, replacement.as_str()).to_string()
+            }
+        }
+    }
+}
+
+pub(crate) struct Glossary {
+    replacements: Vec<Replacement>,
+}
+
+impl Glossary {
+    pub(crate) fn new(config: &AppConfig) -> Self {
+        let replacements = config
+            .replacements
+            .iter()
+            .filter_map(|rule| match Replacement::compile(rule) {
+                Ok(replacement) => replacement,
+                Err(err) => {
+                    eprintln!("Invalid replacement rule {}: {err}", rule.pattern);
+                    None
+                }
+            })
+            .collect();
+        Self { replacements }
+    }
+
+    fn apply(&self, text: &str) -> String {
+        self.replacements
+            .iter()
+            .fold(text.to_string(), |text, replacement| {
+                replacement.apply(&text)
+            })
+    }
+}
+
+pub(crate) fn test_rule(rule: &ReplacementRule, sample: &str) -> Result<String> {
+    Ok(match Replacement::compile(rule)? {
+        Some(replacement) => replacement.apply(sample),
+        None => sample.to_string(),
+    })
+}
+
+struct NgWordFilter {
+    regex: Regex,
+    flag: bool,
+}
+
+impl NgWordFilter {
+    fn new(config: &AppConfig) -> Option<Self> {
+        let mut words: Vec<&str> = config
+            .whisper
+            .ng_words
+            .iter()
+            .map(|word| word.trim())
+            .filter(|word| !word.is_empty())
+            .collect();
+        if words.is_empty() {
+            return None;
+        }
+        words.sort_by_key(|word| std::cmp::Reverse(word.chars().count()));
+        let pattern = words
+            .iter()
+            .map(|word| regex::escape(word))
+            .collect::<Vec<_>>()
+            .join("|");
+        match Regex::new(&format!("(?i){pattern}")) {
+            Ok(regex) => Some(Self {
+                regex,
+                flag: config.whisper.ng_word_mode.trim() == "flag",
+            }),
+            Err(err) => {
+                eprintln!("Invalid NG word list: {err}");
+                None
+            }
+        }
+    }
+
+    fn action(&self) -> &'static str {
+        if self.flag {
+            "flagged"
+        } else {
+            "masked"
+        }
+    }
+
+    fn apply(&self, text: &str, count: &mut usize) -> String {
+        self.regex
+            .replace_all(text, |captures: &regex::Captures| {
+                *count += 1;
+                let word = &captures[0];
+                if self.flag {
+                    format!("[NG]{word}")
+                } else {
+                    "＊".repeat(word.chars().count())
+                }
+            })
+            .to_string()
+    }
+}
+
+fn normalize_for_match(text: &str) -> String {
+    text.chars()
+        .filter(|ch| !ch.is_whitespace() && !ch.is_ascii_punctuation())
+        .filter(|ch| !"。、！？「」『』（）…・".contains(*ch))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn filter_hallucinations(
+    config: &AppConfig,
+    segments: &mut Vec<TranscriptionSegment>,
+    log: &impl Fn(&str),
+) {
+    let phrases: Vec<String> = HALLUCINATION_PHRASES
+        .iter()
+        .map(|phrase| phrase.to_string())
+        .chain(config.whisper.hallucination_phrases.iter().cloned())
+        .map(|phrase| normalize_for_match(&phrase))
+        .filter(|phrase| !phrase.is_empty())
+        .collect();
+    let mut kept: Vec<TranscriptionSegment> = Vec::with_capacity(segments.len());
+    let mut dropped = 0;
+    let mut collapsed = 0;
+    for segment in segments.drain(..) {
+        let normalized = normalize_for_match(&segment.text);
+        if normalized.is_empty() || phrases.contains(&normalized) {
+            if !normalized.is_empty() {
+                log(&format!(
+                    "Removed hallucination: {} {}",
+                    segment.speaker,
+                    segment.text.trim()
+                ));
+            }
+            dropped += 1;
+            continue;
+        }
+        if let Some(previous) = kept.last_mut() {
+            let gap = segment.start - previous.end.unwrap_or(previous.start);
+            if previous.speaker == segment.speaker
+                && gap <= REPEAT_MAX_GAP_SECS
+                && normalize_for_match(&previous.text) == normalized
+            {
+                previous.end = segment.end.or(previous.end);
+                collapsed += 1;
+                continue;
+            }
+        }
+        kept.push(segment);
+    }
+    if dropped > 0 || collapsed > 0 {
+        log(&format!(
+            "Hallucination filter: removed {dropped} segments, collapsed {collapsed} repeats"
+        ));
+    }
+    *segments = kept;
+}
+
+pub(crate) fn apply(
+    config: &AppConfig,
+    segments: &mut Vec<TranscriptionSegment>,
+    log: impl Fn(&str),
+) {
+    if config.whisper.hallucination_filter {
+        filter_hallucinations(config, segments, &log);
+    }
+    if ja_normalize::is_enabled(&config.whisper) {
+        for segment in segments.iter_mut() {
+            segment.text = ja_normalize::normalize(&config.whisper, &segment.text);
+        }
+    }
+    let glossary = Glossary::new(config);
+    if !glossary.replacements.is_empty() {
+        for segment in segments.iter_mut() {
+            segment.text = glossary.apply(&segment.text);
+            for word in segment.words.iter_mut() {
+                word.text = glossary.apply(&word.text);
+            }
+        }
+    }
+    if let Some(filter) = NgWordFilter::new(config) {
+        let mut count = 0;
+        for segment in segments.iter_mut() {
+            segment.text = filter.apply(&segment.text, &mut count);
+            for word in segment.words.iter_mut() {
+                word.text = filter.apply(&word.text, &mut 0);
+            }
+        }
+        if count > 0 {
+            log(&format!("NG words {}: {count}", filter.action()));
+        }
+    }
+}
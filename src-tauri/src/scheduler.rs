@@ -0,0 +1,144 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use chrono::{Local, NaiveTime, TimeZone};
+use tauri::{AppHandle, Manager};
+
+use crate::storage::create_source;
+use crate::{
+    effective_config, fetch_dates, fetch_meetings, finish_job, latest_output_version,
+    meeting_label, meeting_output_path, parse_date_any, register_job, run_transcription,
+    s3_retry_attempts, store, AppConfig, JobState, MeetingSummary,
+};
+
+const IDLE_CHECK_SECS: u64 = 30;
+const MIN_SCHEDULE_INTERVAL_MINS: u64 = 5;
+const DEFAULT_SCHEDULE_TIME: &str = "02:00";
+const FIRST_RUN_LOOKBACK_DAYS: i64 = 1;
+
+fn scheduled_time(config: &AppConfig) -> NaiveTime {
+    NaiveTime::parse_from_str(config.schedule_time.trim(), "%H:%M")
+        .or_else(|_| NaiveTime::parse_from_str(DEFAULT_SCHEDULE_TIME, "%H:%M"))
+        .unwrap_or(NaiveTime::MIN)
+}
+
+fn is_due(config: &AppConfig, last_run: Option<i64>) -> bool {
+    let now = Local::now();
+    match config.schedule_mode.trim() {
+        "interval" => {
+            let interval = config
+                .schedule_interval_mins
+                .max(MIN_SCHEDULE_INTERVAL_MINS) as i64
+                * 60;
+            last_run.is_none_or(|last_run| now.timestamp() - last_run >= interval)
+        }
+        "daily" => {
+            let Some(scheduled) = Local
+                .from_local_datetime(&now.date_naive().and_time(scheduled_time(config)))
+                .earliest()
+            else {
+                return false;
+            };
+            now >= scheduled && last_run.is_none_or(|last_run| last_run < scheduled.timestamp())
+        }
+        _ => false,
+    }
+}
+
+async fn pending_meetings(
+    config: &AppConfig,
+    last_run: Option<i64>,
+) -> Result<Vec<MeetingSummary>> {
+    let since = last_run
+        .and_then(|last_run| Local.timestamp_opt(last_run, 0).single())
+        .map(|last_run| last_run.date_naive())
+        .unwrap_or_else(|| {
+            Local::now().date_naive() - chrono::Duration::days(FIRST_RUN_LOOKBACK_DAYS)
+        });
+    let source = create_source(config).await?;
+    let retry_attempts = s3_retry_attempts(&config.minio);
+    let mut meetings = Vec::new();
+    for date in fetch_dates(source.as_ref(), retry_attempts).await? {
+        if parse_date_any(&date).is_none_or(|date| date < since) {
+            continue;
+        }
+        for meeting in fetch_meetings(source.as_ref(), retry_attempts, &date).await? {
+            if latest_output_version(&meeting_output_path(config, &meeting.id)?).is_none() {
+                meetings.push(meeting);
+            }
+        }
+    }
+    meetings.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(meetings)
+}
+
+async fn run_once(app: &AppHandle, config: &AppConfig, last_run: Option<i64>) -> Result<()> {
+    let started_at = Local::now().timestamp();
+    let meetings = pending_meetings(config, last_run).await?;
+    store::record_schedule_run(started_at, meetings.len())?;
+    if meetings.is_empty() {
+        return Ok(());
+    }
+    eprintln!("Scheduler: queueing {} meetings", meetings.len());
+
+    let jobs = app.state::<JobState>().inner().clone();
+    let queued: Vec<(String, MeetingSummary)> = meetings
+        .into_iter()
+        .map(|meeting| (register_job(&jobs), meeting))
+        .collect();
+    let source = create_source(config).await?;
+    for (job_id, meeting) in queued {
+        let result = run_transcription(
+            config,
+            source.clone(),
+            &meeting.id,
+            None,
+            false,
+            &job_id,
+            &jobs,
+            app,
+        )
+        .await;
+        finish_job(
+            app,
+            config,
+            &jobs,
+            &job_id,
+            &meeting_label(config, &meeting.id),
+            result,
+        )
+        .await;
+    }
+    Ok(())
+}
+
+pub(crate) fn spawn_scheduler(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(Duration::from_secs(IDLE_CHECK_SECS)).await;
+            let config = match effective_config().await {
+                Ok(config) => config,
+                Err(err) => {
+                    eprintln!("Scheduler: {err}");
+                    continue;
+                }
+            };
+            if config.schedule_mode.trim().is_empty() {
+                continue;
+            }
+            let last_run = match store::last_schedule_run() {
+                Ok(last_run) => last_run,
+                Err(err) => {
+                    eprintln!("Scheduler: {err}");
+                    continue;
+                }
+            };
+            if !is_due(&config, last_run) {
+                continue;
+            }
+            if let Err(err) = run_once(&app, &config, last_run).await {
+                eprintln!("Scheduler: {err}");
+            }
+        }
+    });
+}
@@ -0,0 +1,185 @@
+use std::path::Path;
+
+use anyhow::Result;
+use rusqlite::params;
+use serde::Serialize;
+
+use crate::{store, TranscriptionSegment};
+
+const MIN_TRIGRAM_CHARS: usize = 3;
+const DEFAULT_LIMIT: usize = 50;
+const SNIPPET_CONTEXT_CHARS: usize = 20;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SearchHit {
+    start: f64,
+    speaker: String,
+    snippet: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TranscriptMatch {
+    path: String,
+    title: String,
+    hits: Vec<SearchHit>,
+}
+
+fn title_for(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+pub(crate) async fn index_transcript(path: &Path, segments: &[TranscriptionSegment]) -> Result<()> {
+    let title = title_for(path);
+    let path = tokio::fs::canonicalize(path)
+        .await
+        .unwrap_or_else(|_| path.to_path_buf())
+        .to_string_lossy()
+        .to_string();
+    let rows: Vec<(f64, String, String)> = segments
+        .iter()
+        .map(|segment| (segment.start, segment.speaker.clone(), segment.text.clone()))
+        .collect();
+    tokio::task::spawn_blocking(move || {
+        let mut connection = store::connection()?;
+        let transaction = connection.transaction()?;
+        transaction.execute("DELETE FROM transcript_fts WHERE path = ?1", params![path])?;
+        {
+            let mut insert = transaction.prepare(
+                "INSERT INTO transcript_fts (path, title, start, speaker, text)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+            )?;
+            for (start, speaker, text) in rows {
+                insert.execute(params![path, title, start, speaker, text])?;
+            }
+        }
+        transaction.commit()?;
+        Ok(())
+    })
+    .await?
+}
+
+pub(crate) async fn remove_transcript(path: &Path) -> Result<()> {
+    let path = path.to_string_lossy().to_string();
+    tokio::task::spawn_blocking(move || {
+        store::connection()?
+            .execute("DELETE FROM transcript_fts WHERE path = ?1", params![path])?;
+        Ok(())
+    })
+    .await?
+}
+
+fn highlight(text: &str, query: &str) -> String {
+    let Some(position) = text.find(query) else {
+        return text.to_string();
+    };
+    let before: String = text[..position]
+        .chars()
+        .rev()
+        .take(SNIPPET_CONTEXT_CHARS)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect();
+    let after: String = text[position + query.len()..]
+        .chars()
+        .take(SNIPPET_CONTEXT_CHARS)
+        .collect();
+    format!("{before}<mark>{query}</mark>{after}")
+}
+
+fn search_blocking(query: &str, limit: usize) -> Result<Vec<TranscriptMatch>> {
+    let connection = store::connection()?;
+    let rows: Vec<(String, String, f64, String, String)> =
+        if query.chars().count() >= MIN_TRIGRAM_CHARS {
+            let phrase = format!("\"{}\"", query.replace('"', "\"\""));
+            let mut statement = connection.prepare(
+                "SELECT path, title, start, speaker,
+                        snippet(transcript_fts, 4, '<mark>', '</mark>', '…', 16)
+                 FROM transcript_fts WHERE transcript_fts MATCH ?1
+                 ORDER BY rank LIMIT ?2",
+            )?;
+            let rows = statement.query_map(params![phrase, limit as i64], |row| {
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                ))
+            })?;
+            rows.collect::<rusqlite::Result<_>>()?
+        } else {
+            let pattern = format!(
+                "%{}%",
+                query
+                    .replace('\\', "\\\\")
+                    .replace('%', "\\%")
+                    .replace('_', "\\_")
+            );
+            let mut statement = connection.prepare(
+                "SELECT path, title, start, speaker, text
+                 FROM transcript_fts WHERE text LIKE ?1 ESCAPE '\\'
+                 ORDER BY path, start LIMIT ?2",
+            )?;
+            let rows = statement.query_map(params![pattern, limit as i64], |row| {
+                let text: String = row.get(4)?;
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    highlight(&text, query),
+                ))
+            })?;
+            rows.collect::<rusqlite::Result<_>>()?
+        };
+
+    let mut matches: Vec<TranscriptMatch> = Vec::new();
+    for (path, title, start, speaker, snippet) in rows {
+        let hit = SearchHit {
+            start,
+            speaker,
+            snippet,
+        };
+        match matches.iter_mut().find(|entry| entry.path == path) {
+            Some(entry) => entry.hits.push(hit),
+            None => matches.push(TranscriptMatch {
+                path,
+                title,
+                hits: vec![hit],
+            }),
+        }
+    }
+    for entry in matches.iter_mut() {
+        entry.hits.sort_by(|a, b| {
+            a.start
+                .partial_cmp(&b.start)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+    }
+    Ok(matches)
+}
+
+#[tauri::command]
+pub async fn search_transcripts(
+    query: String,
+    limit: Option<usize>,
+) -> Result<Vec<TranscriptMatch>, String> {
+    let query = query.trim().to_string();
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+    let limit = limit.filter(|value| *value > 0).unwrap_or(DEFAULT_LIMIT);
+    let matches = tokio::task::spawn_blocking(move || search_blocking(&query, limit))
+        .await
+        .map_err(|err| err.to_string())?
+        .map_err(|err| err.to_string())?;
+    Ok(matches
+        .into_iter()
+        .filter(|entry| Path::new(&entry.path).is_file())
+        .collect())
+}
@@ -0,0 +1,157 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use tokio::fs;
+use tokio::process::Command;
+
+use crate::{WhisperSegment, SILENCE_NOISE_DB};
+
+const SILENCE_PADDING_SECS: f64 = 0.25;
+
+pub(crate) struct SilenceMap {
+    removed: Vec<(f64, f64)>,
+}
+
+impl SilenceMap {
+    pub(crate) fn removed_secs(&self) -> f64 {
+        self.removed.iter().map(|(_, length)| length).sum()
+    }
+
+    pub(crate) fn spans(&self) -> usize {
+        self.removed.len()
+    }
+
+    fn to_original(&self, seconds: f64) -> f64 {
+        let mut offset = 0.0;
+        for (position, length) in &self.removed {
+            if seconds < *position {
+                break;
+            }
+            offset += length;
+        }
+        seconds + offset
+    }
+
+    pub(crate) fn restore(&self, segments: &mut [WhisperSegment]) {
+        for segment in segments.iter_mut() {
+            segment.start = self.to_original(segment.start);
+            segment.end = segment.end.map(|end| self.to_original(end));
+            for word in segment.words.iter_mut() {
+                word.start = self.to_original(word.start);
+                word.end = self.to_original(word.end);
+            }
+        }
+    }
+}
+
+async fn detect_silences(
+    ffmpeg_path: &Path,
+    input: &Path,
+    min_secs: f64,
+) -> Result<Vec<(f64, f64)>> {
+    let result = Command::new(ffmpeg_path)
+        .arg("-nostdin")
+        .arg("-i")
+        .arg(input)
+        .arg("-af")
+        .arg(format!(
+            "silencedetect=noise={SILENCE_NOISE_DB}dB:d={min_secs}"
+        ))
+        .arg("-f")
+        .arg("null")
+        .arg("-")
+        .output()
+        .await
+        .with_context(|| format!("Failed to execute ffmpeg: {}", ffmpeg_path.display()))?;
+    if !result.status.success() {
+        return Err(anyhow!("ffmpeg silencedetect failed"));
+    }
+    let stderr = String::from_utf8_lossy(&result.stderr);
+    let mut spans = Vec::new();
+    let mut start = None;
+    for line in stderr.lines() {
+        if let Some((_, rest)) = line.split_once("silence_start:") {
+            start = rest.trim().parse::<f64>().ok();
+        }
+        if let Some((_, rest)) = line.split_once("silence_end:") {
+            let end = rest
+                .split('|')
+                .next()
+                .and_then(|value| value.trim().parse::<f64>().ok());
+            if let (Some(start), Some(end)) = (start.take(), end) {
+                spans.push((start.max(0.0), end));
+            }
+        }
+    }
+    Ok(spans)
+}
+
+pub(crate) async fn speech_ratio(
+    ffmpeg_path: &Path,
+    input: &Path,
+    duration: f64,
+    min_secs: f64,
+) -> Result<f64> {
+    let silence: f64 = detect_silences(ffmpeg_path, input, min_secs)
+        .await?
+        .iter()
+        .map(|(start, end)| end - start)
+        .sum();
+    Ok((1.0 - silence / duration).clamp(0.0, 1.0))
+}
+
+pub(crate) async fn trim(
+    ffmpeg_path: &Path,
+    input: &Path,
+    output: &Path,
+    min_secs: f64,
+) -> Result<Option<SilenceMap>> {
+    let spans: Vec<(f64, f64)> = detect_silences(ffmpeg_path, input, min_secs)
+        .await?
+        .into_iter()
+        .map(|(start, end)| (start + SILENCE_PADDING_SECS, end - SILENCE_PADDING_SECS))
+        .filter(|(start, end)| end > start)
+        .collect();
+    if spans.is_empty() {
+        return Ok(None);
+    }
+
+    let conditions: Vec<String> = spans
+        .iter()
+        .map(|(start, end)| format!("between(t\\,{start:.3}\\,{end:.3})"))
+        .collect();
+    let script = output.with_extension("filter.txt");
+    fs::write(
+        &script,
+        format!("aselect=not({}),asetpts=N/SR/TB", conditions.join("+")),
+    )
+    .await?;
+    let result = Command::new(ffmpeg_path)
+        .arg("-y")
+        .arg("-nostdin")
+        .arg("-i")
+        .arg(input)
+        .arg("-filter_script:a")
+        .arg(&script)
+        .arg("-ar")
+        .arg("16000")
+        .arg("-ac")
+        .arg("1")
+        .arg(output)
+        .output()
+        .await
+        .with_context(|| format!("Failed to execute ffmpeg: {}", ffmpeg_path.display()))?;
+    let _ = fs::remove_file(&script).await;
+    if !result.status.success() {
+        let stderr = String::from_utf8_lossy(&result.stderr);
+        return Err(anyhow!("ffmpeg failed to trim silence: {}", stderr.trim()));
+    }
+
+    let mut removed = Vec::with_capacity(spans.len());
+    let mut total = 0.0;
+    for (start, end) in spans {
+        removed.push((start - total, end - start));
+        total += end - start;
+    }
+    Ok(Some(SilenceMap { removed }))
+}
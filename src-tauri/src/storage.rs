@@ -0,0 +1,1235 @@
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context, Result};
+use async_trait::async_trait;
+use aws_sdk_s3::presigning::PresigningConfig;
+use aws_sdk_s3::primitives::ByteStream;
+use aws_sdk_s3::types::{Delete, ObjectIdentifier};
+use aws_sdk_s3::Client;
+use base64::engine::general_purpose::STANDARD_NO_PAD;
+use base64::Engine;
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use serde::Serialize;
+use ssh2::{HashType, Session, Sftp};
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+
+use crate::azure::AzureBlobSource;
+use crate::gcs::GcsSource;
+use crate::gdrive::GoogleDriveSource;
+use crate::models::download_client;
+use crate::{format_sdk_error, project_dirs, s3_client, AppConfig, MinioConfig};
+
+pub(crate) const DOWNLOAD_PROGRESS_STEP: u64 = 16 * 1024 * 1024;
+
+const PROBE_URL_EXPIRY: std::time::Duration = std::time::Duration::from_secs(5 * 60);
+
+const DELETE_OBJECTS_BATCH_SIZE: usize = 1000;
+
+pub(crate) struct ObjectInfo {
+    pub(crate) key: String,
+    pub(crate) size: Option<u64>,
+    pub(crate) etag: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DeleteFailure {
+    pub(crate) key: String,
+    pub(crate) message: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DeleteResult {
+    pub(crate) deleted: Vec<String>,
+    pub(crate) failed: Vec<DeleteFailure>,
+}
+
+pub(crate) type ProgressCallback<'a> = &'a (dyn Fn(u64, Option<u64>) + Send + Sync);
+
+#[async_trait]
+pub(crate) trait StorageSource: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    async fn check(&self) -> Result<()>;
+
+    async fn list_prefixes(&self, prefix: &str) -> Result<Vec<String>>;
+
+    async fn list_objects(&self, prefix: &str) -> Result<Vec<String>>;
+
+    async fn get_object(
+        &self,
+        key: &str,
+        dest: &Path,
+        on_progress: ProgressCallback<'_>,
+    ) -> Result<()>;
+
+    async fn list_object_info(&self, prefix: &str) -> Result<Vec<ObjectInfo>> {
+        Ok(self
+            .list_objects(prefix)
+            .await?
+            .into_iter()
+            .map(|key| ObjectInfo {
+                key,
+                size: None,
+                etag: None,
+            })
+            .collect())
+    }
+
+    async fn probe_location(&self, _key: &str) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    async fn put_object(&self, key: &str, _source: &Path) -> Result<()> {
+        Err(anyhow!(
+            "Uploading {key} is not supported by the {} source",
+            self.name()
+        ))
+    }
+
+    async fn presigned_url(&self, _key: &str, _expires_in: std::time::Duration) -> Result<String> {
+        Err(anyhow!(
+            "Presigned URLs are not supported by the {} source",
+            self.name()
+        ))
+    }
+
+    async fn delete_objects(&self, _keys: &[String]) -> Result<DeleteResult> {
+        Err(anyhow!(
+            "Deleting objects is not supported by the {} source",
+            self.name()
+        ))
+    }
+}
+
+pub(crate) async fn write_response(
+    mut response: reqwest::Response,
+    key: &str,
+    dest: &Path,
+    on_progress: ProgressCallback<'_>,
+) -> Result<()> {
+    let status = response.status();
+    if !status.is_success() {
+        return Err(anyhow!("Failed to download {key}: {status}"));
+    }
+    let total = response.content_length();
+    let mut file = fs::File::create(dest)
+        .await
+        .with_context(|| format!("Failed to write file: {}", dest.display()))?;
+    let mut downloaded = 0u64;
+    let mut next_report = DOWNLOAD_PROGRESS_STEP;
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .with_context(|| "Failed to read object stream")?
+    {
+        file.write_all(&chunk)
+            .await
+            .with_context(|| format!("Failed to write file: {}", dest.display()))?;
+        downloaded += chunk.len() as u64;
+        if downloaded >= next_report {
+            on_progress(downloaded, total);
+            next_report = downloaded + DOWNLOAD_PROGRESS_STEP;
+        }
+    }
+    file.flush().await?;
+    on_progress(downloaded, total);
+    Ok(())
+}
+
+pub(crate) async fn create_source(config: &AppConfig) -> Result<Arc<dyn StorageSource>> {
+    match config.minio.provider.trim() {
+        "local" => Ok(Arc::new(LocalSource::from_config(config)?)),
+        "sftp" => Ok(Arc::new(SftpSource::from_config(&config.minio)?)),
+        "webdav" => Ok(Arc::new(WebDavSource::from_config(config)?)),
+        "gdrive" => Ok(Arc::new(GoogleDriveSource::from_config(config)?)),
+        "azure" => Ok(Arc::new(AzureBlobSource::from_config(config)?)),
+        "gcs" => Ok(Arc::new(GcsSource::from_config(config).await?)),
+        _ => Ok(Arc::new(S3Source::new(config).await?)),
+    }
+}
+
+struct S3Source {
+    client: Client,
+    bucket: String,
+    key_root: String,
+}
+
+impl S3Source {
+    async fn new(config: &AppConfig) -> Result<Self> {
+        Ok(Self {
+            client: s3_client(config).await?,
+            bucket: config.minio.bucket.clone(),
+            key_root: config.minio.key_root(),
+        })
+    }
+
+    fn relative_key<'a>(&self, key: &'a str) -> &'a str {
+        key.strip_prefix(self.key_root.as_str()).unwrap_or(key)
+    }
+}
+
+#[async_trait]
+impl StorageSource for S3Source {
+    fn name(&self) -> &'static str {
+        "s3"
+    }
+
+    async fn check(&self) -> Result<()> {
+        self.client
+            .list_objects_v2()
+            .bucket(&self.bucket)
+            .prefix(self.key_root.clone())
+            .max_keys(1)
+            .send()
+            .await
+            .map_err(|err| anyhow!(format_sdk_error(err)))?;
+        Ok(())
+    }
+
+    async fn list_prefixes(&self, prefix: &str) -> Result<Vec<String>> {
+        let full_prefix = format!("{}{prefix}", self.key_root);
+        let mut prefixes = Vec::new();
+        let mut continuation: Option<String> = None;
+        loop {
+            let mut req = self
+                .client
+                .list_objects_v2()
+                .bucket(&self.bucket)
+                .prefix(full_prefix.clone())
+                .delimiter("/");
+            if let Some(token) = &continuation {
+                req = req.continuation_token(token);
+            }
+            let resp = req
+                .send()
+                .await
+                .map_err(|err| anyhow!(format_sdk_error(err)))?;
+
+            for common_prefix in resp.common_prefixes() {
+                if let Some(value) = common_prefix.prefix() {
+                    let value = value.strip_prefix(full_prefix.as_str()).unwrap_or(value);
+                    let trimmed = value.trim_end_matches('/');
+                    if !trimmed.is_empty() {
+                        prefixes.push(trimmed.to_string());
+                    }
+                }
+            }
+
+            if resp.is_truncated().unwrap_or(false) {
+                continuation = resp.next_continuation_token().map(|s| s.to_string());
+                if continuation.is_none() {
+                    break;
+                }
+            } else {
+                break;
+            }
+        }
+        Ok(prefixes)
+    }
+
+    async fn list_objects(&self, prefix: &str) -> Result<Vec<String>> {
+        Ok(self
+            .list_object_info(prefix)
+            .await?
+            .into_iter()
+            .map(|info| info.key)
+            .collect())
+    }
+
+    async fn list_object_info(&self, prefix: &str) -> Result<Vec<ObjectInfo>> {
+        let full_prefix = format!("{}{prefix}", self.key_root);
+        let mut objects = Vec::new();
+        let mut continuation: Option<String> = None;
+        loop {
+            let mut req = self
+                .client
+                .list_objects_v2()
+                .bucket(&self.bucket)
+                .prefix(full_prefix.clone());
+            if let Some(token) = &continuation {
+                req = req.continuation_token(token);
+            }
+            let resp = req
+                .send()
+                .await
+                .map_err(|err| anyhow!(format_sdk_error(err)))?;
+
+            for object in resp.contents() {
+                if let Some(key) = object.key() {
+                    objects.push(ObjectInfo {
+                        key: self.relative_key(key).to_string(),
+                        size: object.size().and_then(|size| u64::try_from(size).ok()),
+                        etag: object.e_tag().map(str::to_string),
+                    });
+                }
+            }
+
+            if resp.is_truncated().unwrap_or(false) {
+                continuation = resp.next_continuation_token().map(|s| s.to_string());
+                if continuation.is_none() {
+                    break;
+                }
+            } else {
+                break;
+            }
+        }
+        Ok(objects)
+    }
+
+    async fn probe_location(&self, key: &str) -> Result<Option<String>> {
+        self.presigned_url(key, PROBE_URL_EXPIRY).await.map(Some)
+    }
+
+    async fn presigned_url(&self, key: &str, expires_in: std::time::Duration) -> Result<String> {
+        let presigning = PresigningConfig::expires_in(expires_in)?;
+        let request = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(format!("{}{key}", self.key_root))
+            .presigned(presigning)
+            .await
+            .map_err(|err| anyhow!(format_sdk_error(err)))?;
+        Ok(request.uri().to_string())
+    }
+
+    async fn get_object(
+        &self,
+        key: &str,
+        dest: &Path,
+        on_progress: ProgressCallback<'_>,
+    ) -> Result<()> {
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        let mut obj = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(format!("{}{key}", self.key_root))
+            .send()
+            .await
+            .with_context(|| format!("Failed to download {key}"))?;
+        let total = obj
+            .content_length()
+            .and_then(|length| u64::try_from(length).ok());
+        let mut file = fs::File::create(dest)
+            .await
+            .with_context(|| format!("Failed to write file: {}", dest.display()))?;
+        let mut downloaded = 0u64;
+        let mut next_report = DOWNLOAD_PROGRESS_STEP;
+        while let Some(chunk) = obj
+            .body
+            .try_next()
+            .await
+            .with_context(|| "Failed to read object stream")?
+        {
+            file.write_all(&chunk)
+                .await
+                .with_context(|| format!("Failed to write file: {}", dest.display()))?;
+            downloaded += chunk.len() as u64;
+            if downloaded >= next_report {
+                on_progress(downloaded, total);
+                next_report = downloaded + DOWNLOAD_PROGRESS_STEP;
+            }
+        }
+        file.flush().await?;
+        on_progress(downloaded, total);
+        Ok(())
+    }
+
+    async fn put_object(&self, key: &str, source: &Path) -> Result<()> {
+        let body = ByteStream::from_path(source)
+            .await
+            .with_context(|| format!("Failed to read {}", source.display()))?;
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(format!("{}{key}", self.key_root))
+            .body(body)
+            .send()
+            .await
+            .map_err(|err| anyhow!(format_sdk_error(err)))?;
+        Ok(())
+    }
+
+    async fn delete_objects(&self, keys: &[String]) -> Result<DeleteResult> {
+        let mut result = DeleteResult::default();
+        for batch in keys.chunks(DELETE_OBJECTS_BATCH_SIZE) {
+            let objects = batch
+                .iter()
+                .map(|key| {
+                    ObjectIdentifier::builder()
+                        .key(format!("{}{key}", self.key_root))
+                        .build()
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let delete = Delete::builder().set_objects(Some(objects)).build()?;
+            let resp = self
+                .client
+                .delete_objects()
+                .bucket(&self.bucket)
+                .delete(delete)
+                .send()
+                .await
+                .map_err(|err| anyhow!(format_sdk_error(err)))?;
+            for object in resp.deleted() {
+                if let Some(key) = object.key() {
+                    result.deleted.push(self.relative_key(key).to_string());
+                }
+            }
+            for error in resp.errors() {
+                result.failed.push(DeleteFailure {
+                    key: self
+                        .relative_key(error.key().unwrap_or_default())
+                        .to_string(),
+                    message: format!(
+                        "{} {}",
+                        error.code().unwrap_or_default(),
+                        error.message().unwrap_or_default()
+                    )
+                    .trim()
+                    .to_string(),
+                });
+            }
+        }
+        Ok(result)
+    }
+}
+
+struct LocalSource {
+    root: PathBuf,
+}
+
+impl LocalSource {
+    fn from_config(config: &AppConfig) -> Result<Self> {
+        let root = config.minio.local_root.trim();
+        if root.is_empty() {
+            return Err(anyhow!("Local source root directory is not configured"));
+        }
+        Ok(Self {
+            root: PathBuf::from(root),
+        })
+    }
+
+    fn resolve(&self, key: &str) -> Result<PathBuf> {
+        let mut path = self.root.clone();
+        for part in key.split('/').filter(|part| !part.is_empty()) {
+            if part == "." || part == ".." {
+                return Err(anyhow!("Invalid key: {key}"));
+            }
+            path.push(part);
+        }
+        Ok(path)
+    }
+}
+
+fn collect_files(dir: &Path, relative: &str, keys: &mut Vec<String>) -> std::io::Result<()> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err),
+    };
+    for entry in entries {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        let key = format!("{relative}{name}");
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            collect_files(&entry.path(), &format!("{key}/"), keys)?;
+        } else if file_type.is_file() {
+            keys.push(key);
+        }
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl StorageSource for LocalSource {
+    fn name(&self) -> &'static str {
+        "local"
+    }
+
+    async fn check(&self) -> Result<()> {
+        if !self.root.is_dir() {
+            return Err(anyhow!(
+                "Local source root is not a directory: {}",
+                self.root.display()
+            ));
+        }
+        Ok(())
+    }
+
+    async fn list_prefixes(&self, prefix: &str) -> Result<Vec<String>> {
+        let dir = self.resolve(prefix)?;
+        let mut prefixes = Vec::new();
+        let mut entries = match fs::read_dir(&dir).await {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(prefixes),
+            Err(err) => {
+                return Err(err).with_context(|| format!("Failed to read {}", dir.display()))
+            }
+        };
+        while let Some(entry) = entries.next_entry().await? {
+            if entry.file_type().await?.is_dir() {
+                prefixes.push(entry.file_name().to_string_lossy().to_string());
+            }
+        }
+        prefixes.sort();
+        Ok(prefixes)
+    }
+
+    async fn list_objects(&self, prefix: &str) -> Result<Vec<String>> {
+        let dir = self.resolve(prefix)?;
+        let relative = prefix.trim_start_matches('/').to_string();
+        let relative = if relative.is_empty() || relative.ends_with('/') {
+            relative
+        } else {
+            format!("{relative}/")
+        };
+        let keys = tokio::task::spawn_blocking(move || {
+            let mut keys = Vec::new();
+            collect_files(&dir, &relative, &mut keys).map(|_| keys)
+        })
+        .await??;
+        Ok(keys)
+    }
+
+    async fn list_object_info(&self, prefix: &str) -> Result<Vec<ObjectInfo>> {
+        let mut objects = Vec::new();
+        for key in self.list_objects(prefix).await? {
+            let meta = fs::metadata(self.resolve(&key)?).await.ok();
+            let etag = meta.as_ref().and_then(|meta| {
+                let modified = meta
+                    .modified()
+                    .ok()?
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .ok()?;
+                Some(format!("{key}:{}-{}", meta.len(), modified.as_nanos()))
+            });
+            objects.push(ObjectInfo {
+                key,
+                size: meta.map(|meta| meta.len()),
+                etag,
+            });
+        }
+        Ok(objects)
+    }
+
+    async fn probe_location(&self, key: &str) -> Result<Option<String>> {
+        Ok(Some(self.resolve(key)?.to_string_lossy().to_string()))
+    }
+
+    async fn get_object(
+        &self,
+        key: &str,
+        dest: &Path,
+        on_progress: ProgressCallback<'_>,
+    ) -> Result<()> {
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        let source = self.resolve(key)?;
+        let copied = fs::copy(&source, dest)
+            .await
+            .with_context(|| format!("Failed to copy {}", source.display()))?;
+        on_progress(copied, Some(copied));
+        Ok(())
+    }
+
+    async fn put_object(&self, key: &str, source: &Path) -> Result<()> {
+        let dest = self.resolve(key)?;
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        fs::copy(source, &dest)
+            .await
+            .with_context(|| format!("Failed to write file: {}", dest.display()))?;
+        Ok(())
+    }
+
+    async fn delete_objects(&self, keys: &[String]) -> Result<DeleteResult> {
+        let mut result = DeleteResult::default();
+        for key in keys {
+            let removed = match self.resolve(key) {
+                Ok(path) => fs::remove_file(&path).await.map_err(anyhow::Error::from),
+                Err(err) => Err(err),
+            };
+            match removed {
+                Ok(()) => result.deleted.push(key.clone()),
+                Err(err) => result.failed.push(DeleteFailure {
+                    key: key.clone(),
+                    message: err.to_string(),
+                }),
+            }
+        }
+        Ok(result)
+    }
+}
+
+const DEFAULT_SFTP_PORT: u16 = 22;
+const SFTP_TIMEOUT_MS: u32 = 30_000;
+
+#[derive(Clone)]
+struct SftpSource {
+    host: String,
+    port: u16,
+    username: String,
+    password: String,
+    private_key_path: String,
+    host_fingerprint: String,
+    root: String,
+}
+
+impl SftpSource {
+    fn from_config(minio: &MinioConfig) -> Result<Self> {
+        let host = minio.sftp_host.trim();
+        let username = minio.sftp_username.trim();
+        if host.is_empty() || username.is_empty() {
+            return Err(anyhow!("SFTP config is incomplete"));
+        }
+        Ok(Self {
+            host: host.to_string(),
+            port: minio.sftp_port.unwrap_or(DEFAULT_SFTP_PORT),
+            username: username.to_string(),
+            password: minio.sftp_password.clone(),
+            private_key_path: minio.sftp_private_key_path.trim().to_string(),
+            host_fingerprint: minio.sftp_host_fingerprint.trim().to_string(),
+            root: minio.sftp_root.trim().trim_end_matches('/').to_string(),
+        })
+    }
+
+    fn remote_path(&self, key: &str) -> String {
+        let key = key.trim_matches('/');
+        match (self.root.is_empty(), key.is_empty()) {
+            (true, true) => ".".to_string(),
+            (true, false) => key.to_string(),
+            (false, true) => self.root.clone(),
+            (false, false) => format!("{}/{key}", self.root),
+        }
+    }
+
+    fn connect(&self) -> Result<Sftp> {
+        let tcp = TcpStream::connect((self.host.as_str(), self.port))
+            .with_context(|| format!("Failed to connect to {}:{}", self.host, self.port))?;
+        let mut session = Session::new()?;
+        session.set_timeout(SFTP_TIMEOUT_MS);
+        session.set_tcp_stream(tcp);
+        session
+            .handshake()
+            .with_context(|| format!("SSH handshake with {} failed", self.host))?;
+        self.verify_host_key(&session)?;
+
+        if !self.private_key_path.is_empty() {
+            let passphrase = (!self.password.is_empty()).then_some(self.password.as_str());
+            session
+                .userauth_pubkey_file(
+                    &self.username,
+                    None,
+                    Path::new(&self.private_key_path),
+                    passphrase,
+                )
+                .with_context(|| format!("SFTP key authentication failed for {}", self.username))?;
+        } else if !self.password.is_empty() {
+            session
+                .userauth_password(&self.username, &self.password)
+                .with_context(|| {
+                    format!("SFTP password authentication failed for {}", self.username)
+                })?;
+        } else {
+            session.userauth_agent(&self.username).with_context(|| {
+                format!("SFTP agent authentication failed for {}", self.username)
+            })?;
+        }
+        if !session.authenticated() {
+            return Err(anyhow!("SFTP authentication failed for {}", self.username));
+        }
+        Ok(session.sftp()?)
+    }
+
+    fn verify_host_key(&self, session: &Session) -> Result<()> {
+        let hash = session
+            .host_key_hash(HashType::Sha256)
+            .ok_or_else(|| anyhow!("SFTP server did not provide a host key"))?;
+        let actual = format!("SHA256:{}", STANDARD_NO_PAD.encode(hash));
+        if !self.host_fingerprint.is_empty() {
+            if !fingerprint_matches(&self.host_fingerprint, hash) {
+                return Err(anyhow!(
+                    "SFTP host key mismatch for {}: expected {}, got {actual}",
+                    self.host,
+                    self.host_fingerprint
+                ));
+            }
+            return Ok(());
+        }
+
+        let host = format!("{}:{}", self.host, self.port);
+        let path = known_hosts_path()?;
+        let mut known_hosts = load_known_hosts(&path);
+        match known_hosts.get(&host) {
+            Some(known) if *known == actual => Ok(()),
+            Some(known) => Err(anyhow!(
+                "SFTP host key for {host} changed: previously {known}, now {actual}. \
+                 If the server was re-keyed, remove it from {} or pin the new fingerprint.",
+                path.display()
+            )),
+            None => {
+                eprintln!("Trusting SFTP host key for {host} on first use: {actual}");
+                known_hosts.insert(host, actual);
+                save_known_hosts(&path, &known_hosts)
+            }
+        }
+    }
+
+    async fn run<T, F>(&self, op: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Self, &Sftp) -> Result<T> + Send + 'static,
+    {
+        let source = self.clone();
+        tokio::task::spawn_blocking(move || {
+            let sftp = source.connect()?;
+            op(&source, &sftp)
+        })
+        .await?
+    }
+}
+
+fn fingerprint_matches(pinned: &str, hash: &[u8]) -> bool {
+    let pinned = pinned.trim();
+    if let Some(encoded) = pinned.strip_prefix("SHA256:") {
+        return encoded.trim_end_matches('=') == STANDARD_NO_PAD.encode(hash);
+    }
+    let hex: String = hash.iter().map(|byte| format!("{byte:02x}")).collect();
+    pinned.replace(':', "").to_ascii_lowercase() == hex
+}
+
+fn known_hosts_path() -> Result<PathBuf> {
+    Ok(project_dirs()?.data_dir().join("sftp_known_hosts.json"))
+}
+
+fn load_known_hosts(path: &Path) -> std::collections::HashMap<String, String> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default()
+}
+
+fn save_known_hosts(path: &Path, hosts: &std::collections::HashMap<String, String>) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, serde_json::to_string_pretty(hosts)?)
+        .with_context(|| format!("Failed to save SFTP known hosts: {}", path.display()))
+}
+
+fn collect_remote_files(
+    sftp: &Sftp,
+    dir: &str,
+    relative: &str,
+    keys: &mut Vec<String>,
+) -> Result<()> {
+    let entries = sftp
+        .readdir(Path::new(dir))
+        .with_context(|| format!("Failed to list {dir}"))?;
+    for (path, stat) in entries {
+        let Some(name) = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+        else {
+            continue;
+        };
+        if name == "." || name == ".." {
+            continue;
+        }
+        let key = format!("{relative}{name}");
+        if stat.is_dir() {
+            collect_remote_files(sftp, &format!("{dir}/{name}"), &format!("{key}/"), keys)?;
+        } else if stat.is_file() {
+            keys.push(key);
+        }
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl StorageSource for SftpSource {
+    fn name(&self) -> &'static str {
+        "sftp"
+    }
+
+    async fn check(&self) -> Result<()> {
+        self.run(|source, sftp| {
+            let root = source.remote_path("");
+            sftp.stat(Path::new(&root))
+                .with_context(|| format!("SFTP root not found: {root}"))?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn list_prefixes(&self, prefix: &str) -> Result<Vec<String>> {
+        let prefix = prefix.to_string();
+        self.run(move |source, sftp| {
+            let dir = source.remote_path(&prefix);
+            let mut prefixes = Vec::new();
+            for (path, stat) in sftp
+                .readdir(Path::new(&dir))
+                .with_context(|| format!("Failed to list {dir}"))?
+            {
+                if !stat.is_dir() {
+                    continue;
+                }
+                if let Some(name) = path.file_name() {
+                    let name = name.to_string_lossy().to_string();
+                    if name != "." && name != ".." {
+                        prefixes.push(name);
+                    }
+                }
+            }
+            prefixes.sort();
+            Ok(prefixes)
+        })
+        .await
+    }
+
+    async fn list_objects(&self, prefix: &str) -> Result<Vec<String>> {
+        let prefix = prefix.to_string();
+        self.run(move |source, sftp| {
+            let relative = prefix.trim_start_matches('/').to_string();
+            let relative = if relative.is_empty() || relative.ends_with('/') {
+                relative
+            } else {
+                format!("{relative}/")
+            };
+            let dir = source.remote_path(&prefix);
+            let mut keys = Vec::new();
+            if sftp.stat(Path::new(&dir)).is_ok() {
+                collect_remote_files(sftp, &dir, &relative, &mut keys)?;
+            }
+            Ok(keys)
+        })
+        .await
+    }
+
+    async fn get_object(
+        &self,
+        key: &str,
+        dest: &Path,
+        on_progress: ProgressCallback<'_>,
+    ) -> Result<()> {
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        let (progress_tx, mut progress_rx) = tokio::sync::mpsc::unbounded_channel();
+        let remote = self.remote_path(key);
+        let dest_path = dest.to_path_buf();
+        let download = self.run(move |_, sftp| {
+            let mut remote_file = sftp
+                .open(Path::new(&remote))
+                .with_context(|| format!("Failed to download {remote}"))?;
+            let total = remote_file.stat().ok().and_then(|stat| stat.size);
+            let mut file = std::fs::File::create(&dest_path)
+                .with_context(|| format!("Failed to write file: {}", dest_path.display()))?;
+            let mut buffer = vec![0u8; 256 * 1024];
+            let mut downloaded = 0u64;
+            let mut next_report = DOWNLOAD_PROGRESS_STEP;
+            loop {
+                let read = remote_file
+                    .read(&mut buffer)
+                    .with_context(|| format!("Failed to read {remote}"))?;
+                if read == 0 {
+                    break;
+                }
+                file.write_all(&buffer[..read])
+                    .with_context(|| format!("Failed to write file: {}", dest_path.display()))?;
+                downloaded += read as u64;
+                if downloaded >= next_report {
+                    let _ = progress_tx.send((downloaded, total));
+                    next_report = downloaded + DOWNLOAD_PROGRESS_STEP;
+                }
+            }
+            file.flush()?;
+            let _ = progress_tx.send((downloaded, total));
+            Ok(())
+        });
+        let report = async {
+            while let Some((downloaded, total)) = progress_rx.recv().await {
+                on_progress(downloaded, total);
+            }
+        };
+        let (result, _) = tokio::join!(download, report);
+        result
+    }
+
+    async fn put_object(&self, key: &str, source: &Path) -> Result<()> {
+        let remote = self.remote_path(key);
+        let local = source.to_path_buf();
+        self.run(move |_, sftp| {
+            if let Some((parent, _)) = remote.rsplit_once('/') {
+                let mut dir = String::new();
+                for part in parent.split('/').filter(|part| !part.is_empty()) {
+                    if !dir.is_empty() || remote.starts_with('/') {
+                        dir.push('/');
+                    }
+                    dir.push_str(part);
+                    if sftp.stat(Path::new(&dir)).is_err() {
+                        sftp.mkdir(Path::new(&dir), 0o755)
+                            .with_context(|| format!("Failed to create {dir}"))?;
+                    }
+                }
+            }
+            let mut input = std::fs::File::open(&local)
+                .with_context(|| format!("Failed to read {}", local.display()))?;
+            let mut remote_file = sftp
+                .create(Path::new(&remote))
+                .with_context(|| format!("Failed to upload {remote}"))?;
+            std::io::copy(&mut input, &mut remote_file)
+                .with_context(|| format!("Failed to upload {remote}"))?;
+            Ok(())
+        })
+        .await
+    }
+}
+
+pub(crate) const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+const PROPFIND_BODY: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:propfind xmlns:d="DAV:"><d:prop><d:resourcetype/><d:getcontentlength/></d:prop></d:propfind>"#;
+
+struct DavEntry {
+    path: String,
+    is_dir: bool,
+}
+
+struct WebDavSource {
+    client: reqwest::Client,
+    base_url: String,
+    base_path: String,
+    username: String,
+    password: String,
+}
+
+impl WebDavSource {
+    fn from_config(config: &AppConfig) -> Result<Self> {
+        let minio = &config.minio;
+        let base_url = minio.webdav_url.trim().trim_end_matches('/');
+        if base_url.is_empty() {
+            return Err(anyhow!("WebDAV URL is not configured"));
+        }
+        let parsed = reqwest::Url::parse(base_url)
+            .with_context(|| format!("Invalid WebDAV URL: {base_url}"))?;
+        let base_path = percent_decode_str(parsed.path())
+            .decode_utf8_lossy()
+            .trim_end_matches('/')
+            .to_string();
+        Ok(Self {
+            client: download_client(&config.proxy_url)?,
+            base_url: base_url.to_string(),
+            base_path,
+            username: minio.webdav_username.trim().to_string(),
+            password: minio.webdav_password.clone(),
+        })
+    }
+
+    fn url(&self, key: &str) -> String {
+        let mut url = self.base_url.clone();
+        for segment in key.split('/').filter(|segment| !segment.is_empty()) {
+            url.push('/');
+            url.push_str(&utf8_percent_encode(segment, PATH_SEGMENT).to_string());
+        }
+        if key.is_empty() || key.ends_with('/') {
+            url.push('/');
+        }
+        url
+    }
+
+    fn request(&self, method: reqwest::Method, url: &str) -> reqwest::RequestBuilder {
+        let request = self.client.request(method, url);
+        if self.username.is_empty() {
+            request
+        } else {
+            request.basic_auth(&self.username, Some(&self.password))
+        }
+    }
+
+    async fn propfind(&self, key: &str) -> Result<Vec<DavEntry>> {
+        let url = self.url(key);
+        let method = reqwest::Method::from_bytes(b"PROPFIND")?;
+        let response = self
+            .request(method, &url)
+            .header("Depth", "1")
+            .header(reqwest::header::CONTENT_TYPE, "application/xml")
+            .body(PROPFIND_BODY)
+            .send()
+            .await
+            .with_context(|| format!("Failed to list {url}"))?;
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(Vec::new());
+        }
+        if !status.is_success() {
+            return Err(anyhow!("WebDAV PROPFIND {url} failed with {status}"));
+        }
+        let body = response.text().await?;
+        let self_path = format!("{}/{}", self.base_path, key.trim_matches('/'));
+        let self_path = self_path.trim_end_matches('/');
+        Ok(parse_multistatus(&body)?
+            .into_iter()
+            .filter(|entry| entry.path.trim_end_matches('/') != self_path)
+            .collect())
+    }
+
+    fn relative_key(&self, path: &str) -> String {
+        path.strip_prefix(self.base_path.as_str())
+            .unwrap_or(path)
+            .trim_matches('/')
+            .to_string()
+    }
+}
+
+fn parse_multistatus(body: &str) -> Result<Vec<DavEntry>> {
+    let mut reader = Reader::from_str(body);
+    reader.config_mut().trim_text(true);
+    let mut entries = Vec::new();
+    let mut href: Option<String> = None;
+    let mut is_dir = false;
+    let mut in_href = false;
+    loop {
+        match reader.read_event()? {
+            Event::Start(tag) | Event::Empty(tag) => match tag.local_name().as_ref() {
+                b"response" => {
+                    href = None;
+                    is_dir = false;
+                }
+                b"href" => in_href = true,
+                b"collection" => is_dir = true,
+                _ => {}
+            },
+            Event::Text(text) if in_href => {
+                let value = text.unescape()?;
+                let path = match reqwest::Url::parse(&value) {
+                    Ok(url) => url.path().to_string(),
+                    Err(_) => value.to_string(),
+                };
+                href = Some(percent_decode_str(&path).decode_utf8_lossy().to_string());
+            }
+            Event::End(tag) => match tag.local_name().as_ref() {
+                b"href" => in_href = false,
+                b"response" => {
+                    if let Some(path) = href.take() {
+                        entries.push(DavEntry { path, is_dir });
+                    }
+                }
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(entries)
+}
+
+#[async_trait]
+impl StorageSource for WebDavSource {
+    fn name(&self) -> &'static str {
+        "webdav"
+    }
+
+    async fn check(&self) -> Result<()> {
+        let url = self.url("");
+        let method = reqwest::Method::from_bytes(b"PROPFIND")?;
+        let response = self
+            .request(method, &url)
+            .header("Depth", "0")
+            .header(reqwest::header::CONTENT_TYPE, "application/xml")
+            .body(PROPFIND_BODY)
+            .send()
+            .await
+            .with_context(|| format!("Failed to connect to {url}"))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(anyhow!("WebDAV check {url} failed with {status}"));
+        }
+        Ok(())
+    }
+
+    async fn list_prefixes(&self, prefix: &str) -> Result<Vec<String>> {
+        let mut prefixes: Vec<String> = self
+            .propfind(prefix)
+            .await?
+            .into_iter()
+            .filter(|entry| entry.is_dir)
+            .filter_map(|entry| {
+                let key = self.relative_key(&entry.path);
+                key.rsplit('/').next().map(str::to_string)
+            })
+            .filter(|name| !name.is_empty())
+            .collect();
+        prefixes.sort();
+        Ok(prefixes)
+    }
+
+    async fn list_objects(&self, prefix: &str) -> Result<Vec<String>> {
+        let mut keys = Vec::new();
+        let mut pending = vec![prefix.trim_matches('/').to_string()];
+        while let Some(dir) = pending.pop() {
+            for entry in self.propfind(&format!("{dir}/")).await? {
+                let key = self.relative_key(&entry.path);
+                if key.is_empty() {
+                    continue;
+                }
+                if entry.is_dir {
+                    pending.push(key);
+                } else {
+                    keys.push(key);
+                }
+            }
+        }
+        Ok(keys)
+    }
+
+    async fn get_object(
+        &self,
+        key: &str,
+        dest: &Path,
+        on_progress: ProgressCallback<'_>,
+    ) -> Result<()> {
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        let url = self.url(key);
+        let response = self
+            .request(reqwest::Method::GET, &url)
+            .send()
+            .await
+            .with_context(|| format!("Failed to download {key}"))?;
+        write_response(response, key, dest, on_progress).await
+    }
+
+    async fn put_object(&self, key: &str, source: &Path) -> Result<()> {
+        let mut dir = String::new();
+        let parts: Vec<&str> = key.split('/').filter(|part| !part.is_empty()).collect();
+        for part in &parts[..parts.len().saturating_sub(1)] {
+            dir.push_str(part);
+            dir.push('/');
+            let method = reqwest::Method::from_bytes(b"MKCOL")?;
+            let status = self.request(method, &self.url(&dir)).send().await?.status();
+            if !status.is_success() && status != reqwest::StatusCode::METHOD_NOT_ALLOWED {
+                return Err(anyhow!("WebDAV MKCOL {dir} failed with {status}"));
+            }
+        }
+        let body = fs::read(source)
+            .await
+            .with_context(|| format!("Failed to read {}", source.display()))?;
+        let status = self
+            .request(reqwest::Method::PUT, &self.url(key))
+            .body(body)
+            .send()
+            .await
+            .with_context(|| format!("Failed to upload {key}"))?
+            .status();
+        if !status.is_success() {
+            return Err(anyhow!("Failed to upload {key}: {status}"));
+        }
+        Ok(())
+    }
+}
+
+pub(crate) struct FileSource;
+
+#[async_trait]
+impl StorageSource for FileSource {
+    fn name(&self) -> &'static str {
+        "files"
+    }
+
+    async fn check(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn list_prefixes(&self, _prefix: &str) -> Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_objects(&self, _prefix: &str) -> Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn get_object(
+        &self,
+        key: &str,
+        dest: &Path,
+        on_progress: ProgressCallback<'_>,
+    ) -> Result<()> {
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        let copied = fs::copy(key, dest)
+            .await
+            .with_context(|| format!("Failed to copy {key}"))?;
+        on_progress(copied, Some(copied));
+        Ok(())
+    }
+}
+
+pub(crate) struct UrlSource {
+    client: reqwest::Client,
+}
+
+impl UrlSource {
+    pub(crate) fn new(proxy_url: &str) -> Result<Self> {
+        Ok(Self {
+            client: download_client(proxy_url)?,
+        })
+    }
+}
+
+#[async_trait]
+impl StorageSource for UrlSource {
+    fn name(&self) -> &'static str {
+        "url"
+    }
+
+    async fn check(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn list_prefixes(&self, _prefix: &str) -> Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_objects(&self, _prefix: &str) -> Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn get_object(
+        &self,
+        key: &str,
+        dest: &Path,
+        on_progress: ProgressCallback<'_>,
+    ) -> Result<()> {
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        let response = self
+            .client
+            .get(key)
+            .send()
+            .await
+            .with_context(|| format!("Failed to download {key}"))?;
+        write_response(response, key, dest, on_progress).await
+    }
+}
@@ -0,0 +1,329 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Serialize;
+
+use crate::{project_dirs, MeetingSummary};
+
+const DEFAULT_JOB_LIMIT: usize = 100;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS meetings (
+    id TEXT PRIMARY KEY,
+    date TEXT NOT NULL,
+    room_id TEXT NOT NULL,
+    meeting_time TEXT NOT NULL,
+    track_count INTEGER NOT NULL DEFAULT 0,
+    first_seen INTEGER NOT NULL,
+    last_seen INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS jobs (
+    id TEXT PRIMARY KEY,
+    meeting_id TEXT,
+    state TEXT NOT NULL,
+    output_path TEXT,
+    previous_output TEXT,
+    error TEXT,
+    started_at INTEGER NOT NULL,
+    finished_at INTEGER
+);
+CREATE INDEX IF NOT EXISTS jobs_output_path ON jobs (output_path);
+CREATE TABLE IF NOT EXISTS job_tracks (
+    job_id TEXT NOT NULL,
+    track_key TEXT NOT NULL,
+    segments INTEGER NOT NULL,
+    PRIMARY KEY (job_id, track_key)
+);
+CREATE TABLE IF NOT EXISTS schedule_runs (
+    started_at INTEGER NOT NULL,
+    queued INTEGER NOT NULL DEFAULT 0
+);
+CREATE VIRTUAL TABLE IF NOT EXISTS transcript_fts USING fts5(
+    path UNINDEXED,
+    title UNINDEXED,
+    start UNINDEXED,
+    speaker,
+    text,
+    tokenize = 'trigram'
+);
+";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct JobRecord {
+    pub(crate) id: String,
+    pub(crate) meeting_id: Option<String>,
+    pub(crate) state: String,
+    pub(crate) output_path: Option<String>,
+    pub(crate) previous_output: Option<String>,
+    pub(crate) error: Option<String>,
+    pub(crate) started_at: i64,
+    pub(crate) finished_at: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TrackRecord {
+    track_key: String,
+    segments: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct JobDetail {
+    job: JobRecord,
+    tracks: Vec<TrackRecord>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct MeetingRecord {
+    id: String,
+    date: String,
+    room_id: String,
+    meeting_time: String,
+    track_count: usize,
+    first_seen: i64,
+    last_seen: i64,
+}
+
+fn database_path() -> Result<PathBuf> {
+    let dirs = project_dirs()?;
+    Ok(dirs.data_dir().join("transcripts.db"))
+}
+
+pub(crate) fn connection() -> Result<Connection> {
+    let path = database_path()?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let connection = Connection::open(path)?;
+    connection.busy_timeout(std::time::Duration::from_secs(5))?;
+    connection.execute_batch(SCHEMA)?;
+    Ok(connection)
+}
+
+fn now() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+pub(crate) fn record_job_started(job_id: &str) -> Result<()> {
+    connection()?.execute(
+        "INSERT OR REPLACE INTO jobs (id, state, started_at) VALUES (?1, 'running', ?2)",
+        params![job_id, now()],
+    )?;
+    Ok(())
+}
+
+pub(crate) fn record_job_meeting(job_id: &str, meeting_id: &str) -> Result<()> {
+    connection()?.execute(
+        "UPDATE jobs SET meeting_id = ?2 WHERE id = ?1",
+        params![job_id, meeting_id],
+    )?;
+    Ok(())
+}
+
+pub(crate) fn record_job_previous_output(job_id: &str, previous_output: &str) -> Result<()> {
+    connection()?.execute(
+        "UPDATE jobs SET previous_output = ?2 WHERE id = ?1",
+        params![job_id, previous_output],
+    )?;
+    Ok(())
+}
+
+pub(crate) fn record_job_finished(
+    job_id: &str,
+    state: &str,
+    output_path: Option<&str>,
+    error: Option<&str>,
+) -> Result<()> {
+    connection()?.execute(
+        "UPDATE jobs SET state = ?2, output_path = COALESCE(?3, output_path), error = ?4,
+            finished_at = ?5
+         WHERE id = ?1",
+        params![job_id, state, output_path, error, now()],
+    )?;
+    Ok(())
+}
+
+pub(crate) fn record_tracks(job_id: &str, tracks: &[(String, usize)]) -> Result<()> {
+    let mut connection = connection()?;
+    let transaction = connection.transaction()?;
+    for (track_key, segments) in tracks {
+        transaction.execute(
+            "INSERT OR REPLACE INTO job_tracks (job_id, track_key, segments)
+             VALUES (?1, ?2, ?3)",
+            params![job_id, track_key, *segments as i64],
+        )?;
+    }
+    transaction.commit()?;
+    Ok(())
+}
+
+pub(crate) fn record_meetings(meetings: &[MeetingSummary]) -> Result<()> {
+    let mut connection = connection()?;
+    let transaction = connection.transaction()?;
+    let seen = now();
+    for meeting in meetings {
+        transaction.execute(
+            "INSERT INTO meetings
+                (id, date, room_id, meeting_time, track_count, first_seen, last_seen)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?6)
+             ON CONFLICT (id) DO UPDATE SET
+                track_count = excluded.track_count,
+                last_seen = excluded.last_seen",
+            params![
+                meeting.id,
+                meeting.date,
+                meeting.room_id,
+                meeting.meeting_time,
+                meeting.track_count as i64,
+                seen
+            ],
+        )?;
+    }
+    transaction.commit()?;
+    Ok(())
+}
+
+pub(crate) fn record_schedule_run(started_at: i64, queued: usize) -> Result<()> {
+    connection()?.execute(
+        "INSERT INTO schedule_runs (started_at, queued) VALUES (?1, ?2)",
+        params![started_at, queued as i64],
+    )?;
+    Ok(())
+}
+
+pub(crate) fn last_schedule_run() -> Result<Option<i64>> {
+    Ok(
+        connection()?.query_row("SELECT MAX(started_at) FROM schedule_runs", [], |row| {
+            row.get(0)
+        })?,
+    )
+}
+
+pub(crate) fn mark_output_deleted(output_path: &str) -> Result<()> {
+    connection()?.execute(
+        "UPDATE jobs SET state = 'deleted' WHERE output_path = ?1",
+        params![output_path],
+    )?;
+    Ok(())
+}
+
+fn job_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<JobRecord> {
+    Ok(JobRecord {
+        id: row.get(0)?,
+        meeting_id: row.get(1)?,
+        state: row.get(2)?,
+        output_path: row.get(3)?,
+        previous_output: row.get(4)?,
+        error: row.get(5)?,
+        started_at: row.get(6)?,
+        finished_at: row.get(7)?,
+    })
+}
+
+const JOB_COLUMNS: &str =
+    "id, meeting_id, state, output_path, previous_output, error, started_at, finished_at";
+
+pub(crate) fn job(job_id: &str) -> Result<Option<JobRecord>> {
+    Ok(connection()?
+        .query_row(
+            &format!("SELECT {JOB_COLUMNS} FROM jobs WHERE id = ?1"),
+            params![job_id],
+            job_from_row,
+        )
+        .optional()?)
+}
+
+pub(crate) fn jobs(meeting_id: Option<&str>, limit: usize) -> Result<Vec<JobRecord>> {
+    let connection = connection()?;
+    let mut statement = connection.prepare(&format!(
+        "SELECT {JOB_COLUMNS} FROM jobs
+         WHERE ?1 IS NULL OR meeting_id = ?1
+         ORDER BY started_at DESC LIMIT ?2"
+    ))?;
+    let rows = statement.query_map(params![meeting_id, limit as i64], job_from_row)?;
+    Ok(rows.collect::<rusqlite::Result<_>>()?)
+}
+
+pub(crate) fn jobs_with_output() -> Result<Vec<JobRecord>> {
+    let connection = connection()?;
+    let mut statement = connection.prepare(&format!(
+        "SELECT {JOB_COLUMNS} FROM jobs WHERE output_path IS NOT NULL
+         ORDER BY started_at DESC"
+    ))?;
+    let rows = statement.query_map([], job_from_row)?;
+    Ok(rows.collect::<rusqlite::Result<_>>()?)
+}
+
+fn job_tracks(job_id: &str) -> Result<Vec<TrackRecord>> {
+    let connection = connection()?;
+    let mut statement = connection.prepare(
+        "SELECT track_key, segments FROM job_tracks WHERE job_id = ?1 ORDER BY track_key",
+    )?;
+    let rows = statement.query_map(params![job_id], |row| {
+        Ok(TrackRecord {
+            track_key: row.get(0)?,
+            segments: row.get::<_, i64>(1)? as usize,
+        })
+    })?;
+    Ok(rows.collect::<rusqlite::Result<_>>()?)
+}
+
+fn meetings() -> Result<Vec<MeetingRecord>> {
+    let connection = connection()?;
+    let mut statement = connection.prepare(
+        "SELECT id, date, room_id, meeting_time, track_count, first_seen, last_seen
+         FROM meetings ORDER BY date DESC, meeting_time DESC",
+    )?;
+    let rows = statement.query_map([], |row| {
+        Ok(MeetingRecord {
+            id: row.get(0)?,
+            date: row.get(1)?,
+            room_id: row.get(2)?,
+            meeting_time: row.get(3)?,
+            track_count: row.get::<_, i64>(4)? as usize,
+            first_seen: row.get(5)?,
+            last_seen: row.get(6)?,
+        })
+    })?;
+    Ok(rows.collect::<rusqlite::Result<_>>()?)
+}
+
+#[tauri::command]
+pub async fn list_job_records(
+    meeting_id: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<JobRecord>, String> {
+    let limit = limit
+        .filter(|value| *value > 0)
+        .unwrap_or(DEFAULT_JOB_LIMIT);
+    tokio::task::spawn_blocking(move || jobs(meeting_id.as_deref(), limit))
+        .await
+        .map_err(|err| err.to_string())?
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn get_job_record(job_id: String) -> Result<Option<JobDetail>, String> {
+    tokio::task::spawn_blocking(move || -> Result<Option<JobDetail>> {
+        let Some(job) = job(&job_id)? else {
+            return Ok(None);
+        };
+        let tracks = job_tracks(&job_id)?;
+        Ok(Some(JobDetail { job, tracks }))
+    })
+    .await
+    .map_err(|err| err.to_string())?
+    .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn list_seen_meetings() -> Result<Vec<MeetingRecord>, String> {
+    tokio::task::spawn_blocking(meetings)
+        .await
+        .map_err(|err| err.to_string())?
+        .map_err(|err| err.to_string())
+}
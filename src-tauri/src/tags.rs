@@ -0,0 +1,126 @@
+use std::collections::{BTreeSet, HashMap};
+use std::path::PathBuf;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+use tokio::sync::Mutex;
+
+use crate::{meeting_output_path, project_dirs, AppConfig, MeetingSummary};
+
+const TAG_TRANSCRIBED: &str = "transcribed";
+const TAG_UNTRANSCRIBED: &str = "untranscribed";
+
+static TAGS_LOCK: Mutex<()> = Mutex::const_new(());
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub(crate) struct MeetingTags {
+    tags: Vec<String>,
+    favorite: bool,
+}
+
+type TagStore = HashMap<String, MeetingTags>;
+
+fn tags_path() -> Result<PathBuf> {
+    let dirs = project_dirs()?;
+    Ok(dirs.data_dir().join("meeting_tags.json"))
+}
+
+async fn load_store() -> Result<TagStore> {
+    let path = tags_path()?;
+    match fs::read_to_string(&path).await {
+        Ok(data) if !data.trim().is_empty() => Ok(serde_json::from_str(&data)?),
+        Ok(_) => Ok(TagStore::default()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(TagStore::default()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+async fn save_store(store: &TagStore) -> Result<()> {
+    let path = tags_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+    fs::write(&path, serde_json::to_string_pretty(store)?).await?;
+    Ok(())
+}
+
+fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+    let mut seen = BTreeSet::new();
+    tags.into_iter()
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty() && tag != TAG_TRANSCRIBED && tag != TAG_UNTRANSCRIBED)
+        .filter(|tag| seen.insert(tag.clone()))
+        .collect()
+}
+
+async fn update_entry(
+    meeting_id: &str,
+    update: impl FnOnce(&mut MeetingTags),
+) -> Result<MeetingTags> {
+    let _guard = TAGS_LOCK.lock().await;
+    let mut store = load_store().await?;
+    let meeting_id = meeting_id.trim().trim_matches('/').to_string();
+    let entry = store.entry(meeting_id.clone()).or_default();
+    update(entry);
+    let result = entry.clone();
+    if result.tags.is_empty() && !result.favorite {
+        store.remove(&meeting_id);
+    }
+    save_store(&store).await?;
+    Ok(result)
+}
+
+pub(crate) async fn apply_tags(config: &AppConfig, meetings: &mut [MeetingSummary]) {
+    let store = match load_store().await {
+        Ok(store) => store,
+        Err(err) => {
+            eprintln!("Failed to load meeting tags: {err}");
+            TagStore::default()
+        }
+    };
+    for meeting in meetings {
+        let entry = store.get(&meeting.id).cloned().unwrap_or_default();
+        let transcribed = meeting_output_path(config, &meeting.id)
+            .map(|path| path.is_file())
+            .unwrap_or(false);
+        let mut tags = vec![if transcribed {
+            TAG_TRANSCRIBED.to_string()
+        } else {
+            TAG_UNTRANSCRIBED.to_string()
+        }];
+        tags.extend(entry.tags);
+        meeting.tags = tags;
+        meeting.favorite = entry.favorite;
+    }
+}
+
+#[tauri::command]
+pub async fn set_meeting_tags(
+    meeting_id: String,
+    tags: Vec<String>,
+) -> Result<MeetingTags, String> {
+    update_entry(&meeting_id, |entry| entry.tags = normalize_tags(tags))
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn set_meeting_favorite(
+    meeting_id: String,
+    favorite: bool,
+) -> Result<MeetingTags, String> {
+    update_entry(&meeting_id, |entry| entry.favorite = favorite)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn list_meeting_tags() -> Result<Vec<String>, String> {
+    let store = load_store().await.map_err(|err| err.to_string())?;
+    let mut tags: BTreeSet<String> = store.into_values().flat_map(|entry| entry.tags).collect();
+    tags.insert(TAG_TRANSCRIBED.to_string());
+    tags.insert(TAG_UNTRANSCRIBED.to_string());
+    Ok(tags.into_iter().collect())
+}
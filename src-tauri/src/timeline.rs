@@ -0,0 +1,59 @@
+use serde::Serialize;
+
+use crate::TranscriptionSegment;
+
+const MERGE_GAP_SECS: f64 = 1.0;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SpeakerSpan {
+    start: f64,
+    end: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SpeakerTimeline {
+    speaker: String,
+    total_secs: f64,
+    spans: Vec<SpeakerSpan>,
+}
+
+pub(crate) fn build(segments: &[TranscriptionSegment]) -> Vec<SpeakerTimeline> {
+    let mut timelines: Vec<SpeakerTimeline> = Vec::new();
+    for segment in segments {
+        let end = segment.end.unwrap_or(segment.start).max(segment.start);
+        let index = match timelines
+            .iter()
+            .position(|timeline| timeline.speaker == segment.speaker)
+        {
+            Some(index) => index,
+            None => {
+                timelines.push(SpeakerTimeline {
+                    speaker: segment.speaker.clone(),
+                    total_secs: 0.0,
+                    spans: Vec::new(),
+                });
+                timelines.len() - 1
+            }
+        };
+        let spans = &mut timelines[index].spans;
+        match spans.last_mut() {
+            Some(last) if segment.start - last.end <= MERGE_GAP_SECS => {
+                last.end = last.end.max(end);
+            }
+            _ => spans.push(SpeakerSpan {
+                start: segment.start,
+                end,
+            }),
+        }
+    }
+    for timeline in timelines.iter_mut() {
+        timeline.total_secs = timeline
+            .spans
+            .iter()
+            .map(|span| span.end - span.start)
+            .sum();
+    }
+    timelines
+}
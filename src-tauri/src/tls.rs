@@ -0,0 +1,134 @@
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use anyhow::{anyhow, Context, Result};
+use aws_sdk_s3::config::SharedHttpClient;
+use aws_smithy_http_client::hyper_014::HyperClientBuilder;
+use aws_smithy_http_client::proxy::ProxyConfig;
+use aws_smithy_http_client::tls::rustls_provider::CryptoMode;
+use aws_smithy_http_client::tls::{self, TlsContext, TrustStore};
+use aws_smithy_http_client::{Builder, Connector};
+use rustls::client::{ServerCertVerified, ServerCertVerifier};
+use rustls::{Certificate, ClientConfig, RootCertStore, ServerName};
+use rustls_pki_types::pem::PemObject;
+use rustls_pki_types::CertificateDer;
+
+use crate::MinioConfig;
+
+struct NoCertificateVerification;
+
+impl ServerCertVerifier for NoCertificateVerification {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &Certificate,
+        _intermediates: &[Certificate],
+        _server_name: &ServerName,
+        _scts: &mut dyn Iterator<Item = &[u8]>,
+        _ocsp_response: &[u8],
+        _now: SystemTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+}
+
+const PROXY_ENV_VARS: [&str; 4] = ["HTTPS_PROXY", "https_proxy", "ALL_PROXY", "all_proxy"];
+
+fn proxy_config(proxy_url: &str) -> Result<Option<ProxyConfig>> {
+    let proxy_url = proxy_url.trim();
+    if !proxy_url.is_empty() {
+        return ProxyConfig::all(proxy_url)
+            .map(Some)
+            .map_err(|err| anyhow!("Invalid proxy URL {proxy_url}: {err}"));
+    }
+    let from_env = PROXY_ENV_VARS
+        .iter()
+        .any(|name| std::env::var_os(name).is_some_and(|value| !value.is_empty()));
+    Ok(from_env.then(ProxyConfig::from_env))
+}
+
+pub(crate) fn s3_http_client(
+    minio: &MinioConfig,
+    proxy_url: &str,
+) -> Result<Option<SharedHttpClient>> {
+    let ca_bundle_path = minio.ca_bundle_path.trim();
+    if let Some(proxy) = proxy_config(proxy_url)? {
+        if minio.insecure_skip_verify {
+            return Err(anyhow!(
+                "Disabling TLS verification is not supported together with a proxy"
+            ));
+        }
+        return proxied_http_client(ca_bundle_path, proxy).map(Some);
+    }
+    if ca_bundle_path.is_empty() && !minio.insecure_skip_verify {
+        return Ok(None);
+    }
+
+    let mut roots = RootCertStore::empty();
+    for cert in rustls_native_certs::load_native_certs().certs {
+        let _ = roots.add(&Certificate(cert.to_vec()));
+    }
+    if !ca_bundle_path.is_empty() {
+        let certs = CertificateDer::pem_file_iter(ca_bundle_path)
+            .with_context(|| format!("Failed to read CA bundle: {ca_bundle_path}"))?
+            .collect::<Result<Vec<_>, _>>()
+            .with_context(|| format!("Failed to parse CA bundle: {ca_bundle_path}"))?;
+        if certs.is_empty() {
+            return Err(anyhow!(
+                "No certificates found in CA bundle: {ca_bundle_path}"
+            ));
+        }
+        for cert in certs {
+            roots
+                .add(&Certificate(cert.to_vec()))
+                .with_context(|| format!("Invalid certificate in CA bundle: {ca_bundle_path}"))?;
+        }
+    }
+
+    let mut tls_config = ClientConfig::builder()
+        .with_safe_defaults()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    if minio.insecure_skip_verify {
+        eprintln!(
+            "WARNING: TLS certificate verification is DISABLED for {}. Connections to this endpoint are not secure.",
+            minio.url
+        );
+        tls_config
+            .dangerous()
+            .set_certificate_verifier(Arc::new(NoCertificateVerification));
+    }
+
+    let connector = hyper_rustls::HttpsConnectorBuilder::new()
+        .with_tls_config(tls_config)
+        .https_or_http()
+        .enable_http1()
+        .enable_http2()
+        .build();
+    Ok(Some(HyperClientBuilder::new().build(connector)))
+}
+
+fn proxied_http_client(ca_bundle_path: &str, proxy: ProxyConfig) -> Result<SharedHttpClient> {
+    let mut trust_store = TrustStore::empty().with_native_roots(true);
+    if !ca_bundle_path.is_empty() {
+        let pem = std::fs::read(ca_bundle_path)
+            .with_context(|| format!("Failed to read CA bundle: {ca_bundle_path}"))?;
+        trust_store = trust_store.with_pem_certificate(pem);
+    }
+    let tls_context = TlsContext::builder()
+        .with_trust_store(trust_store)
+        .build()
+        .with_context(|| format!("Failed to load CA bundle: {ca_bundle_path}"))?;
+    Ok(
+        Builder::new().build_with_connector_fn(move |settings, components| {
+            let mut builder = Connector::builder().proxy_config(proxy.clone());
+            builder.set_connector_settings(settings.cloned());
+            if let Some(components) = components {
+                builder.set_sleep_impl(components.sleep_impl());
+            }
+            builder
+                .tls_provider(tls::Provider::Rustls(CryptoMode::AwsLc))
+                .tls_context(tls_context.clone())
+                .build()
+        }),
+    )
+}
@@ -0,0 +1,59 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use sha2::{Digest, Sha256};
+use tokio::fs;
+
+use crate::{project_dirs, WhisperConfig, WhisperSegment};
+
+fn cache_dir() -> Result<PathBuf> {
+    let dirs = project_dirs()?;
+    Ok(dirs.data_dir().join("transcript_cache"))
+}
+
+pub(crate) fn cache_key(whisper: &WhisperConfig, etag: &str) -> String {
+    let options = serde_json::json!({
+        "etag": etag.trim_matches('"'),
+        "language": whisper.language_code(),
+        "backend": whisper.backend,
+        "modelPath": whisper.model_path,
+        "openaiModel": whisper.openai_model,
+        "deepgramModel": whisper.deepgram_model,
+        "voskModelPath": whisper.vosk_model_path,
+        "externalCommand": whisper.external_command,
+        "alignmentCommand": whisper.alignment_command,
+        "diarization": whisper.diarization,
+        "diarizationCommand": whisper.diarization_command,
+        "beamSize": whisper.beam_size,
+        "bestOf": whisper.best_of,
+        "temperature": whisper.temperature,
+        "wordTimestamps": whisper.word_timestamps,
+        "chunkMinutes": whisper.chunk_minutes,
+        "chunkOverlapSecs": whisper.chunk_overlap_secs,
+        "minSpeechRatio": whisper.min_speech_ratio,
+        "trimSilenceSecs": whisper.trim_silence_secs,
+        "speedUp": whisper.speed_up,
+        "audioFilter": whisper.audio_filter(),
+        "stereoSpeakers": whisper.stereo_speakers,
+        "stereoSpeakerNames": whisper.stereo_speaker_names,
+    });
+    let digest = Sha256::digest(options.to_string().as_bytes());
+    digest.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+pub(crate) async fn load(key: &str) -> Option<Vec<WhisperSegment>> {
+    let path = cache_dir().ok()?.join(format!("{key}.json"));
+    let data = fs::read_to_string(&path).await.ok()?;
+    serde_json::from_str(&data).ok()
+}
+
+pub(crate) async fn store(key: &str, segments: &[WhisperSegment]) -> Result<()> {
+    let dir = cache_dir()?;
+    fs::create_dir_all(&dir).await?;
+    fs::write(
+        dir.join(format!("{key}.json")),
+        serde_json::to_string(segments)?,
+    )
+    .await?;
+    Ok(())
+}
@@ -0,0 +1,234 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use tauri::{AppHandle, State};
+use tauri_plugin_opener::OpenerExt;
+use tokio::fs;
+
+use crate::{effective_config, output_root, search_index, store, AppConfig, JobState, RoomLabeler};
+
+const MANIFEST_SUFFIX: &str = ".manifest.json";
+const TRANSCRIPT_EXTENSIONS: &[&str] = &["txt", "srt", "vtt", "json"];
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TranscriptEntry {
+    path: String,
+    file_name: String,
+    date: String,
+    room_id: String,
+    room_label: String,
+    meeting_time: String,
+    size: u64,
+    created_secs: Option<u64>,
+    job_id: Option<String>,
+    job_state: Option<String>,
+}
+
+fn created_secs(meta: &std::fs::Metadata) -> Option<u64> {
+    meta.created()
+        .or_else(|_| meta.modified())
+        .ok()
+        .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|duration| duration.as_secs())
+}
+
+pub(crate) fn split_stem(stem: &str) -> (String, String, String) {
+    let Some((date, rest)) = stem.split_once('_') else {
+        return (stem.to_string(), String::new(), String::new());
+    };
+    match rest.rsplit_once('_') {
+        Some((room, time)) => (date.to_string(), room.to_string(), time.to_string()),
+        None => (date.to_string(), rest.to_string(), String::new()),
+    }
+}
+
+async fn transcript_for_manifest(manifest: &Path) -> Option<PathBuf> {
+    let name = manifest.file_name()?.to_str()?;
+    let stem = name.strip_suffix(MANIFEST_SUFFIX)?;
+    for extension in TRANSCRIPT_EXTENSIONS {
+        let candidate = manifest.with_file_name(format!("{stem}.{extension}"));
+        if fs::metadata(&candidate)
+            .await
+            .map(|meta| meta.is_file())
+            .unwrap_or(false)
+        {
+            return Some(candidate);
+        }
+    }
+    None
+}
+
+pub(crate) async fn list(config: &AppConfig, jobs: &JobState) -> Result<Vec<TranscriptEntry>> {
+    let root = output_root(config)?;
+    let labeler = RoomLabeler::new(config);
+    let mut job_outputs: Vec<(String, String, String)> = {
+        let map = jobs.lock().unwrap();
+        map.iter()
+            .filter_map(|(job_id, status)| {
+                status
+                    .output_path
+                    .clone()
+                    .map(|path| (path, job_id.clone(), status.state.clone()))
+            })
+            .collect()
+    };
+    match tokio::task::spawn_blocking(store::jobs_with_output).await? {
+        Ok(records) => job_outputs.extend(records.into_iter().filter_map(|record| {
+            record
+                .output_path
+                .map(|path| (path, record.id, record.state))
+        })),
+        Err(err) => eprintln!("Failed to read job records: {err}"),
+    }
+
+    let mut entries = Vec::new();
+    let mut dir = match fs::read_dir(&root).await {
+        Ok(dir) => dir,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(entries),
+        Err(err) => return Err(err.into()),
+    };
+    while let Some(entry) = dir.next_entry().await? {
+        let manifest = entry.path();
+        let Some(path) = transcript_for_manifest(&manifest).await else {
+            continue;
+        };
+        let Ok(meta) = fs::metadata(&path).await else {
+            continue;
+        };
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let stem = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let (date, room_id, meeting_time) = split_stem(&stem);
+        let path = path.to_string_lossy().to_string();
+        let job = job_outputs
+            .iter()
+            .find(|(output_path, _, _)| *output_path == path);
+        entries.push(TranscriptEntry {
+            room_label: labeler.label(&room_id),
+            file_name,
+            date,
+            room_id,
+            meeting_time,
+            size: meta.len(),
+            created_secs: created_secs(&meta),
+            job_id: job.map(|(_, job_id, _)| job_id.clone()),
+            job_state: job.map(|(_, _, state)| state.clone()),
+            path,
+        });
+    }
+    entries.sort_by_key(|entry| std::cmp::Reverse(entry.created_secs));
+    Ok(entries)
+}
+
+pub(crate) async fn resolve_path(config: &AppConfig, path: &str) -> Result<PathBuf> {
+    let root = fs::canonicalize(output_root(config)?).await?;
+    let path = fs::canonicalize(path.trim())
+        .await
+        .map_err(|err| anyhow!("Transcript not found: {path} ({err})"))?;
+    if !path.starts_with(&root) || !path.is_file() {
+        return Err(anyhow!(
+            "{} is not a transcript in the output directory",
+            path.display()
+        ));
+    }
+    Ok(path)
+}
+
+#[tauri::command]
+pub async fn list_transcripts(jobs: State<'_, JobState>) -> Result<Vec<TranscriptEntry>, String> {
+    let config = effective_config().await.map_err(|err| err.to_string())?;
+    list(&config, &jobs).await.map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn open_transcript(app: AppHandle, path: String) -> Result<(), String> {
+    let config = effective_config().await.map_err(|err| err.to_string())?;
+    let path = resolve_path(&config, &path)
+        .await
+        .map_err(|err| err.to_string())?;
+    app.opener()
+        .open_path(path.to_string_lossy(), None::<&str>)
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn reveal_transcript(app: AppHandle, path: String) -> Result<(), String> {
+    let config = effective_config().await.map_err(|err| err.to_string())?;
+    let path = resolve_path(&config, &path)
+        .await
+        .map_err(|err| err.to_string())?;
+    app.opener()
+        .reveal_item_in_dir(path)
+        .map_err(|err| err.to_string())
+}
+
+pub(crate) async fn delete(config: &AppConfig, jobs: &JobState, path: &str) -> Result<usize> {
+    let path = resolve_path(config, path).await?;
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .ok_or_else(|| anyhow!("Invalid transcript path: {}", path.display()))?;
+    let parent = path
+        .parent()
+        .ok_or_else(|| anyhow!("Invalid transcript path: {}", path.display()))?;
+    let (job_ids, mut output_paths): (Vec<String>, Vec<String>) = {
+        let map = jobs.lock().unwrap();
+        map.iter()
+            .filter_map(|(job_id, status)| {
+                let output = status.output_path.clone()?;
+                std::fs::canonicalize(&output)
+                    .is_ok_and(|output| output == path)
+                    .then(|| (job_id.clone(), output))
+            })
+            .unzip()
+    };
+
+    let prefix = format!("{stem}.");
+    let mut removed = 0;
+    let mut dir = fs::read_dir(parent).await?;
+    while let Some(entry) = dir.next_entry().await? {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with(&prefix) && entry.file_type().await?.is_file() {
+            fs::remove_file(entry.path()).await?;
+            removed += 1;
+        }
+    }
+
+    if let Err(err) = search_index::remove_transcript(&path).await {
+        eprintln!("Failed to update search index: {err}");
+    }
+    output_paths.push(path.to_string_lossy().to_string());
+    let marked = tokio::task::spawn_blocking(move || -> Result<()> {
+        for output_path in &output_paths {
+            store::mark_output_deleted(output_path)?;
+        }
+        Ok(())
+    })
+    .await?;
+    if let Err(err) = marked {
+        eprintln!("Failed to record deletion: {err}");
+    }
+
+    let mut map = jobs.lock().unwrap();
+    for job_id in job_ids {
+        if let Some(status) = map.get_mut(&job_id) {
+            status.state = "deleted".to_string();
+        }
+    }
+    Ok(removed)
+}
+
+#[tauri::command]
+pub async fn delete_transcript(jobs: State<'_, JobState>, path: String) -> Result<usize, String> {
+    let config = effective_config().await.map_err(|err| err.to_string())?;
+    delete(&config, &jobs, &path)
+        .await
+        .map_err(|err| err.to_string())
+}
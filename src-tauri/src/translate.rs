@@ -0,0 +1,152 @@
+use anyhow::{anyhow, Context, Result};
+
+use crate::models::download_client;
+use crate::{llm, TranscriptionSegment, WhisperConfig};
+
+const TRANSLATION_BATCH_SIZE: usize = 50;
+const DEFAULT_TRANSLATION_MODEL: &str = "gpt-4o-mini";
+
+fn string_array(value: &serde_json::Value, pointer: &str, field: &str) -> Result<Vec<String>> {
+    value
+        .pointer(pointer)
+        .and_then(|items| items.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .map(|item| {
+                    item.get(field)
+                        .or(Some(item))
+                        .and_then(|text| text.as_str())
+                        .unwrap_or_default()
+                        .to_string()
+                })
+                .collect()
+        })
+        .ok_or_else(|| anyhow!("Translation response did not include translations"))
+}
+
+async fn post_json(request: reqwest::RequestBuilder, provider: &str) -> Result<serde_json::Value> {
+    let response = request
+        .send()
+        .await
+        .with_context(|| format!("Failed to send request to {provider}"))?;
+    let status = response.status();
+    let body = response.text().await?;
+    if !status.is_success() {
+        return Err(anyhow!("{provider} returned {status}: {body}"));
+    }
+    serde_json::from_str(&body).with_context(|| format!("Failed to parse {provider} response"))
+}
+
+async fn translate_deepl(
+    client: &reqwest::Client,
+    key: &str,
+    target: &str,
+    texts: &[String],
+) -> Result<Vec<String>> {
+    let host = if key.ends_with(":fx") {
+        "api-free.deepl.com"
+    } else {
+        "api.deepl.com"
+    };
+    let request = client
+        .post(format!("https://{host}/v2/translate"))
+        .header("Authorization", format!("DeepL-Auth-Key {key}"))
+        .json(&serde_json::json!({
+            "text": texts,
+            "target_lang": target.to_uppercase(),
+        }));
+    let value = post_json(request, "DeepL").await?;
+    string_array(&value, "/translations", "text")
+}
+
+async fn translate_google(
+    client: &reqwest::Client,
+    key: &str,
+    target: &str,
+    texts: &[String],
+) -> Result<Vec<String>> {
+    let request = client
+        .post("https://translation.googleapis.com/language/translate/v2")
+        .query(&[("key", key)])
+        .json(&serde_json::json!({
+            "q": texts,
+            "target": target,
+            "format": "text",
+        }));
+    let value = post_json(request, "Google Translate").await?;
+    string_array(&value, "/data/translations", "translatedText")
+}
+
+async fn translate_openai(
+    whisper: &WhisperConfig,
+    proxy_url: &str,
+    target: &str,
+    texts: &[String],
+) -> Result<Vec<String>> {
+    let model = match whisper.translation_model.trim() {
+        "" => DEFAULT_TRANSLATION_MODEL,
+        value => value,
+    };
+    let instructions = format!(
+        "Translate every string in the \"texts\" array into the language with code \"{target}\". \
+Respond with a JSON object {{\"translations\": [...]}} containing exactly one translated string \
+per input, in the same order."
+    );
+    let input = serde_json::json!({ "texts": texts }).to_string();
+    let value = llm::chat_json(whisper, proxy_url, model, &instructions, &input).await?;
+    string_array(&value, "/translations", "text")
+}
+
+async fn translate_batch(
+    whisper: &WhisperConfig,
+    proxy_url: &str,
+    target: &str,
+    texts: &[String],
+) -> Result<Vec<String>> {
+    let key = whisper.translation_api_key.trim();
+    let translated = match whisper.translation_provider.trim() {
+        "deepl" => translate_deepl(&download_client(proxy_url)?, key, target, texts).await?,
+        "google" => translate_google(&download_client(proxy_url)?, key, target, texts).await?,
+        "openai" => translate_openai(whisper, proxy_url, target, texts).await?,
+        other => return Err(anyhow!("Unknown translation provider: {other}")),
+    };
+    if translated.len() != texts.len() {
+        return Err(anyhow!(
+            "Translation returned {} lines for {} segments",
+            translated.len(),
+            texts.len()
+        ));
+    }
+    Ok(translated)
+}
+
+pub(crate) fn target_language(whisper: &WhisperConfig) -> Option<&str> {
+    let provider = whisper.translation_provider.trim();
+    let target = whisper.translation_target.trim();
+    if provider.is_empty() || provider == "off" || target.is_empty() {
+        None
+    } else {
+        Some(target)
+    }
+}
+
+pub(crate) async fn translate_segments(
+    whisper: &WhisperConfig,
+    proxy_url: &str,
+    target: &str,
+    segments: &[TranscriptionSegment],
+) -> Result<Vec<TranscriptionSegment>> {
+    let mut translated = Vec::with_capacity(segments.len());
+    for batch in segments.chunks(TRANSLATION_BATCH_SIZE) {
+        let texts: Vec<String> = batch.iter().map(|segment| segment.text.clone()).collect();
+        let lines = translate_batch(whisper, proxy_url, target, &texts).await?;
+        for (segment, text) in batch.iter().zip(lines) {
+            let mut segment = segment.clone();
+            segment.text = text;
+            segment.words.clear();
+            translated.push(segment);
+        }
+    }
+    Ok(translated)
+}
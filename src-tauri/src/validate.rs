@@ -0,0 +1,332 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::Serialize;
+use tokio::fs;
+use tokio::process::Command;
+
+use crate::backend::detect_gpu_backend;
+use crate::models::{self, LocalModel, ModelRecommendation};
+use crate::storage::create_source;
+use crate::{
+    default_ffmpeg_path, default_output_dir, default_whisper_binary, default_whisper_model_root,
+    output_root, resolve_ffmpeg_path, resolve_whisper_paths, AppConfig,
+};
+
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ConfigCheck {
+    name: String,
+    ok: bool,
+    message: String,
+    hint: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SetupReport {
+    output_dir: Option<String>,
+    whisper_binary: Option<String>,
+    whisper_version: Option<String>,
+    model_root: Option<String>,
+    models: Vec<LocalModel>,
+    ffmpeg_binary: Option<String>,
+    ffmpeg_version: Option<String>,
+    gpu: Option<String>,
+    recommended_model: Option<ModelRecommendation>,
+    suggestions: Vec<String>,
+}
+
+impl ConfigCheck {
+    fn pass(name: &str, message: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            ok: true,
+            message: message.into(),
+            hint: None,
+        }
+    }
+
+    fn fail(name: &str, message: impl Into<String>, hint: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ok: false,
+            message: message.into(),
+            hint: Some(hint.to_string()),
+        }
+    }
+}
+
+pub(crate) async fn validate(config: &AppConfig) -> Vec<ConfigCheck> {
+    let mut checks = vec![check_storage(config).await];
+    match config.whisper.backend.as_str() {
+        "openai" => checks.push(require_value(
+            "openai",
+            &config.whisper.openai_api_key,
+            "OpenAI API key is set",
+            "Set the OpenAI API key in the whisper settings.",
+        )),
+        "deepgram" => checks.push(require_value(
+            "deepgram",
+            &config.whisper.deepgram_api_key,
+            "Deepgram API key is set",
+            "Set the Deepgram API key in the whisper settings.",
+        )),
+        "azure" => {
+            checks.push(require_value(
+                "azureSpeech",
+                &config.whisper.azure_speech_key,
+                "Azure Speech key is set",
+                "Set the Azure Speech key in the whisper settings.",
+            ));
+            checks.push(require_value(
+                "azureSpeechRegion",
+                &config.whisper.azure_speech_region,
+                "Azure Speech region is set",
+                "Set the Azure Speech region, e.g. japaneast.",
+            ));
+        }
+        "external" => checks.push(require_value(
+            "externalCommand",
+            &config.whisper.external_command,
+            "External command is set",
+            "Set the external transcription command.",
+        )),
+        "vosk" => checks.push(check_path(
+            "voskModel",
+            Path::new(config.whisper.vosk_model_path.trim()),
+            "Download a Vosk model and set its directory.",
+        )),
+        _ => checks.extend(check_whisper(config).await),
+    }
+    checks.push(check_ffmpeg(config).await);
+    checks.push(check_output_dir(config).await);
+    checks
+}
+
+fn require_value(name: &str, value: &str, message: &str, hint: &str) -> ConfigCheck {
+    if value.trim().is_empty() {
+        ConfigCheck::fail(name, "Not configured", hint)
+    } else {
+        ConfigCheck::pass(name, message)
+    }
+}
+
+fn check_path(name: &str, path: &Path, hint: &str) -> ConfigCheck {
+    if path.as_os_str().is_empty() {
+        ConfigCheck::fail(name, "Not configured", hint)
+    } else if path.exists() {
+        ConfigCheck::pass(name, format!("Found {}", path.display()))
+    } else {
+        ConfigCheck::fail(name, format!("Not found: {}", path.display()), hint)
+    }
+}
+
+async fn check_storage(config: &AppConfig) -> ConfigCheck {
+    let source = match create_source(config).await {
+        Ok(source) => source,
+        Err(err) => {
+            return ConfigCheck::fail(
+                "storage",
+                err.to_string(),
+                "Fill in the storage endpoint, bucket and credentials.",
+            )
+        }
+    };
+    match tokio::time::timeout(COMMAND_TIMEOUT, source.check()).await {
+        Ok(Ok(())) => ConfigCheck::pass("storage", format!("{} is reachable", source.name())),
+        Ok(Err(err)) => ConfigCheck::fail(
+            "storage",
+            err.to_string(),
+            "Check the endpoint URL, bucket name and credentials, and that the bucket exists.",
+        ),
+        Err(_) => ConfigCheck::fail(
+            "storage",
+            "Timed out connecting to storage",
+            "Check the endpoint URL, proxy settings and network access.",
+        ),
+    }
+}
+
+async fn check_whisper(config: &AppConfig) -> Vec<ConfigCheck> {
+    let (binary_path, model_path) = match resolve_whisper_paths(config) {
+        Ok(paths) => paths,
+        Err(err) => {
+            return vec![ConfigCheck::fail(
+                "whisperBinary",
+                err.to_string(),
+                "Install whisper.cpp or set the whisper binary path.",
+            )]
+        }
+    };
+    let binary = match run_probe(&binary_path, &["-h"]).await {
+        Ok(output) => {
+            let version = whisper_version(&output);
+            ConfigCheck::pass(
+                "whisperBinary",
+                format!(
+                    "{} ({})",
+                    binary_path.display(),
+                    version.as_deref().unwrap_or("version unknown")
+                ),
+            )
+        }
+        Err(err) => ConfigCheck::fail(
+            "whisperBinary",
+            format!("{}: {err}", binary_path.display()),
+            "Make sure the whisper binary exists and is executable.",
+        ),
+    };
+    vec![
+        binary,
+        check_path(
+            "whisperModel",
+            &model_path,
+            "Download a model from the models page or set the model path.",
+        ),
+    ]
+}
+
+async fn check_ffmpeg(config: &AppConfig) -> ConfigCheck {
+    let ffmpeg_path = match resolve_ffmpeg_path(config) {
+        Ok(path) => path,
+        Err(err) => {
+            return ConfigCheck::fail(
+                "ffmpeg",
+                err.to_string(),
+                "Install ffmpeg or set the ffmpeg path.",
+            )
+        }
+    };
+    match run_probe(&ffmpeg_path, &["-version"]).await {
+        Ok(output) => ConfigCheck::pass("ffmpeg", ffmpeg_version(&output)),
+        Err(err) => ConfigCheck::fail(
+            "ffmpeg",
+            format!("{}: {err}", ffmpeg_path.display()),
+            "Make sure ffmpeg exists and is executable.",
+        ),
+    }
+}
+
+async fn check_output_dir(config: &AppConfig) -> ConfigCheck {
+    let hint = "Choose an output directory you have write access to.";
+    let dir = match output_root(config) {
+        Ok(dir) => dir,
+        Err(err) => return ConfigCheck::fail("outputDir", err.to_string(), hint),
+    };
+    if let Err(err) = fs::create_dir_all(&dir).await {
+        return ConfigCheck::fail("outputDir", format!("{}: {err}", dir.display()), hint);
+    }
+    let probe = dir.join(".whisperdesktop-write-test");
+    match fs::write(&probe, b"ok").await {
+        Ok(()) => {
+            let _ = fs::remove_file(&probe).await;
+            ConfigCheck::pass("outputDir", format!("{} is writable", dir.display()))
+        }
+        Err(err) => ConfigCheck::fail("outputDir", format!("{}: {err}", dir.display()), hint),
+    }
+}
+
+async fn run_probe(binary: &Path, args: &[&str]) -> Result<String, String> {
+    let output = tokio::time::timeout(COMMAND_TIMEOUT, Command::new(binary).args(args).output())
+        .await
+        .map_err(|_| "timed out".to_string())?
+        .map_err(|err| err.to_string())?;
+    let mut text = String::from_utf8_lossy(&output.stdout).to_string();
+    text.push_str(&String::from_utf8_lossy(&output.stderr));
+    Ok(text)
+}
+
+fn whisper_version(output: &str) -> Option<String> {
+    output
+        .lines()
+        .find(|line| line.to_lowercase().contains("version"))
+        .map(|line| line.trim().to_string())
+}
+
+fn ffmpeg_version(output: &str) -> String {
+    output.lines().next().unwrap_or_default().trim().to_string()
+}
+
+fn display(path: &Path) -> String {
+    path.to_string_lossy().to_string()
+}
+
+pub(crate) async fn detect_everything(config: &AppConfig) -> anyhow::Result<SetupReport> {
+    let mut suggestions = Vec::new();
+
+    let whisper_binary: Option<PathBuf> = resolve_whisper_paths(config)
+        .ok()
+        .map(|(binary_path, _)| binary_path)
+        .filter(|path| path.is_file())
+        .or_else(default_whisper_binary)
+        .filter(|path| path.is_file());
+    let mut whisper_version_line = None;
+    match &whisper_binary {
+        Some(path) => match run_probe(path, &["-h"]).await {
+            Ok(output) => whisper_version_line = whisper_version(&output),
+            Err(err) => suggestions.push(format!(
+                "whisper binary at {} could not be executed ({err}); reinstall whisper.cpp",
+                path.display()
+            )),
+        },
+        None => suggestions
+            .push("Install whisper.cpp or set the whisper binary path in settings".to_string()),
+    }
+
+    let ffmpeg_binary = resolve_ffmpeg_path(config)
+        .ok()
+        .filter(|path| path.is_file())
+        .or_else(default_ffmpeg_path);
+    let mut ffmpeg_version_line = None;
+    match &ffmpeg_binary {
+        Some(path) => match run_probe(path, &["-version"]).await {
+            Ok(output) => ffmpeg_version_line = Some(ffmpeg_version(&output)),
+            Err(err) => suggestions.push(format!(
+                "ffmpeg at {} could not be executed ({err}); reinstall ffmpeg",
+                path.display()
+            )),
+        },
+        None => suggestions.push("Install ffmpeg and make sure it is on PATH".to_string()),
+    }
+
+    let models = models::local_models().await.unwrap_or_default();
+    let gpu = whisper_binary
+        .as_deref()
+        .and_then(detect_gpu_backend)
+        .map(str::to_string);
+    let recommended_model = models::recommend_model(gpu.as_deref()).ok();
+    if let Some(recommendation) = &recommended_model {
+        if models.is_empty() && !recommendation.installed {
+            suggestions.push(format!(
+                "Download the {} model for this machine",
+                recommendation.name
+            ));
+        }
+    }
+
+    let output_dir = output_root(config).or_else(|_| default_output_dir()).ok();
+    if output_dir.is_none() {
+        suggestions.push("Choose an output directory for transcripts".to_string());
+    }
+    if matches!(config.minio.provider.trim(), "" | "minio" | "aws" | "other")
+        && config.minio.bucket.trim().is_empty()
+    {
+        suggestions.push("Configure the storage connection for meeting recordings".to_string());
+    }
+
+    Ok(SetupReport {
+        output_dir: output_dir.as_deref().map(display),
+        whisper_binary: whisper_binary.as_deref().map(display),
+        whisper_version: whisper_version_line,
+        model_root: default_whisper_model_root().ok().as_deref().map(display),
+        models,
+        ffmpeg_binary: ffmpeg_binary.as_deref().map(display),
+        ffmpeg_version: ffmpeg_version_line,
+        gpu,
+        recommended_model,
+        suggestions,
+    })
+}
@@ -0,0 +1,102 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use anyhow::Result;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_notification::NotificationExt;
+
+use crate::storage::create_source;
+use crate::{
+    effective_config, fetch_dates, fetch_meetings, parse_date_any, s3_retry_attempts, RoomLabeler,
+};
+
+const IDLE_CHECK_SECS: u64 = 30;
+const MIN_WATCH_INTERVAL_SECS: u64 = 10;
+const WATCH_RECENT_DATES: usize = 2;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct NewMeetingEvent {
+    id: String,
+    date: String,
+    room_id: String,
+    room_label: String,
+    meeting_time: String,
+}
+
+pub(crate) fn spawn_watcher(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut seen: Option<HashSet<String>> = None;
+        let mut watched_profile = String::new();
+        loop {
+            let config = match effective_config().await {
+                Ok(config) => config,
+                Err(err) => {
+                    eprintln!("Meeting watcher: {err}");
+                    tokio::time::sleep(Duration::from_secs(IDLE_CHECK_SECS)).await;
+                    continue;
+                }
+            };
+            let interval = config.watch_interval_secs;
+            if interval == 0 {
+                seen = None;
+                tokio::time::sleep(Duration::from_secs(IDLE_CHECK_SECS)).await;
+                continue;
+            }
+            if config.active_profile != watched_profile {
+                watched_profile = config.active_profile.clone();
+                seen = None;
+            }
+
+            match recent_meetings(&config).await {
+                Ok(meetings) => {
+                    if let Some(seen) = seen.as_mut() {
+                        for meeting in &meetings {
+                            if seen.insert(meeting.id.clone()) {
+                                notify(&app, meeting, config.watch_notifications);
+                            }
+                        }
+                    } else {
+                        seen = Some(meetings.into_iter().map(|meeting| meeting.id).collect());
+                    }
+                }
+                Err(err) => eprintln!("Meeting watcher: {err}"),
+            }
+            tokio::time::sleep(Duration::from_secs(interval.max(MIN_WATCH_INTERVAL_SECS))).await;
+        }
+    });
+}
+
+async fn recent_meetings(config: &crate::AppConfig) -> Result<Vec<NewMeetingEvent>> {
+    let source = create_source(config).await?;
+    let retry_attempts = s3_retry_attempts(&config.minio);
+    let labeler = RoomLabeler::new(config);
+    let mut dates = fetch_dates(source.as_ref(), retry_attempts).await?;
+    dates.sort_by_key(|date| std::cmp::Reverse(parse_date_any(date)));
+    let mut meetings = Vec::new();
+    for date in dates.iter().take(WATCH_RECENT_DATES) {
+        for meeting in fetch_meetings(source.as_ref(), retry_attempts, date).await? {
+            meetings.push(NewMeetingEvent {
+                room_label: labeler.label(&meeting.room_id),
+                id: meeting.id,
+                date: meeting.date,
+                room_id: meeting.room_id,
+                meeting_time: meeting.meeting_time,
+            });
+        }
+    }
+    Ok(meetings)
+}
+
+fn notify(app: &AppHandle, meeting: &NewMeetingEvent, show_notification: bool) {
+    let _ = app.emit("meetings://new", meeting.clone());
+    if show_notification {
+        let _ = app
+            .notification()
+            .builder()
+            .title("WhisperDesktop")
+            .body(format!("New meeting recorded in {}", meeting.room_label))
+            .show();
+    }
+}
@@ -0,0 +1,198 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use chrono::Timelike;
+use serde::Serialize;
+use tokio::fs;
+use tokio::io::AsyncReadExt;
+use tokio::process::Command;
+use uuid::Uuid;
+
+use crate::storage::{create_source, StorageSource};
+use crate::{
+    log_retry, parse_key, parse_time_any, resolve_ffmpeg_path, s3_retry_attempts, temp_base_dir,
+    with_retry, AppConfig,
+};
+
+const SAMPLE_RATE: u32 = 8000;
+const DEFAULT_RESOLUTION_SECS: f64 = 0.1;
+const MIN_RESOLUTION_SECS: f64 = 0.01;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Waveform {
+    start: f64,
+    resolution_secs: f64,
+    peaks: Vec<f32>,
+}
+
+async fn decode_peaks(
+    ffmpeg_path: &Path,
+    location: &str,
+    samples_per_peak: usize,
+) -> Result<Vec<f32>> {
+    let mut child = Command::new(ffmpeg_path)
+        .arg("-nostdin")
+        .arg("-v")
+        .arg("error")
+        .arg("-i")
+        .arg(location)
+        .arg("-vn")
+        .arg("-ac")
+        .arg("1")
+        .arg("-ar")
+        .arg(SAMPLE_RATE.to_string())
+        .arg("-f")
+        .arg("s16le")
+        .arg("-")
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .with_context(|| format!("Failed to execute ffmpeg: {}", ffmpeg_path.display()))?;
+    let mut stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| anyhow!("Failed to capture ffmpeg stdout"))?;
+
+    let mut peaks = Vec::new();
+    let mut buffer = vec![0u8; 64 * 1024];
+    let mut pending: Option<u8> = None;
+    let mut peak: i32 = 0;
+    let mut count = 0;
+    loop {
+        let read = stdout.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        let mut bytes = buffer[..read].iter().copied();
+        loop {
+            let low = match pending.take() {
+                Some(low) => low,
+                None => match bytes.next() {
+                    Some(low) => low,
+                    None => break,
+                },
+            };
+            let Some(high) = bytes.next() else {
+                pending = Some(low);
+                break;
+            };
+            peak = peak.max(i32::from(i16::from_le_bytes([low, high])).abs());
+            count += 1;
+            if count == samples_per_peak {
+                peaks.push(peak as f32 / f32::from(i16::MAX));
+                peak = 0;
+                count = 0;
+            }
+        }
+    }
+    if count > 0 {
+        peaks.push(peak as f32 / f32::from(i16::MAX));
+    }
+
+    let status = child.wait().await?;
+    if !status.success() {
+        return Err(anyhow!("ffmpeg failed to decode {location}"));
+    }
+    Ok(peaks)
+}
+
+async fn track_peaks(
+    config: &AppConfig,
+    source: &dyn StorageSource,
+    key: &str,
+    samples_per_peak: usize,
+) -> Result<Vec<f32>> {
+    let ffmpeg_path = resolve_ffmpeg_path(config)?;
+    if let Some(location) = source.probe_location(key).await? {
+        return decode_peaks(&ffmpeg_path, &location, samples_per_peak).await;
+    }
+    let work_dir = temp_base_dir().join(format!("waveform-{}", Uuid::new_v4()));
+    fs::create_dir_all(&work_dir).await?;
+    let local_file = work_dir.join("track");
+    let no_progress = |_: u64, _: Option<u64>| {};
+    let result = async {
+        with_retry(
+            s3_retry_attempts(&config.minio),
+            "get_object",
+            log_retry,
+            || source.get_object(key, &local_file, &no_progress),
+        )
+        .await?;
+        decode_peaks(
+            &ffmpeg_path,
+            &local_file.to_string_lossy(),
+            samples_per_peak,
+        )
+        .await
+    }
+    .await;
+    let _ = fs::remove_dir_all(&work_dir).await;
+    result
+}
+
+fn track_start(key: &str) -> f64 {
+    parse_key(key)
+        .and_then(|(_, _, _, _, track_time)| parse_time_any(&track_time))
+        .map(|time| time.num_seconds_from_midnight() as f64)
+        .unwrap_or(0.0)
+}
+
+pub(crate) async fn build(
+    config: &AppConfig,
+    target: &str,
+    resolution_secs: Option<f64>,
+) -> Result<Waveform> {
+    let resolution_secs = resolution_secs
+        .filter(|value| value.is_finite())
+        .unwrap_or(DEFAULT_RESOLUTION_SECS)
+        .max(MIN_RESOLUTION_SECS);
+    let samples_per_peak = ((resolution_secs * f64::from(SAMPLE_RATE)).round() as usize).max(1);
+    let source = create_source(config).await?;
+    let target = target.trim().trim_matches('/');
+
+    if parse_key(target).is_some() {
+        let peaks = track_peaks(config, source.as_ref(), target, samples_per_peak).await?;
+        return Ok(Waveform {
+            start: track_start(target),
+            resolution_secs,
+            peaks,
+        });
+    }
+
+    let prefix = format!("{target}/");
+    let keys: Vec<String> = with_retry(
+        s3_retry_attempts(&config.minio),
+        "list_objects",
+        log_retry,
+        || source.list_objects(&prefix),
+    )
+    .await?
+    .into_iter()
+    .filter(|key| parse_key(key).is_some())
+    .collect();
+    if keys.is_empty() {
+        return Err(anyhow!("No tracks found for {target}"));
+    }
+    let start = keys
+        .iter()
+        .map(|key| track_start(key))
+        .fold(f64::MAX, f64::min);
+    let mut peaks: Vec<f32> = Vec::new();
+    for key in &keys {
+        let track = track_peaks(config, source.as_ref(), key, samples_per_peak).await?;
+        let offset = ((track_start(key) - start) / resolution_secs).round() as usize;
+        if peaks.len() < offset + track.len() {
+            peaks.resize(offset + track.len(), 0.0);
+        }
+        for (index, peak) in track.into_iter().enumerate() {
+            let merged = &mut peaks[offset + index];
+            *merged = merged.max(peak);
+        }
+    }
+    Ok(Waveform {
+        start,
+        resolution_secs,
+        peaks,
+    })
+}
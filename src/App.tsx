@@ -1,5 +1,6 @@
 import { useEffect, useMemo, useRef, useState } from "react";
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import "./App.css";
 
 type MeetingSummary = {
@@ -10,6 +11,13 @@ type MeetingSummary = {
   meetingTime: string;
 };
 
+type Listing<T> = {
+  items: T;
+  fetchedAt: number;
+  cached: boolean;
+  stale: boolean;
+};
+
 type JobStatus = {
   state: string;
   completed: number;
@@ -19,6 +27,14 @@ type JobStatus = {
   log?: string;
 };
 
+type JobFinishedEvent = {
+  jobId: string;
+  state: string;
+  label: string;
+  outputPath?: string;
+  error?: string;
+};
+
 type AppConfig = {
   minio: {
     url: string;
@@ -78,6 +94,9 @@ function App() {
     "idle" | "ok" | "ng"
   >("ng");
   const [modelRoot, setModelRoot] = useState("");
+  const [finishedJob, setFinishedJob] = useState<JobFinishedEvent | null>(
+    null,
+  );
 
   const pollingRef = useRef<number | null>(null);
   const saveInFlightRef = useRef(false);
@@ -127,7 +146,7 @@ function App() {
   const refreshDates = async () => {
     setDatesLoading(true);
     try {
-      const result = await invoke<string[]>("list_dates");
+      const { items: result } = await invoke<Listing<string[]>>("list_dates");
       const sorted = [...result].sort(compareDateKeysDesc);
       setDates(sorted);
       if (result.length > 0) {
@@ -149,9 +168,10 @@ function App() {
     if (!targetDate) return;
     setMeetingsLoading(true);
     try {
-      const result = await invoke<MeetingSummary[]>("list_meetings", {
-        date: targetDate,
-      });
+      const { items: result } = await invoke<Listing<MeetingSummary[]>>(
+        "list_meetings",
+        { date: targetDate },
+      );
       setMeetings(result);
       setSelectedRoom(null);
       setSelectedMeetingId(null);
@@ -163,6 +183,11 @@ function App() {
   };
 
   const refreshAll = async () => {
+    try {
+      await invoke("refresh_listings", { date: selectedDate });
+    } catch (err) {
+      console.error(err);
+    }
     await refreshDates();
     if (selectedDate) {
       await refreshMeetings(selectedDate);
@@ -333,6 +358,39 @@ function App() {
     };
   }, []);
 
+  useEffect(() => {
+    const unlisten = listen("config://changed", () => {
+      loadConfig();
+    });
+    return () => {
+      unlisten.then((stop) => stop());
+    };
+  }, []);
+
+  useEffect(() => {
+    const unlisten = listen<JobFinishedEvent>(
+      "transcribe://finished",
+      (event) => {
+        if (event.payload.state === "done" && event.payload.outputPath) {
+          setFinishedJob(event.payload);
+        }
+      },
+    );
+    return () => {
+      unlisten.then((stop) => stop());
+    };
+  }, []);
+
+  const openFinishedOutput = async () => {
+    if (!finishedJob?.outputPath) return;
+    try {
+      await invoke("open_transcript", { path: finishedJob.outputPath });
+      setFinishedJob(null);
+    } catch (err) {
+      console.error(err);
+    }
+  };
+
   useEffect(() => {
     if (selectedDate) {
       refreshMeetings(selectedDate);
@@ -437,6 +495,22 @@ function App() {
         ) : null}
       </div>
 
+      {finishedJob ? (
+        <div className="row finished-banner" role="status">
+          <span>Transcription finished: {finishedJob.label}</span>
+          <button type="button" onClick={openFinishedOutput}>
+            Open
+          </button>
+          <button
+            type="button"
+            className="ghost"
+            onClick={() => setFinishedJob(null)}
+          >
+            Dismiss
+          </button>
+        </div>
+      ) : null}
+
       {activeTab === "meetings" ? (
         <>
           <div className="grid">